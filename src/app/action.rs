@@ -14,6 +14,16 @@ pub enum Action {
     PageDown,
     Backspace,
     Tab,
+    Strategies,
+    Toggle,
+    Close,
+    EvaluateAll,
+    Traces,
+    History,
+    Export,
+    Params,
+    Decrease,
+    Increase,
 }
 
 impl Action {
@@ -33,6 +43,16 @@ impl Action {
             Action::PageDown => &[Key::PageDown],
             Action::Backspace => &[Key::Backspace],
             Action::Tab => &[Key::Tab],
+            Action::Strategies => &[Key::Char('s')],
+            Action::Toggle => &[Key::Char(' '), Key::Enter],
+            Action::Close => &[Key::Esc],
+            Action::EvaluateAll => &[Key::Char('e')],
+            Action::Traces => &[Key::Char('t')],
+            Action::History => &[Key::Char('h')],
+            Action::Export => &[Key::Char('x')],
+            Action::Params => &[Key::Char('p')],
+            Action::Decrease => &[Key::Left],
+            Action::Increase => &[Key::Right],
         }
     }
 }
@@ -48,6 +68,16 @@ impl Display for Action {
             Action::PageDown => "PageDown",
             Action::Backspace => "Backspace",
             Action::Tab => "Tab",
+            Action::Strategies => "Strategies",
+            Action::Toggle => "Toggle",
+            Action::Close => "Close",
+            Action::EvaluateAll => "Evaluate all",
+            Action::Traces => "Traces",
+            Action::History => "Decision history",
+            Action::Export => "Export",
+            Action::Params => "Strategy parameters",
+            Action::Decrease => "Decrease",
+            Action::Increase => "Increase",
         };
         write!(f, "{}", str)
     }
@@ -0,0 +1,69 @@
+use std::{io, sync::Arc};
+
+use chrono::Local;
+use log::{LevelFilter, Log, Metadata, Record};
+
+use super::{io::IoEvent, App};
+
+/// Logs to stderr (collected by journald when running as a service).
+struct StderrLogger {
+    level: LevelFilter,
+}
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!(
+                "{} {:<5} {}",
+                Local::now().format("%Y-%m-%d %H:%M:%S"),
+                record.level(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+pub fn init_logger(level: LevelFilter) -> Result<(), log::SetLoggerError> {
+    log::set_boxed_logger(Box::new(StderrLogger { level }))?;
+    log::set_max_level(level);
+    Ok(())
+}
+
+/// Runs the application without UI: players details are refreshed and strategies run
+/// in the background, until SIGTERM or Ctrl-C is received.
+pub async fn run(app: &Arc<tokio::sync::Mutex<App>>) -> io::Result<()> {
+    {
+        let mut app = app.lock().await;
+        app.dispatch(IoEvent::Initialize).await;
+    }
+
+    wait_for_shutdown().await?;
+    log::info!("🛑 Shutting down");
+
+    // Let the running state update complete before exiting
+    let _app = app.lock().await;
+
+    Ok(())
+}
+
+#[cfg(unix)]
+async fn wait_for_shutdown() -> io::Result<()> {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut terminate = signal(SignalKind::terminate())?;
+    tokio::select! {
+        _ = terminate.recv() => Ok(()),
+        result = tokio::signal::ctrl_c() => result,
+    }
+}
+
+#[cfg(not(unix))]
+async fn wait_for_shutdown() -> io::Result<()> {
+    tokio::signal::ctrl_c().await
+}
@@ -1,7 +1,8 @@
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 
 use eyre::Result;
 use log::{error, info};
+use serde_json::Value;
 
 use super::IoEvent;
 use crate::{
@@ -10,16 +11,33 @@ use crate::{
             load_player_prices::LoadPlayerPricesTask, load_players::LoadPlayersTask,
             load_players_stats::LoadPlayersStatsTask,
             refresh_players_details::RefreshPlayersDetailsTask, run_strategies::RunStrategiesTask, load_players_injury::LoadPlayersInjuryTask,
+            evaluate_players::EvaluatePlayersTask, notify_decisions::NotifyDecisionsTask,
         },
+        state::{ExportTable, Popup},
         App,
     },
-    core::{service::player::PlayerError, MainTaskManager},
-    resolve,
+    core::{
+        model::{
+            currency::Currency, decision::Decision, evaluation::EvaluationProgress,
+            requirement::DataRequirement,
+        },
+        service::{
+            decision::{DecisionError, DecisionService},
+            player::PlayerError,
+            strategy::{StrategyError, StrategyService},
+        },
+        DecisionSender, MainTaskManager,
+    },
+    output::{self, OutputFormat},
+    resolve, resolve_trait,
 };
 
 #[derive(Debug)]
 pub enum IoAsyncHandlerError {
     Player(String),
+    Strategy(String),
+    Decision(String),
+    Export(String),
 }
 
 impl From<PlayerError> for IoAsyncHandlerError {
@@ -28,24 +46,54 @@ impl From<PlayerError> for IoAsyncHandlerError {
     }
 }
 
+impl From<StrategyError> for IoAsyncHandlerError {
+    fn from(e: StrategyError) -> Self {
+        Self::Strategy(e.to_string())
+    }
+}
+
+impl From<DecisionError> for IoAsyncHandlerError {
+    fn from(e: DecisionError) -> Self {
+        Self::Decision(e.to_string())
+    }
+}
+
+/// Number of players evaluated by each "evaluate all" task
+const EVALUATION_CHUNK_SIZE: usize = 10;
+
 /// In the IO thread, we handle IO event without blocking the UI thread
 pub struct IoAsyncHandler {
     app: Arc<tokio::sync::Mutex<App>>,
+    /// Directory of exported tables
+    export_dir: PathBuf,
 }
 
 impl IoAsyncHandler {
-    pub fn new(app: Arc<tokio::sync::Mutex<App>>) -> Self {
-        Self { app }
+    pub fn new(app: Arc<tokio::sync::Mutex<App>>, export_dir: PathBuf) -> Self {
+        Self { app, export_dir }
     }
 
     /// We could be async here
     pub async fn handle_io_event(&mut self, io_event: IoEvent) {
         let result = match io_event {
             IoEvent::Initialize => self.do_initialize().await,
-            IoEvent::LoadPlayerPrices(slug) => self.do_load_player_prices(&slug).await,
+            IoEvent::LoadPlayersPrices(slugs) => self.do_load_players_prices(slugs).await,
             IoEvent::LoadPlayersStats(slugs) => self.do_load_players_stats(slugs).await,
             IoEvent::LoadPlayersInjury(slugs) => self.do_load_players_injury(slugs).await,
             IoEvent::RunStrategies(slug) => self.do_run_strategies(&slug).await,
+            IoEvent::LoadStrategies => self.do_load_strategies().await,
+            IoEvent::SetStrategyEnabled(name, enabled) => {
+                self.do_set_strategy_enabled(&name, enabled).await
+            }
+            IoEvent::LoadStrategyParams(name) => self.do_load_strategy_params(&name).await,
+            IoEvent::SetStrategyParam(name, param, value) => {
+                self.do_set_strategy_param(&name, &param, value).await
+            }
+            IoEvent::EvaluateAll => self.do_evaluate_all().await,
+            IoEvent::LoadTraces(slug) => self.do_load_traces(&slug).await,
+            IoEvent::LoadDecisionHistory => self.do_load_decision_history().await,
+            IoEvent::NotifyDecisions(decisions) => self.do_notify_decisions(decisions).await,
+            IoEvent::Export(table, format) => self.do_export(table, format).await,
         };
 
         if let Err(err) = result {
@@ -58,16 +106,226 @@ impl IoAsyncHandler {
 
     async fn do_run_strategies(&mut self, slug: &str) -> Result<(), IoAsyncHandlerError> {
         let task_manager = resolve!(MainTaskManager);
-        let app = self.app.lock().await;
-        if let Some(player) = app.state.get_player(slug) {
+        let requirements = resolve_trait!(StrategyService).requirements().await?;
+        let mut app = self.app.lock().await;
+        if let Some(player) = app.state.get_player(slug).cloned() {
+            // Load data required by strategies, but missing (once per player)
+            let missing = requirements
+                .iter()
+                .flat_map(|r| player.missing_data(r))
+                .collect();
+            for requirement in app.state.request_data(slug, missing) {
+                let slugs = vec![slug.to_string()];
+                match requirement {
+                    DataRequirement::Prices => {
+                        task_manager
+                            .run(Box::new(LoadPlayerPricesTask::new(self.app.clone(), slugs)))
+                            .await
+                    }
+                    DataRequirement::Stats => {
+                        task_manager
+                            .run(Box::new(LoadPlayersStatsTask::new(self.app.clone(), slugs)))
+                            .await
+                    }
+                    DataRequirement::Injury => {
+                        task_manager
+                            .run(Box::new(LoadPlayersInjuryTask::new(self.app.clone(), slugs)))
+                            .await
+                    }
+                    // Not provided by socli yet: strategies requiring it stay skipped
+                    DataRequirement::Schedule => (),
+                }
+            }
+
+            // Context is shared by strategy runs until players data changes
+            let context = app.state.strategy_context();
             task_manager
-                .run(Box::new(RunStrategiesTask::new(self.app.clone(), player.clone())))
+                .run(Box::new(RunStrategiesTask::new(
+                    self.app.clone(),
+                    player,
+                    context,
+                )))
                 .await;
         }
 
         Ok(())
     }
 
+    async fn do_evaluate_all(&mut self) -> Result<(), IoAsyncHandlerError> {
+        let task_manager = resolve!(MainTaskManager);
+        let mut app = self.app.lock().await;
+        let players = app.state.get_players().to_vec();
+        let requirements = resolve_trait!(StrategyService).requirements().await?;
+        let progress = EvaluationProgress::new(&players, &requirements);
+        info!(
+            "Evaluating strategies on {} players ({} skipped: missing data)",
+            progress.player_count, progress.skipped_count
+        );
+        app.state.start_evaluation(progress);
+
+        // Players are split in tasks, run in parallel by the task manager
+        let context = app.state.strategy_context();
+        let evaluable = players
+            .into_iter()
+            .filter(|p| p.is_evaluable(&requirements))
+            .collect::<Vec<_>>();
+        if evaluable.is_empty() {
+            info!("No player to evaluate: data required by strategies is not loaded yet");
+        }
+        for (index, chunk) in evaluable.chunks(EVALUATION_CHUNK_SIZE).enumerate() {
+            task_manager
+                .run(Box::new(EvaluatePlayersTask::new(
+                    self.app.clone(),
+                    index,
+                    chunk.to_vec(),
+                    context.clone(),
+                )))
+                .await;
+        }
+
+        Ok(())
+    }
+
+    async fn do_load_traces(&mut self, slug: &str) -> Result<(), IoAsyncHandlerError> {
+        let traces = resolve_trait!(StrategyService).traces(slug);
+        let mut app = self.app.lock().await;
+        let player_name = app
+            .state
+            .get_player(slug)
+            .map_or(slug.to_string(), |p| p.display_name.clone());
+        app.state.show_traces(&player_name, traces);
+
+        Ok(())
+    }
+
+    async fn do_load_decision_history(&mut self) -> Result<(), IoAsyncHandlerError> {
+        let history = resolve_trait!(DecisionService).get_history().await?;
+        let mut app = self.app.lock().await;
+        // Current prices, to show how decisions performed
+        let records = history
+            .records
+            .into_iter()
+            .map(|r| {
+                let price = app
+                    .state
+                    .get_player(&r.player_slug)
+                    .and_then(|p| p.price_avg(Currency::Euro, 1));
+                (r, price)
+            })
+            .collect();
+        app.state.show_decision_history(records);
+
+        Ok(())
+    }
+
+    async fn do_notify_decisions(
+        &mut self,
+        decisions: Vec<Decision>,
+    ) -> Result<(), IoAsyncHandlerError> {
+        // Sending fails only if nobody listens
+        let decision_sender = resolve!(DecisionSender);
+        for decision in &decisions {
+            let _ = decision_sender.send(decision.clone());
+        }
+
+        let task_manager = resolve!(MainTaskManager);
+        task_manager
+            .run(Box::new(NotifyDecisionsTask::new(decisions)))
+            .await;
+
+        Ok(())
+    }
+
+    async fn do_export(
+        &mut self,
+        table: ExportTable,
+        format: OutputFormat,
+    ) -> Result<(), IoAsyncHandlerError> {
+        // Tables are exported as displayed
+        let app = self.app.lock().await;
+        let result = match table {
+            ExportTable::Players => {
+                output::export(&self.export_dir, format, app.state.get_players())
+            }
+            ExportTable::Decisions => {
+                output::export(&self.export_dir, format, app.state.get_decisions())
+            }
+        };
+        let path = result.map_err(|e| IoAsyncHandlerError::Export(e.to_string()))?;
+        info!("📄 {:?} exported to {}", table, path.display());
+
+        Ok(())
+    }
+
+    async fn do_load_strategies(&mut self) -> Result<(), IoAsyncHandlerError> {
+        let strategies = resolve_trait!(StrategyService).list().await?;
+        let mut app = self.app.lock().await;
+        app.state.show_strategies(strategies);
+
+        Ok(())
+    }
+
+    async fn do_set_strategy_enabled(
+        &mut self,
+        name: &str,
+        enabled: bool,
+    ) -> Result<(), IoAsyncHandlerError> {
+        let strategy_service = resolve_trait!(StrategyService);
+        strategy_service.set_enabled(name, enabled).await?;
+        info!(
+            "Strategy {} {}",
+            name,
+            if enabled { "enabled" } else { "disabled" }
+        );
+
+        let strategies = strategy_service.list().await?;
+        let mut app = self.app.lock().await;
+        if !enabled {
+            app.state.remove_strategy_decisions(name);
+        }
+        app.state.show_strategies(strategies);
+
+        Ok(())
+    }
+
+    async fn do_load_strategy_params(&mut self, name: &str) -> Result<(), IoAsyncHandlerError> {
+        let params = resolve_trait!(StrategyService)
+            .params(name)
+            .into_iter()
+            .flat_map(|(strategy, values)| {
+                values
+                    .into_iter()
+                    .map(move |(param, value)| (strategy.clone(), param, value))
+            })
+            .collect();
+        let mut app = self.app.lock().await;
+        app.state.show_params(name, params);
+
+        Ok(())
+    }
+
+    async fn do_set_strategy_param(
+        &mut self,
+        name: &str,
+        param: &str,
+        value: Value,
+    ) -> Result<(), IoAsyncHandlerError> {
+        resolve_trait!(StrategyService)
+            .set_param(name, param, value.clone())
+            .await?;
+        info!("Strategy {} parameter {} set to {}", name, param, value);
+
+        // Refresh the parameters popup
+        let popup_strategy = match self.app.lock().await.state.get_popup() {
+            Some(Popup::Params { strategy_name, .. }) => Some(strategy_name.clone()),
+            _ => None,
+        };
+        match popup_strategy {
+            Some(strategy_name) => self.do_load_strategy_params(&strategy_name).await,
+            None => Ok(()),
+        }
+    }
+
     async fn do_load_players_stats(
         &mut self,
         slugs: Vec<String>,
@@ -92,10 +350,13 @@ impl IoAsyncHandler {
         Ok(())
     }
 
-    async fn do_load_player_prices(&mut self, slug: &str) -> Result<(), IoAsyncHandlerError> {
+    async fn do_load_players_prices(
+        &mut self,
+        slugs: Vec<String>,
+    ) -> Result<(), IoAsyncHandlerError> {
         let task_manager = resolve!(MainTaskManager);
         task_manager
-            .run(Box::new(LoadPlayerPricesTask::new(self.app.clone(), slug)))
+            .run(Box::new(LoadPlayerPricesTask::new(self.app.clone(), slugs)))
             .await;
 
         Ok(())
@@ -1,3 +1,9 @@
+use serde_json::Value;
+
+use crate::{core::model::decision::Decision, output::OutputFormat};
+
+use super::state::ExportTable;
+
 pub mod handler;
 
 #[derive(Debug, Clone)]
@@ -5,8 +11,18 @@ pub enum IoEvent {
     Initialize,      // Launch to initialize the application
     //Sleep(Duration), // Just take a little break
     // Refresh TODO
-    LoadPlayerPrices(String),
+    LoadPlayersPrices(Vec<String>),
     LoadPlayersStats(Vec<String>),
     LoadPlayersInjury(Vec<String>),
     RunStrategies(String),
+    LoadStrategies,
+    SetStrategyEnabled(String, bool),
+    LoadStrategyParams(String),
+    /// Strategy name, parameter name and value
+    SetStrategyParam(String, String, Value),
+    EvaluateAll,
+    LoadTraces(String),
+    LoadDecisionHistory,
+    NotifyDecisions(Vec<Decision>),
+    Export(ExportTable, OutputFormat),
 }
\ No newline at end of file
@@ -1,15 +1,26 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
 use strum::IntoEnumIterator;
 
-use crate::core::model::player::Player;
+use crate::{
+    core::model::{
+        config::Config, decision::Decision, params::step_param, player::Player,
+        schedule::RefreshScheduler,
+    },
+    resolve,
+};
 
 use self::{
     action::{Action, Actions},
     input::key::Key,
     io::IoEvent,
-    state::{AppState, Panel},
+    state::{AppState, ExportTable, Panel, Popup, EXPORT_FORMATS},
 };
 
 pub mod action;
+pub mod daemon;
 pub mod input;
 pub mod io;
 pub mod state;
@@ -32,6 +43,10 @@ pub struct App {
     /// State
     is_loading: bool,
     state: AppState,
+    /// Background refresh of player details
+    scheduler: RefreshScheduler,
+    /// A terminal UI is attached (players are selected by the user)
+    ui_attached: bool,
 }
 
 impl App {
@@ -45,6 +60,10 @@ impl App {
             decisions: vec![],
             selected_panel: Panel::Players,
             selected_decision: 0,
+            popup: None,
+            evaluation: None,
+            data_requests: HashMap::new(),
+            context: None,
         };
 
         Self {
@@ -52,9 +71,20 @@ impl App {
             actions,
             state,
             is_loading,
+            scheduler: RefreshScheduler::new(resolve!(Config).refresh.clone()),
+            ui_attached: false,
         }
     }
 
+    /// Marks the app as displayed in a terminal UI (not in daemon mode).
+    pub fn attach_ui(&mut self) {
+        self.ui_attached = true;
+    }
+
+    pub fn is_ui_attached(&self) -> bool {
+        self.ui_attached
+    }
+
     /// Handle a user action
     pub async fn do_action(&mut self, key: Key) -> AppReturn {
         if let Some(action) = self.actions.find(key) {
@@ -67,6 +97,16 @@ impl App {
                 Action::PageDown => self.go_down(20).await,
                 Action::Backspace => self.clear_decisions(),
                 Action::Tab => self.next_panel_selection(),
+                Action::Strategies => self.toggle_strategies().await,
+                Action::Toggle => self.toggle_popup_selection().await,
+                Action::Close => self.close_popup(),
+                Action::EvaluateAll => self.evaluate_all().await,
+                Action::Traces => self.toggle_traces().await,
+                Action::History => self.toggle_decision_history().await,
+                Action::Export => self.toggle_export(),
+                Action::Params => self.toggle_params().await,
+                Action::Decrease => self.change_param(-1).await,
+                Action::Increase => self.change_param(1).await,
             }
         } else {
             log::warn!("No action bound to {}", key);
@@ -84,6 +124,11 @@ impl App {
         };
     }
 
+    /// IO events sender, to dispatch without holding the app lock (the IO handler needs it).
+    pub fn io_sender(&self) -> tokio::sync::mpsc::Sender<IoEvent> {
+        self.io_tx.clone()
+    }
+
     pub fn actions(&self) -> &Actions {
         &self.actions
     }
@@ -95,6 +140,11 @@ impl App {
         self.is_loading
     }
 
+    /// Last background refresh of player details.
+    pub fn last_refresh(&self) -> Option<DateTime<Utc>> {
+        self.scheduler.last_refresh()
+    }
+
     pub async fn initialize(&mut self, players: Vec<Player>) {
         // Update contextual actions
         self.actions = vec![
@@ -105,6 +155,16 @@ impl App {
             Action::PageDown,
             Action::Backspace,
             Action::Tab,
+            Action::Strategies,
+            Action::Toggle,
+            Action::Close,
+            Action::EvaluateAll,
+            Action::Traces,
+            Action::History,
+            Action::Export,
+            Action::Params,
+            Action::Decrease,
+            Action::Increase,
         ]
         .into();
         self.state = AppState::Initialized {
@@ -113,6 +173,10 @@ impl App {
             decisions: vec![],
             selected_panel: Panel::Players,
             selected_decision: 0,
+            popup: None,
+            evaluation: None,
+            data_requests: HashMap::new(),
+            context: None,
         };
         self.refresh_player_prices(0, false).await;
     }
@@ -123,7 +187,7 @@ impl App {
             match players.get(index) {
                 Some(p) => {
                     if force || (!force && p.prices.is_empty()) {
-                        self.dispatch(IoEvent::LoadPlayerPrices(p.slug.to_string()))
+                        self.dispatch(IoEvent::LoadPlayersPrices(vec![p.slug.to_string()]))
                             .await
                     }
                 }
@@ -160,6 +224,139 @@ impl App {
         AppReturn::Continue
     }
 
+    /// Runs strategies over all players.
+    pub async fn evaluate_all(&mut self) -> AppReturn {
+        if self.state.get_evaluation().map_or(false, |e| !e.is_done()) {
+            log::warn!("Evaluation already in progress");
+        } else {
+            self.dispatch(IoEvent::EvaluateAll).await;
+        }
+        AppReturn::Continue
+    }
+
+    /// Opens or closes the strategies popup.
+    pub async fn toggle_strategies(&mut self) -> AppReturn {
+        if matches!(self.state.get_popup(), Some(Popup::Strategies { .. })) {
+            self.state.close_popup();
+        } else {
+            self.dispatch(IoEvent::LoadStrategies).await;
+        }
+        AppReturn::Continue
+    }
+
+    /// Opens or closes the evaluation traces popup of the selected player.
+    pub async fn toggle_traces(&mut self) -> AppReturn {
+        if matches!(self.state.get_popup(), Some(Popup::Traces { .. })) {
+            self.state.close_popup();
+        } else if let Some(player) = self.state.get_selected_player() {
+            let slug = player.slug.clone();
+            self.dispatch(IoEvent::LoadTraces(slug)).await;
+        }
+        AppReturn::Continue
+    }
+
+    /// Opens or closes the decision history popup.
+    pub async fn toggle_decision_history(&mut self) -> AppReturn {
+        if matches!(self.state.get_popup(), Some(Popup::History { .. })) {
+            self.state.close_popup();
+        } else {
+            self.dispatch(IoEvent::LoadDecisionHistory).await;
+        }
+        AppReturn::Continue
+    }
+
+    /// Opens or closes the export popup, for the decisions table if selected (players table otherwise).
+    pub fn toggle_export(&mut self) -> AppReturn {
+        if matches!(self.state.get_popup(), Some(Popup::Export { .. })) {
+            self.state.close_popup();
+        } else if let AppState::Initialized { selected_panel, .. } = &self.state {
+            let table = match selected_panel {
+                Panel::Decisions => ExportTable::Decisions,
+                _ => ExportTable::Players,
+            };
+            self.state.show_export(table);
+        }
+        AppReturn::Continue
+    }
+
+    /// Opens the parameters popup of the strategy selected in the strategies popup, or closes it.
+    pub async fn toggle_params(&mut self) -> AppReturn {
+        let event = match self.state.get_popup() {
+            Some(Popup::Params { .. }) => {
+                self.state.close_popup();
+                None
+            }
+            Some(Popup::Strategies {
+                strategies,
+                selected,
+            }) => strategies
+                .get(*selected)
+                .map(|s| IoEvent::LoadStrategyParams(s.name.clone())),
+            _ => None,
+        };
+        if let Some(event) = event {
+            self.dispatch(event).await;
+        }
+        AppReturn::Continue
+    }
+
+    /// Changes the number parameter selected in the parameters popup.
+    pub async fn change_param(&mut self, steps: i64) -> AppReturn {
+        let event = match self.state.get_popup() {
+            Some(Popup::Params {
+                params, selected, ..
+            }) => params.get(*selected).and_then(|(strategy, param, value)| {
+                step_param(value, steps)
+                    .map(|v| IoEvent::SetStrategyParam(strategy.clone(), param.clone(), v))
+            }),
+            _ => None,
+        };
+        if let Some(event) = event {
+            self.dispatch(event).await;
+        }
+        AppReturn::Continue
+    }
+
+    /// Enables or disables the strategy selected in the strategies popup,
+    /// switches the boolean parameter selected in the parameters popup,
+    /// or exports in the format selected in the export popup.
+    pub async fn toggle_popup_selection(&mut self) -> AppReturn {
+        let event = match self.state.get_popup() {
+            Some(Popup::Strategies {
+                strategies,
+                selected,
+            }) => strategies
+                .get(*selected)
+                .map(|s| IoEvent::SetStrategyEnabled(s.name.clone(), !s.enabled)),
+            Some(Popup::Params {
+                params, selected, ..
+            }) => match params.get(*selected) {
+                Some((strategy, param, Value::Bool(b))) => Some(IoEvent::SetStrategyParam(
+                    strategy.clone(),
+                    param.clone(),
+                    Value::Bool(!b),
+                )),
+                _ => None,
+            },
+            Some(Popup::Export { table, selected }) => EXPORT_FORMATS
+                .get(*selected)
+                .map(|format| IoEvent::Export(*table, *format)),
+            _ => None,
+        };
+        if let Some(event) = event {
+            if matches!(event, IoEvent::Export(..)) {
+                self.state.close_popup();
+            }
+            self.dispatch(event).await;
+        }
+        AppReturn::Continue
+    }
+
+    pub fn close_popup(&mut self) -> AppReturn {
+        self.state.close_popup();
+        AppReturn::Continue
+    }
+
     pub async fn refresh_players_stats(&mut self, player_slugs: &[String]) {
         // Trigger players stats load / update
         if let AppState::Initialized { .. } = &self.state {
@@ -184,6 +381,10 @@ impl App {
     }
 
     pub async fn go_up(&mut self, step: usize) -> AppReturn {
+        if self.state.scroll_popup(-(step as isize)) {
+            return AppReturn::Continue;
+        }
+
         if let AppState::Initialized {
             selected_player,
             selected_panel,
@@ -207,6 +408,10 @@ impl App {
     }
 
     pub async fn go_down(&mut self, step: usize) -> AppReturn {
+        if self.state.scroll_popup(step as isize) {
+            return AppReturn::Continue;
+        }
+
         if let AppState::Initialized {
             players,
             decisions,
@@ -1,9 +1,29 @@
+use std::{collections::HashMap, sync::Arc};
+
+use serde_json::Value;
 use strum_macros::EnumIter;
 
-use crate::core::model::{
-    decision::Decision, injury::Injury, player::Player, price::Price, stats::Stats,
+use crate::{
+    core::model::{
+        context::StrategyContext,
+        decision::Decision,
+        decision_history::DecisionRecord,
+        evaluation::EvaluationProgress,
+        injury::Injury,
+        player::Player,
+        price::Price,
+        requirement::{DataRequirement, Requirements},
+        stats::Stats,
+        strategy::StrategyInfo,
+        trace::EvaluationTrace,
+    },
+    output::OutputFormat,
 };
 
+/// Formats available to export tables
+pub const EXPORT_FORMATS: [OutputFormat; 3] =
+    [OutputFormat::Csv, OutputFormat::Json, OutputFormat::Markdown];
+
 #[derive(Clone, EnumIter, PartialEq, Eq, Copy)]
 pub enum Panel {
     Players,
@@ -12,6 +32,43 @@ pub enum Panel {
     Logs,
 }
 
+/// Table exported from the UI
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportTable {
+    Players,
+    Decisions,
+}
+
+/// Popup displayed over panels
+#[derive(Clone)]
+pub enum Popup {
+    /// Strategies list, to enable or disable them
+    Strategies {
+        strategies: Vec<StrategyInfo>,
+        selected: usize,
+    },
+    /// Last evaluation of each strategy on a player
+    Traces {
+        player_name: String,
+        traces: Vec<EvaluationTrace>,
+        selected: usize,
+    },
+    /// Recorded decisions, with the current price of their player
+    History {
+        records: Vec<(DecisionRecord, Option<f64>)>,
+        selected: usize,
+    },
+    /// Export format selection
+    Export { table: ExportTable, selected: usize },
+    /// Parameters of a strategy (and its parameter sets), to override them
+    Params {
+        strategy_name: String,
+        /// Strategy (or parameter set) name, parameter name and value
+        params: Vec<(String, String, Value)>,
+        selected: usize,
+    },
+}
+
 #[derive(Clone)]
 pub enum AppState {
     Init,
@@ -21,6 +78,13 @@ pub enum AppState {
         selected_player: usize,
         decisions: Vec<Decision>,
         selected_decision: usize,
+        popup: Option<Popup>,
+        /// Last "evaluate all" progress
+        evaluation: Option<EvaluationProgress>,
+        /// Data loads requested for strategies, by player slug
+        data_requests: HashMap<String, Requirements>,
+        /// Context of strategies, computed once until players data changes
+        context: Option<Arc<StrategyContext>>,
     },
     Error(String),
 }
@@ -64,6 +128,198 @@ impl AppState {
         }
     }
 
+    pub fn start_evaluation(&mut self, progress: EvaluationProgress) {
+        if let Self::Initialized { evaluation, .. } = self {
+            *evaluation = Some(progress);
+        }
+    }
+
+    /// Counts an evaluated player, returns the progress once evaluation is done.
+    pub fn evaluation_step(&mut self) -> Option<EvaluationProgress> {
+        if let Self::Initialized {
+            evaluation: Some(progress),
+            ..
+        } = self
+        {
+            progress.evaluated_count += 1;
+            if progress.is_done() {
+                return Some(progress.clone());
+            }
+        }
+        None
+    }
+
+    /// Records data loads requested for a player, returns the ones not requested before.
+    pub fn request_data(&mut self, player_slug: &str, missing: Requirements) -> Requirements {
+        if let Self::Initialized { data_requests, .. } = self {
+            let requested = data_requests.entry(player_slug.to_string()).or_default();
+            let new_requests = missing.difference(requested).copied().collect();
+            requested.extend(missing);
+            new_requests
+        } else {
+            Requirements::new()
+        }
+    }
+
+    /// Forgets failed data loads of players, so that they are requested again.
+    pub fn data_request_failed(&mut self, player_slugs: &[String], requirement: DataRequirement) {
+        if let Self::Initialized { data_requests, .. } = self {
+            for slug in player_slugs {
+                if let Some(requested) = data_requests.get_mut(slug) {
+                    requested.remove(&requirement);
+                }
+            }
+        }
+    }
+
+    pub fn get_evaluation(&self) -> Option<&EvaluationProgress> {
+        if let Self::Initialized { evaluation, .. } = self {
+            evaluation.as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Shows the strategies popup (keeps selection if already displayed).
+    pub fn show_strategies(&mut self, strategies: Vec<StrategyInfo>) {
+        if let Self::Initialized { popup, .. } = self {
+            let selected = match popup {
+                Some(Popup::Strategies { selected, .. }) => {
+                    (*selected).min(strategies.len().saturating_sub(1))
+                }
+                _ => 0,
+            };
+            *popup = Some(Popup::Strategies {
+                strategies,
+                selected,
+            });
+        }
+    }
+
+    /// Shows the parameters popup (keeps selection if already displayed).
+    pub fn show_params(&mut self, strategy_name: &str, params: Vec<(String, String, Value)>) {
+        if let Self::Initialized { popup, .. } = self {
+            let selected = match popup {
+                Some(Popup::Params { selected, .. }) => {
+                    (*selected).min(params.len().saturating_sub(1))
+                }
+                _ => 0,
+            };
+            *popup = Some(Popup::Params {
+                strategy_name: strategy_name.to_string(),
+                params,
+                selected,
+            });
+        }
+    }
+
+    pub fn show_traces(&mut self, player_name: &str, traces: Vec<EvaluationTrace>) {
+        if let Self::Initialized { popup, .. } = self {
+            *popup = Some(Popup::Traces {
+                player_name: player_name.to_string(),
+                traces,
+                selected: 0,
+            });
+        }
+    }
+
+    pub fn show_decision_history(&mut self, records: Vec<(DecisionRecord, Option<f64>)>) {
+        if let Self::Initialized { popup, .. } = self {
+            *popup = Some(Popup::History {
+                records,
+                selected: 0,
+            });
+        }
+    }
+
+    pub fn show_export(&mut self, table: ExportTable) {
+        if let Self::Initialized { popup, .. } = self {
+            *popup = Some(Popup::Export { table, selected: 0 });
+        }
+    }
+
+    pub fn close_popup(&mut self) {
+        if let Self::Initialized { popup, .. } = self {
+            *popup = None;
+        }
+    }
+
+    pub fn get_popup(&self) -> Option<&Popup> {
+        if let Self::Initialized { popup, .. } = self {
+            popup.as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Moves popup selection, returns `false` if no popup is displayed.
+    pub fn scroll_popup(&mut self, step: isize) -> bool {
+        let (len, selected) = match self {
+            Self::Initialized {
+                popup:
+                    Some(Popup::Strategies {
+                        strategies,
+                        selected,
+                    }),
+                ..
+            } => (strategies.len(), selected),
+            Self::Initialized {
+                popup: Some(Popup::Traces {
+                    traces, selected, ..
+                }),
+                ..
+            } => (traces.len(), selected),
+            Self::Initialized {
+                popup: Some(Popup::History { records, selected }),
+                ..
+            } => (records.len(), selected),
+            Self::Initialized {
+                popup: Some(Popup::Export { selected, .. }),
+                ..
+            } => (EXPORT_FORMATS.len(), selected),
+            Self::Initialized {
+                popup: Some(Popup::Params {
+                    params, selected, ..
+                }),
+                ..
+            } => (params.len(), selected),
+            _ => return false,
+        };
+        let max = len.saturating_sub(1) as isize;
+        *selected = (*selected as isize + step).clamp(0, max) as usize;
+        true
+    }
+
+    /// Removes decisions of a strategy, or of a single parameter set (such as when disabled).
+    pub fn remove_strategy_decisions(&mut self, strategy_name: &str) {
+        if let Self::Initialized {
+            decisions,
+            selected_decision,
+            ..
+        } = self
+        {
+            // Parameter sets of the strategy are named `<name>:<set>`
+            decisions.retain(|d| {
+                d.strategy != strategy_name
+                    && !d.strategy.starts_with(&format!("{}:", strategy_name))
+            });
+            *selected_decision = (*selected_decision).min(decisions.len().saturating_sub(1));
+        }
+    }
+
+    pub fn get_selected_player(&self) -> Option<&Player> {
+        if let Self::Initialized {
+            players,
+            selected_player,
+            ..
+        } = self
+        {
+            players.get(*selected_player)
+        } else {
+            None
+        }
+    }
+
     pub fn get_player(&self, player_slug: &str) -> Option<&Player> {
         if let Self::Initialized { players, .. } = self {
             players.iter().find(|p| p.slug == player_slug)
@@ -72,8 +328,42 @@ impl AppState {
         }
     }
 
-    pub fn merge_prices(&mut self, player_slug: &str, prices: Vec<Price>) {
+    pub fn get_players(&self) -> &[Player] {
         if let Self::Initialized { players, .. } = self {
+            players
+        } else {
+            &[]
+        }
+    }
+
+    pub fn get_decisions(&self) -> &[Decision] {
+        if let Self::Initialized { decisions, .. } = self {
+            decisions
+        } else {
+            &[]
+        }
+    }
+
+    /// Context of strategies, shared by strategy runs until players data changes.
+    pub fn strategy_context(&mut self) -> Arc<StrategyContext> {
+        if let Self::Initialized {
+            players, context, ..
+        } = self
+        {
+            context
+                .get_or_insert_with(|| Arc::new(StrategyContext::new(players)))
+                .clone()
+        } else {
+            Arc::new(StrategyContext::default())
+        }
+    }
+
+    pub fn merge_prices(&mut self, player_slug: &str, prices: Vec<Price>) {
+        if let Self::Initialized {
+            players, context, ..
+        } = self
+        {
+            *context = None;
             match players.iter_mut().find(|p| p.slug == player_slug) {
                 Some(p) => p.prices = prices,
                 None => (),
@@ -82,7 +372,11 @@ impl AppState {
     }
 
     pub fn merge_stats(&mut self, stats: Vec<Stats>) {
-        if let Self::Initialized { players, .. } = self {
+        if let Self::Initialized {
+            players, context, ..
+        } = self
+        {
+            *context = None;
             for s in stats {
                 match players.iter_mut().find(|p| p.slug == s.player_slug) {
                     Some(p) => p.stats = Some(s),
@@ -93,11 +387,16 @@ impl AppState {
     }
 
     pub fn merge_injuries(&mut self, player_slugs: &[String], injuries: Vec<Injury>) {
-        if let Self::Initialized { players, .. } = self {
+        if let Self::Initialized {
+            players, context, ..
+        } = self
+        {
+            *context = None;
             for s in player_slugs {
                 match players.iter_mut().find(|p| &p.slug == s) {
                     Some(p) => {
-                        p.injury = injuries.iter().find(|i| p.slug == i.player_slug).cloned()
+                        p.injury = injuries.iter().find(|i| p.slug == i.player_slug).cloned();
+                        p.injury_loaded = true;
                     }
                     None => (),
                 }
@@ -105,8 +404,26 @@ impl AppState {
         }
     }
 
-    pub fn merge_decisions(&mut self, player_slug: &str, player_decisions: Vec<Decision>) {
+    /// Replaces the decisions of a player, returns decisions not emitted before
+    /// (by the same strategy, with the same action).
+    pub fn merge_decisions(
+        &mut self,
+        player_slug: &str,
+        player_decisions: Vec<Decision>,
+    ) -> Vec<Decision> {
         if let Self::Initialized { decisions, .. } = self {
+            let added = player_decisions
+                .iter()
+                .filter(|d| {
+                    !decisions.iter().any(|p| {
+                        p.player_slug == player_slug
+                            && p.strategy == d.strategy
+                            && p.action == d.action
+                    })
+                })
+                .cloned()
+                .collect();
+
             //Filter previous decisions for player
             let mut new_decisions: Vec<Decision> = decisions
                 .iter()
@@ -118,6 +435,9 @@ impl AppState {
             new_decisions.append(&mut player_decisions.clone());
 
             *decisions = new_decisions;
+            added
+        } else {
+            vec![]
         }
     }
 }
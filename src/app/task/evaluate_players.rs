@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use quartermaster::task::Task;
+
+use crate::{
+    app::App,
+    core::{
+        metrics::metrics,
+        model::{context::StrategyContext, player::Player},
+        service::{decision::DecisionService, strategy::StrategyService},
+    },
+    resolve_trait,
+};
+
+/// Runs strategies over a batch of players, as part of an "evaluate all".
+pub struct EvaluatePlayersTask {
+    app: Arc<tokio::sync::Mutex<App>>,
+    index: usize,
+    players: Vec<Player>,
+    context: Arc<StrategyContext>,
+}
+impl EvaluatePlayersTask {
+    pub fn new(
+        app: Arc<tokio::sync::Mutex<App>>,
+        index: usize,
+        players: Vec<Player>,
+        context: Arc<StrategyContext>,
+    ) -> Self {
+        Self {
+            app,
+            index,
+            players,
+            context,
+        }
+    }
+}
+
+#[async_trait]
+impl Task for EvaluatePlayersTask {
+    fn name(&self) -> String {
+        "evaluate players".to_string()
+    }
+
+    fn id(&self) -> String {
+        format!("evaluate players {}", self.index)
+    }
+
+    async fn run(&self) {
+        let _timer = metrics().task_timer(&self.name());
+        let strategy_service = resolve_trait!(StrategyService);
+        let decision_service = resolve_trait!(DecisionService);
+
+        for player in &self.players {
+            let result = strategy_service.run_all(player, &self.context).await;
+            if let Ok(decisions) = &result {
+                if let Err(e) = decision_service.record(player, decisions).await {
+                    metrics().error("decision");
+                    log::error!("Failed to record decisions: {}", e.to_string());
+                }
+            }
+
+            let mut app = self.app.lock().await;
+            let added = match result {
+                Ok(decisions) => app.state.merge_decisions(&player.slug, decisions),
+                Err(e) => {
+                    metrics().task_failed(&self.name());
+                    log::error!(
+                        "Failed to run strategies on {}: {}",
+                        player.slug,
+                        e.to_string()
+                    );
+                    vec![]
+                }
+            };
+            if let Some(progress) = app.state.evaluation_step() {
+                log::info!(
+                    "Evaluation done: {} players evaluated, {} skipped (missing data)",
+                    progress.evaluated_count,
+                    progress.skipped_count
+                );
+            }
+            let io_tx = app.io_sender();
+            std::mem::drop(app);
+
+            super::notify_decisions(io_tx, added).await;
+        }
+    }
+}
@@ -3,18 +3,20 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use quartermaster::task::Task;
 
-use crate::{app::App, core::service::price::PriceService, resolve_trait};
+use crate::{
+    app::App,
+    core::{metrics::metrics, model::requirement::DataRequirement, service::price::PriceService},
+    resolve_trait,
+};
 
+/// Loads prices of players, with batch requests.
 pub struct LoadPlayerPricesTask {
     app: Arc<tokio::sync::Mutex<App>>,
-    slug: String,
+    slugs: Vec<String>,
 }
 impl LoadPlayerPricesTask {
-    pub fn new(app: Arc<tokio::sync::Mutex<App>>, slug: &str) -> Self {
-        Self {
-            app,
-            slug: slug.to_string(),
-        }
+    pub fn new(app: Arc<tokio::sync::Mutex<App>>, slugs: Vec<String>) -> Self {
+        Self { app, slugs }
     }
 }
 
@@ -25,28 +27,55 @@ impl Task for LoadPlayerPricesTask {
     }
 
     fn id(&self) -> String {
-        self.slug.to_string()
+        if self.slugs.is_empty() {
+            "empty".to_string()
+        } else {
+            format!(
+                "{}-{}",
+                self.slugs.first().unwrap(),
+                self.slugs.last().unwrap()
+            )
+        }
     }
 
     async fn run(&self) {
+        let _timer = metrics().task_timer(&self.name());
         let price_service = resolve_trait!(PriceService);
 
         // Get prices
-        match price_service.get_prices(&self.slug).await {
-            Ok(prices) => {
-                // Update player in app state
-                let mut app = self.app.lock().await;
-                app.state.merge_prices(&self.slug,prices);
-                 // After update: run strategies
-                 app.run_strategies(&self.slug.clone()).await;
-            }
-            Err(err) => {
-                log::error!(
-                    "Failed to load prices for {}: {}",
-                    &self.slug,
-                    err.to_string()
-                )
-            }
-        };
+        let batch = price_service.get_prices_batch(&self.slugs).await;
+        if !batch.errors.is_empty() {
+            metrics().task_failed(&self.name());
+        }
+        for (slugs, err) in &batch.errors {
+            log::error!("Failed to load prices for {}: {}", slugs.join(", "), err);
+        }
+
+        // Update players in app state
+        let mut app = self.app.lock().await;
+        if batch.prices.is_empty() && !batch.errors.is_empty() {
+            // Nothing loaded: slow down refreshes (such as when rate limited)
+            app.scheduler.failed();
+        }
+        for (slugs, _) in &batch.errors {
+            // Requested again on next strategies run
+            app.state
+                .data_request_failed(slugs, DataRequirement::Prices);
+        }
+        let mut slugs = vec![];
+        for (slug, prices) in batch.prices {
+            let changed = app
+                .state
+                .get_player(&slug)
+                .map_or(true, |p| p.prices != prices);
+            app.scheduler.refreshed(&slug, changed);
+            app.state.merge_prices(&slug, prices);
+            slugs.push(slug);
+        }
+        let io_tx = app.io_sender();
+        std::mem::drop(app);
+
+        // After update: run strategies
+        super::run_strategies(io_tx, slugs).await;
     }
 }
@@ -5,7 +5,10 @@ use quartermaster::task::Task;
 
 use crate::{
     app::App,
-    core::service::player::{PlayerError, PlayerService},
+    core::{
+        metrics::metrics,
+        service::player::{PlayerError, PlayerService},
+    },
     resolve_trait,
 };
 
@@ -29,6 +32,7 @@ impl LoadPlayersTask {
                 Ok(())
             }
             Err(e) => {
+                metrics().task_failed(&self.name());
                 // If failed to load : retry after storage clear
                 log::error!("Failed to load players: {}", e.to_string());
                 Err(e)
@@ -48,6 +52,7 @@ impl Task for LoadPlayersTask {
     }
 
     async fn run(&self) {
+        let _timer = metrics().task_timer(&self.name());
         let player_service = resolve_trait!(PlayerService);
 
         let mut result = self.load_players().await;
@@ -3,7 +3,11 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use quartermaster::task::Task;
 
-use crate::{app::App, core::service::stats::StatsService, resolve_trait};
+use crate::{
+    app::App,
+    core::{metrics::metrics, model::requirement::DataRequirement, service::stats::StatsService},
+    resolve_trait,
+};
 
 pub struct LoadPlayersStatsTask {
     app: Arc<tokio::sync::Mutex<App>>,
@@ -34,6 +38,7 @@ impl Task for LoadPlayersStatsTask {
     }
 
     async fn run(&self) {
+        let _timer = metrics().task_timer(&self.name());
         let stats_service = resolve_trait!(StatsService);
 
         // Get stats
@@ -42,12 +47,19 @@ impl Task for LoadPlayersStatsTask {
                 // Update player in app state
                 let mut app = self.app.lock().await;
                 app.state.merge_stats(stats);
+                let io_tx = app.io_sender();
+                std::mem::drop(app);
+
                 // After update: run strategies
-                for s in &self.slugs {
-                    app.run_strategies(&s.clone()).await;
-                }
+                super::run_strategies(io_tx, self.slugs.clone()).await;
             }
             Err(err) => {
+                metrics().task_failed(&self.name());
+                let mut app = self.app.lock().await;
+                app.scheduler.failed();
+                // Requested again on next strategies run
+                app.state
+                    .data_request_failed(&self.slugs, DataRequirement::Stats);
                 log::error!(
                     "Failed to load stats for {}: {}",
                     &self.id(),
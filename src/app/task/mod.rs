@@ -3,4 +3,32 @@ pub mod load_player_prices;
 pub mod refresh_players_details;
 pub mod load_players_stats;
 pub mod load_players_injury;
-pub mod run_strategies;
\ No newline at end of file
+pub mod run_strategies;
+pub mod evaluate_players;
+pub mod notify_decisions;
+
+use tokio::sync::mpsc::Sender;
+
+use crate::core::model::decision::Decision;
+
+use super::io::IoEvent;
+
+/// Runs strategies on updated players: called once the app is unlocked, as the IO handler
+/// needs the lock before reading the next events.
+pub(crate) async fn run_strategies(io_tx: Sender<IoEvent>, slugs: Vec<String>) {
+    for slug in slugs {
+        if let Err(e) = io_tx.send(IoEvent::RunStrategies(slug)).await {
+            log::error!("Error from dispatch {}", e);
+        }
+    }
+}
+
+/// Notifies new decisions: called once the app is unlocked, as for `run_strategies`.
+pub(crate) async fn notify_decisions(io_tx: Sender<IoEvent>, decisions: Vec<Decision>) {
+    if decisions.is_empty() {
+        return;
+    }
+    if let Err(e) = io_tx.send(IoEvent::NotifyDecisions(decisions)).await {
+        log::error!("Error from dispatch {}", e);
+    }
+}
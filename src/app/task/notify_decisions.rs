@@ -0,0 +1,49 @@
+use async_trait::async_trait;
+use quartermaster::task::Task;
+
+use crate::{
+    core::{
+        metrics::metrics, model::decision::Decision, service::notification::NotificationService,
+    },
+    resolve_trait,
+};
+
+/// Sends notifications for new decisions (sinks can be slow: not run in the IO thread).
+pub struct NotifyDecisionsTask {
+    decisions: Vec<Decision>,
+}
+impl NotifyDecisionsTask {
+    pub fn new(decisions: Vec<Decision>) -> Self {
+        Self { decisions }
+    }
+}
+
+#[async_trait]
+impl Task for NotifyDecisionsTask {
+    fn name(&self) -> String {
+        "notify decisions".to_string()
+    }
+
+    fn id(&self) -> String {
+        self.decisions
+            .iter()
+            .map(|d| format!("{}-{}", d.player_slug, d.strategy))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    async fn run(&self) {
+        let _timer = metrics().task_timer(&self.name());
+        let notification_service = resolve_trait!(NotificationService);
+
+        match notification_service.notify(&self.decisions).await {
+            Ok(count) if count > 0 => log::info!("{} notification(s) sent", count),
+            Ok(_) => (),
+            Err(e) => {
+                metrics().error("notification");
+                metrics().task_failed(&self.name());
+                log::error!("Failed to send notifications: {}", e.to_string())
+            }
+        }
+    }
+}
@@ -1,10 +1,15 @@
-use std::{sync::Arc, time::Duration};
+use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use quartermaster::task::Task;
 use tokio::time::sleep;
 
-use crate::app::{state::AppState, App};
+use crate::{
+    app::{io::IoEvent, state::AppState, App},
+    core::model::config::Config,
+    resolve,
+};
 
 pub struct RefreshPlayersDetailsTask {
     app: Arc<tokio::sync::Mutex<App>>,
@@ -26,40 +31,62 @@ impl Task for RefreshPlayersDetailsTask {
     }
 
     async fn run(&self) {
-        let mut index = 0;
-
-        let mut players_slugs_for_refresh = vec![];
+        let batch_size = resolve!(Config).refresh.batch_size;
 
         loop {
-            // Iterate on players every X secs and schedule a load prices task
+            // Schedule load tasks for the next due players: prices, stats and injury are bulk refreshed
             let mut app = self.app.lock().await;
-            if let AppState::Initialized { players, .. } = &app.state {
-                let player_count = players.len();
-                if let Some(p) = players.get(index) {
-                    let slug = p.slug.clone();
-                    players_slugs_for_refresh.push(slug.clone());
-                    app.refresh_player_prices(index, true).await;
-                    if (index + 1) == player_count {
-                        index = 0;
-                    } else {
-                        index += 1;
-                    }
-                    // Every 5, refreshes, player stats and injury are bulk refreshed
-                    if players_slugs_for_refresh.len() == 5 {
-                        
-                        app.refresh_players_stats(&players_slugs_for_refresh).await;
-                        app.refresh_players_injury(&players_slugs_for_refresh).await;
-
-                        players_slugs_for_refresh.clear();
-                    }
-
+            let now = Utc::now();
+            let slugs = next_players(&app, now, batch_size);
+            let mut events = vec![];
+            if !slugs.is_empty() {
+                for slug in &slugs {
+                    app.scheduler.refreshing(slug, now);
                 }
-              
+                events.push(IoEvent::LoadPlayersPrices(slugs.clone()));
+                events.push(IoEvent::LoadPlayersStats(slugs.clone()));
+                events.push(IoEvent::LoadPlayersInjury(slugs));
             }
+            let delay = app.scheduler.delay();
+            let io_tx = app.io_sender();
             std::mem::drop(app);
 
+            // Dispatch once unlocked (the IO handler needs the lock)
+            for event in events {
+                if let Err(e) = io_tx.send(event).await {
+                    log::error!("Error from dispatch {}", e);
+                }
+            }
+
             // Wait
-            sleep(Duration::from_secs(3)).await;
+            sleep(delay).await;
         }
     }
 }
+
+/// Next players to refresh, by priority (selected, watched, with decisions).
+/// Without UI (daemon mode), no player is selected.
+fn next_players(app: &App, now: DateTime<Utc>, count: usize) -> Vec<String> {
+    if let AppState::Initialized { players, .. } = &app.state {
+        let selected = if app.is_ui_attached() {
+            app.state.get_selected_player().map(|p| p.slug.as_str())
+        } else {
+            None
+        };
+        let decisions = app.state.get_decisions();
+        let candidates = players
+            .iter()
+            .map(|p| {
+                let priority = app.scheduler.priority(
+                    &p.slug,
+                    selected == Some(p.slug.as_str()),
+                    decisions.iter().any(|d| d.player_slug == p.slug),
+                );
+                (p.slug.clone(), priority)
+            })
+            .collect::<Vec<_>>();
+        app.scheduler.next(&candidates, now, count)
+    } else {
+        vec![]
+    }
+}
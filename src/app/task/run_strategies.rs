@@ -5,17 +5,30 @@ use quartermaster::task::Task;
 
 use crate::{
     app::App,
-    core::{model::player::Player, service::strategy::StrategyService},
+    core::{
+        metrics::metrics,
+        model::{context::StrategyContext, player::Player},
+        service::{decision::DecisionService, strategy::StrategyService},
+    },
     resolve_trait,
 };
 
 pub struct RunStrategiesTask {
     app: Arc<tokio::sync::Mutex<App>>,
     player: Player,
+    context: Arc<StrategyContext>,
 }
 impl RunStrategiesTask {
-    pub fn new(app: Arc<tokio::sync::Mutex<App>>, player: Player) -> Self {
-        Self { app, player }
+    pub fn new(
+        app: Arc<tokio::sync::Mutex<App>>,
+        player: Player,
+        context: Arc<StrategyContext>,
+    ) -> Self {
+        Self {
+            app,
+            player,
+            context,
+        }
     }
 }
 
@@ -30,14 +43,36 @@ impl Task for RunStrategiesTask {
     }
 
     async fn run(&self) {
+        let _timer = metrics().task_timer(&self.name());
         let strategy_service = resolve_trait!(StrategyService);
 
-        match strategy_service.run_all(&self.player).await {
+        match strategy_service.run_all(&self.player, &self.context).await {
             Ok(decisions) => {
+                if let Err(e) = resolve_trait!(DecisionService)
+                    .record(&self.player, &decisions)
+                    .await
+                {
+                    metrics().error("decision");
+                    log::error!("Failed to record decisions: {}", e.to_string());
+                }
                 let mut app = self.app.lock().await;
-                app.state.merge_decisions(&self.player.slug, decisions);
+                let added = app.state.merge_decisions(&self.player.slug, decisions);
+                for d in &added {
+                    log::info!(
+                        "{} {} ({}): {}",
+                        d.action,
+                        d.player_name,
+                        d.strategy,
+                        d.comment
+                    );
+                }
+                let io_tx = app.io_sender();
+                std::mem::drop(app);
+
+                super::notify_decisions(io_tx, added).await;
             }
             Err(e) => {
+                metrics().task_failed(&self.name());
                 log::error!(
                     "Failed to run strategies on {}: {}",
                     self.player.slug,
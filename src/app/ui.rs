@@ -5,10 +5,13 @@ use ratatui::{
 };
 
 use super::{
-    state::{AppState, Panel},
+    state::{AppState, Panel, Popup},
     widget::{
-        decisions_table::DecisionsTable, header::Header, logs_panel::LogsPanel,
-        player_details::PlayerDetails, players_table::PlayersTable, Renderable,
+        decisions_table::DecisionsTable, evaluation_progress::EvaluationProgressBar,
+        header::Header, logs_panel::LogsPanel,
+        player_details::PlayerDetails, players_table::PlayersTable,
+        export_popup::ExportPopup, history_popup::HistoryPopup, strategies_popup::StrategiesPopup,
+        traces_popup::TracesPopup, params_popup::ParamsPopup, Renderable,
     },
     App,
 };
@@ -55,6 +58,11 @@ where
     let mut header = Header {};
     header.render(rect, master_layout[0]);
 
+    // Evaluation progress
+    if let Some(progress) = app.state.get_evaluation() {
+        EvaluationProgressBar::new(progress.clone()).render(rect, master_layout[1]);
+    }
+
     // Players horizontal layout (list + details)
     let player_layout = Layout::default()
         .direction(Direction::Horizontal)
@@ -125,4 +133,29 @@ where
         LogsPanel::new(false)
     };
     logs_panel.render(rect, master_layout[4]);
+
+    // Popup
+    match app.state.get_popup() {
+        Some(Popup::Strategies {
+            strategies,
+            selected,
+        }) => StrategiesPopup::new(strategies.clone(), *selected).render(rect, size),
+        Some(Popup::Traces {
+            player_name,
+            traces,
+            selected,
+        }) => TracesPopup::new(player_name, traces.clone(), *selected).render(rect, size),
+        Some(Popup::History { records, selected }) => {
+            HistoryPopup::new(records.clone(), *selected).render(rect, size)
+        }
+        Some(Popup::Export { table, selected }) => {
+            ExportPopup::new(*table, *selected).render(rect, size)
+        }
+        Some(Popup::Params {
+            strategy_name,
+            params,
+            selected,
+        }) => ParamsPopup::new(strategy_name, params.clone(), *selected).render(rect, size),
+        None => (),
+    }
 }
@@ -0,0 +1,51 @@
+use ratatui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Style},
+    symbols,
+    widgets::LineGauge,
+    Frame,
+};
+
+use crate::core::model::evaluation::EvaluationProgress;
+
+use super::Renderable;
+
+pub struct EvaluationProgressBar {
+    progress: EvaluationProgress,
+}
+
+impl EvaluationProgressBar {
+    pub fn new(progress: EvaluationProgress) -> Self {
+        Self { progress }
+    }
+}
+
+impl Renderable for EvaluationProgressBar {
+    fn render<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let p = &self.progress;
+        let label = format!(
+            "{} {}/{} players ({} skipped: missing prices or stats)",
+            if p.is_done() {
+                "Evaluated"
+            } else {
+                "Evaluating"
+            },
+            p.evaluated_count + p.skipped_count,
+            p.player_count,
+            p.skipped_count
+        );
+
+        let gauge = LineGauge::default()
+            .gauge_style(Style::default().fg(if p.is_done() {
+                Color::Green
+            } else {
+                Color::Yellow
+            }))
+            .line_set(symbols::line::THICK)
+            .label(label)
+            .ratio(p.ratio().clamp(0.0, 1.0));
+
+        f.render_widget(gauge, area);
+    }
+}
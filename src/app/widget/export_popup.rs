@@ -0,0 +1,83 @@
+use ratatui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, BorderType, Borders, Cell, Clear, Row, Table, TableState},
+    Frame,
+};
+
+use crate::{
+    app::state::{ExportTable, EXPORT_FORMATS},
+    output::OutputFormat,
+};
+
+use super::{centered_rect, Renderable};
+
+pub struct ExportPopup {
+    state: TableState,
+    table: ExportTable,
+}
+
+impl ExportPopup {
+    pub fn new(table: ExportTable, selection: usize) -> Self {
+        let mut state = TableState::default();
+        state.select(Some(selection));
+        Self { state, table }
+    }
+}
+
+fn format_name(format: &OutputFormat) -> &'static str {
+    match format {
+        OutputFormat::Csv => "CSV",
+        OutputFormat::Json => "JSON",
+        OutputFormat::Markdown => "Markdown",
+        OutputFormat::Table => "Text",
+        OutputFormat::Ndjson => "NDJSON",
+    }
+}
+
+impl Renderable for ExportPopup {
+    fn render<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let area = centered_rect(50, 30, area);
+
+        let rows: Vec<Row> = EXPORT_FORMATS
+            .iter()
+            .map(|format| {
+                Row::new(vec![
+                    Cell::from(format_name(format)),
+                    Cell::from(format!(".{}", format.extension())),
+                ])
+            })
+            .collect();
+
+        let title = match self.table {
+            ExportTable::Players => {
+                "Export players (⬆⬇ to browse) (ENTER to export) (ESC to close)"
+            }
+            ExportTable::Decisions => {
+                "Export decisions (⬆⬇ to browse) (ENTER to export) (ESC to close)"
+            }
+        };
+        let table = Table::new(rows)
+            .header(Row::new(vec!["Format", "File"]).style(Style::default().fg(Color::Yellow)))
+            .block(
+                Block::default()
+                    .title(title)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(Color::Yellow)),
+            )
+            .widths(&[Constraint::Percentage(60), Constraint::Percentage(30)])
+            .column_spacing(1)
+            .highlight_style(
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .fg(Color::White),
+            )
+            .highlight_symbol(" 📄  ");
+
+        // Clear panels below the popup
+        f.render_widget(Clear, area);
+        f.render_stateful_widget(table, area, &mut self.state)
+    }
+}
@@ -0,0 +1,110 @@
+use chrono::DateTime;
+use ratatui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, BorderType, Borders, Cell, Clear, Row, Table, TableState},
+    Frame,
+};
+
+use crate::core::model::decision_history::DecisionRecord;
+
+use super::{centered_rect, Renderable};
+
+pub struct HistoryPopup {
+    state: TableState,
+    records: Vec<(DecisionRecord, Option<f64>)>,
+}
+
+impl HistoryPopup {
+    pub fn new(records: Vec<(DecisionRecord, Option<f64>)>, selection: usize) -> Self {
+        let mut state = TableState::default();
+        state.select(Some(selection));
+        Self { state, records }
+    }
+}
+
+fn format_date(date: &str) -> String {
+    match DateTime::parse_from_rfc3339(date) {
+        Ok(d) => d.format("%m/%d %H:%M").to_string(),
+        Err(_) => "-".to_string(),
+    }
+}
+
+fn format_price(price: Option<f64>) -> String {
+    price.map_or("-".to_string(), |p| format!("{:.2}€", p))
+}
+
+impl Renderable for HistoryPopup {
+    fn render<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let area = centered_rect(90, 70, area);
+
+        let rows: Vec<Row> = self
+            .records
+            .iter()
+            .map(|(record, current_price)| {
+                let change = record
+                    .price_change(*current_price)
+                    .map_or("-".to_string(), |c| format!("{:+.1}%", c * 100.0));
+                let color = match record.is_successful(*current_price) {
+                    Some(true) => Color::Green,
+                    Some(false) => Color::Red,
+                    None => Color::Reset,
+                };
+                Row::new(vec![
+                    Cell::from(format_date(&record.first_seen)),
+                    Cell::from(format_date(&record.last_seen)),
+                    Cell::from(record.player_name.clone()),
+                    Cell::from(record.strategy.clone()),
+                    Cell::from(record.action.clone()),
+                    Cell::from(format_price(record.price)),
+                    Cell::from(format_price(*current_price)),
+                    Cell::from(change).style(Style::default().fg(color)),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(rows)
+            .header(
+                Row::new(vec![
+                    "First seen",
+                    "Last seen",
+                    "Player",
+                    "Strategy",
+                    "Action",
+                    "Price",
+                    "Now",
+                    "Change",
+                ])
+                .style(Style::default().fg(Color::Yellow)),
+            )
+            .block(
+                Block::default()
+                    .title("Decision history (⬆⬇ to browse) (ESC to close)")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(Color::Yellow)),
+            )
+            .widths(&[
+                Constraint::Length(11),
+                Constraint::Length(11),
+                Constraint::Percentage(20),
+                Constraint::Percentage(20),
+                Constraint::Length(8),
+                Constraint::Length(9),
+                Constraint::Length(9),
+                Constraint::Length(8),
+            ])
+            .column_spacing(1)
+            .highlight_style(
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .fg(Color::White),
+            )
+            .highlight_symbol(" 📜  ");
+
+        // Clear panels below the popup
+        f.render_widget(Clear, area);
+        f.render_stateful_widget(table, area, &mut self.state)
+    }
+}
@@ -1,4 +1,8 @@
-use ratatui::{backend::Backend, Frame, layout::Rect};
+use ratatui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    Frame,
+};
 
 pub mod players_table;
 pub mod logs_panel;
@@ -6,7 +10,34 @@ pub mod decisions_table;
 pub mod header;
 pub mod player_details;
 pub mod label;
+pub mod evaluation_progress;
+pub mod strategies_popup;
+pub mod traces_popup;
+pub mod history_popup;
+pub mod export_popup;
+pub mod params_popup;
 
 pub trait Renderable {
     fn render<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect);
+}
+
+/// Area of a popup, centered in `area` (sizes in percent).
+pub fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(area);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(vertical[1])[1]
 }
\ No newline at end of file
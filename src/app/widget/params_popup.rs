@@ -0,0 +1,94 @@
+use ratatui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, BorderType, Borders, Cell, Clear, Row, Table, TableState},
+    Frame,
+};
+use serde_json::Value;
+
+use super::{centered_rect, Renderable};
+
+pub struct ParamsPopup {
+    state: TableState,
+    strategy_name: String,
+    /// Strategy (or parameter set) name, parameter name and value
+    params: Vec<(String, String, Value)>,
+}
+
+impl ParamsPopup {
+    pub fn new(
+        strategy_name: &str,
+        params: Vec<(String, String, Value)>,
+        selection: usize,
+    ) -> Self {
+        let mut state = TableState::default();
+        state.select(Some(selection));
+        Self {
+            state,
+            strategy_name: strategy_name.to_string(),
+            params,
+        }
+    }
+}
+
+impl Renderable for ParamsPopup {
+    fn render<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let area = centered_rect(70, 50, area);
+
+        let rows: Vec<Row> = self
+            .params
+            .iter()
+            .map(|(strategy, name, value)| {
+                Row::new(vec![
+                    Cell::from(strategy.clone()),
+                    Cell::from(name.clone()),
+                    Cell::from(match value {
+                        Value::String(s) => s.clone(),
+                        other => other.to_string(),
+                    }),
+                ])
+            })
+            .collect();
+
+        let title = if self.params.is_empty() {
+            format!(
+                "{} parameters (none, or not evaluated yet) (ESC to close)",
+                self.strategy_name
+            )
+        } else {
+            format!(
+                "{} parameters (⬆⬇ to browse) (⬅➡ to change numbers) (SPACE to switch booleans) (ESC to close)",
+                self.strategy_name
+            )
+        };
+        let table = Table::new(rows)
+            .header(
+                Row::new(vec!["Strategy", "Parameter", "Value"])
+                    .style(Style::default().fg(Color::Yellow)),
+            )
+            .block(
+                Block::default()
+                    .title(title)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(Color::Yellow)),
+            )
+            .widths(&[
+                Constraint::Percentage(40),
+                Constraint::Percentage(30),
+                Constraint::Percentage(25),
+            ])
+            .column_spacing(1)
+            .highlight_style(
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .fg(Color::White),
+            )
+            .highlight_symbol(" ⚙  ");
+
+        // Clear panels below the popup
+        f.render_widget(Clear, area);
+        f.render_stateful_widget(table, area, &mut self.state)
+    }
+}
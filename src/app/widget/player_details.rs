@@ -5,7 +5,10 @@ use ratatui::{
     widgets::{block::Title, Bar, BarChart, BarGroup, Block, BorderType, Borders, Paragraph},
 };
 
-use crate::core::model::{currency::Currency, player::Player};
+use crate::{
+    core::model::{config::Config, currency::Currency, player::Player},
+    resolve,
+};
 
 use super::{label::Label, Renderable};
 
@@ -322,12 +325,14 @@ impl PlayerDetails {
         }
         last_sale_price.render(f, layout[0]);
 
-        // Last 5 Sales average
-        let mut avg_sale_price = Label::new(Some("Last 5 Sales Avg.".into()), None);
+        // Last sales average
+        let price_avg_count = resolve!(Config).ui.price_avg_count;
+        let avg_caption = format!("Last {} Sales Avg.", price_avg_count);
+        let mut avg_sale_price = Label::new(Some(avg_caption.clone()), None);
         if let Some(player) = &self.player {
             avg_sale_price = Label::new(
-                Some("Last 5 Sales Avg.".into()),
-                Some(match player.price_avg(Currency::Euro, 5) {
+                Some(avg_caption),
+                Some(match player.price_avg(Currency::Euro, price_avg_count) {
                     Some(avg) => format!("{:.2} €", avg),
                     None => "-".to_string(),
                 }),
@@ -431,7 +436,7 @@ impl Renderable for PlayerDetails {
         let block = Block::default()
             .borders(Borders::ALL)
             .title(if self.focused {
-                "Player (TAB to switch panel)"
+                "Player (TAB to switch panel) (T to show strategies trace)"
             } else {
                 "Player"
             })
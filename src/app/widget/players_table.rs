@@ -6,7 +6,10 @@ use ratatui::{
     Frame,
 };
 
-use crate::core::model::{currency::Currency, player::Player};
+use crate::{
+    core::model::{config::Config, currency::Currency, player::Player},
+    resolve,
+};
 
 use super::Renderable;
 
@@ -30,12 +33,13 @@ impl PlayersTable {
 
 impl Renderable for PlayersTable {
     fn render<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let price_avg_count = resolve!(Config).ui.price_avg_count;
         let rows: Vec<Row> = self
             .players
             .iter()
             .map(|p| {
                 let price_delta_ratio = p.price_delta_ratio(Currency::Euro);
-                let avg_price: Option<f64> = p.price_avg(Currency::Euro, 5);
+                let avg_price: Option<f64> = p.price_avg(Currency::Euro, price_avg_count);
                 let (games_count, played_games_count, played_games_ratio) = match &p.stats {
                     Some(s) => (
                         Some(s.games.len() as u64),
@@ -0,0 +1,76 @@
+use ratatui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, BorderType, Borders, Cell, Clear, Row, Table, TableState},
+    Frame,
+};
+
+use crate::core::model::strategy::StrategyInfo;
+
+use super::{centered_rect, Renderable};
+
+pub struct StrategiesPopup {
+    state: TableState,
+    strategies: Vec<StrategyInfo>,
+}
+
+impl StrategiesPopup {
+    pub fn new(strategies: Vec<StrategyInfo>, selection: usize) -> Self {
+        let mut state = TableState::default();
+        state.select(Some(selection));
+        Self { state, strategies }
+    }
+}
+
+impl Renderable for StrategiesPopup {
+    fn render<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let area = centered_rect(70, 60, area);
+
+        let rows: Vec<Row> = self
+            .strategies
+            .iter()
+            .map(|s| {
+                Row::new(vec![
+                    Cell::from(if s.enabled { "[x]" } else { "[ ]" }),
+                    Cell::from(s.name.clone()),
+                    Cell::from(s.engine.clone()),
+                ])
+                .style(Style::default().fg(if s.enabled {
+                    Color::Reset
+                } else {
+                    Color::DarkGray
+                }))
+            })
+            .collect();
+
+        let table = Table::new(rows)
+            .header(
+                Row::new(vec!["On", "Strategy", "Engine"])
+                    .style(Style::default().fg(Color::Yellow)),
+            )
+            .block(
+                Block::default()
+                    .title("Strategies (⬆⬇ to browse) (SPACE to enable / disable) (P for parameters) (ESC to close)")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(Color::Yellow)),
+            )
+            .widths(&[
+                Constraint::Length(4),
+                Constraint::Percentage(70),
+                Constraint::Percentage(20),
+            ])
+            .column_spacing(1)
+            .highlight_style(
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .fg(Color::White),
+            )
+            .highlight_symbol(" 🧠  ");
+
+        // Clear panels below the popup
+        f.render_widget(Clear, area);
+        f.render_stateful_widget(table, area, &mut self.state)
+    }
+}
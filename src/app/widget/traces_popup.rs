@@ -0,0 +1,108 @@
+use ratatui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::{Block, BorderType, Borders, Cell, Clear, Paragraph, Row, Table, TableState, Wrap},
+    Frame,
+};
+
+use crate::core::model::trace::{EvaluationTrace, TraceOutcome};
+
+use super::{centered_rect, Renderable};
+
+pub struct TracesPopup {
+    state: TableState,
+    player_name: String,
+    traces: Vec<EvaluationTrace>,
+}
+
+impl TracesPopup {
+    pub fn new(player_name: &str, traces: Vec<EvaluationTrace>, selection: usize) -> Self {
+        let mut state = TableState::default();
+        state.select(Some(selection));
+        Self {
+            state,
+            player_name: player_name.to_string(),
+            traces,
+        }
+    }
+}
+
+fn outcome_color(outcome: &TraceOutcome) -> Color {
+    match outcome {
+        TraceOutcome::Skipped { .. } => Color::DarkGray,
+        TraceOutcome::NoDecision => Color::Reset,
+        TraceOutcome::Decision { .. } => Color::Green,
+        TraceOutcome::Error { .. } => Color::Red,
+    }
+}
+
+impl Renderable for TracesPopup {
+    fn render<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
+        let area = centered_rect(80, 70, area);
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(5), Constraint::Length(6)].as_ref())
+            .split(area);
+
+        let block = |title: String| {
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Yellow))
+        };
+
+        let rows: Vec<Row> = self
+            .traces
+            .iter()
+            .map(|t| {
+                Row::new(vec![
+                    Cell::from(t.strategy.clone()),
+                    Cell::from(t.status()),
+                    Cell::from(format!("{:.1}", t.duration_ms)),
+                    Cell::from(t.details()),
+                ])
+                .style(Style::default().fg(outcome_color(&t.outcome)))
+            })
+            .collect();
+
+        let table = Table::new(rows)
+            .header(
+                Row::new(vec!["Strategy", "Status", "Time (ms)", "Details"])
+                    .style(Style::default().fg(Color::Yellow)),
+            )
+            .block(block(format!(
+                "{} strategies trace (⬆⬇ to browse) (ESC to close)",
+                self.player_name
+            )))
+            .widths(&[
+                Constraint::Percentage(25),
+                Constraint::Percentage(15),
+                Constraint::Percentage(10),
+                Constraint::Percentage(50),
+            ])
+            .column_spacing(1)
+            .highlight_style(
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .fg(Color::White),
+            )
+            .highlight_symbol(" 🔎  ");
+
+        // Full details of the selected trace
+        let details = match self.state.selected().and_then(|i| self.traces.get(i)) {
+            Some(trace) => format!("{} - {}", trace.date, trace.details()),
+            None => "No evaluation yet for this player".to_string(),
+        };
+        let details = Paragraph::new(Span::raw(details))
+            .block(block("Details".to_string()))
+            .wrap(Wrap { trim: true });
+
+        // Clear panels below the popup
+        f.render_widget(Clear, area);
+        f.render_stateful_widget(table, layout[0], &mut self.state);
+        f.render_widget(details, layout[1]);
+    }
+}
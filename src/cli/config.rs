@@ -0,0 +1,25 @@
+use std::io::{self, Write};
+
+use serde_json::Map;
+
+use crate::{
+    core::model::config::Config,
+    output::{self, OutputFormat},
+    resolve,
+};
+
+/// Record kind of settings, in JSON envelopes
+const CONFIG_KIND: &str = "config";
+
+/// Prints settings in effect (from the settings file, environment variables and `--set` options).
+/// Settings are output as TOML, except in JSON and NDJSON formats.
+pub fn show(format: OutputFormat) -> io::Result<()> {
+    let config = resolve!(Config);
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    match format {
+        OutputFormat::Json => output::write_json(&mut out, CONFIG_KIND, &[&*config], &Map::new()),
+        OutputFormat::Ndjson => output::write_ndjson(&mut out, &[&*config]),
+        _ => write!(out, "{}", config.to_toml()),
+    }
+}
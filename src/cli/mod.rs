@@ -0,0 +1,140 @@
+use std::{fmt::Display, io};
+
+use clap::Subcommand;
+
+use crate::output::OutputFormat;
+
+pub mod config;
+pub mod player;
+pub mod report;
+pub mod strategy;
+
+/// Non-interactive commands: they run without UI, so they can be used in shell pipelines.
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Replay stored price and stats history to evaluate strategies
+    Backtest {
+        /// Output trades instead of strategies results
+        #[clap(long, action)]
+        trades: bool,
+    },
+    /// Run strategies against their fixture files (`<name>.test.json`)
+    TestStrategies,
+    /// Run strategies over every player, with their last recorded prices and stats
+    Evaluate,
+    /// Write TypeScript definitions (`socli.d.ts`) to the strategy scripts folder
+    Types,
+    /// Players list
+    Players {
+        #[command(subcommand)]
+        command: PlayersCommand,
+    },
+    /// Player details
+    Player {
+        #[command(subcommand)]
+        command: PlayerCommand,
+    },
+    /// Last sales of a player
+    Prices {
+        /// Player slug
+        slug: String,
+    },
+    /// Last games stats of players
+    Stats {
+        /// Player slugs
+        #[arg(required = true)]
+        slugs: Vec<String>,
+    },
+    /// Strategies
+    Strategies {
+        #[command(subcommand)]
+        command: StrategiesCommand,
+    },
+    /// Stored data
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+    /// Settings
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum PlayersCommand {
+    /// List players (from the cached player list)
+    List,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum PlayerCommand {
+    /// Show a player, with its last prices, stats and injury
+    Show {
+        /// Player slug
+        slug: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum StrategiesCommand {
+    /// List strategies
+    List,
+    /// Run enabled strategies with up to date player data
+    Run {
+        /// Player slug (can be repeated). All players if not set: data is then loaded for every player, which takes a while
+        #[arg(long)]
+        player: Vec<String>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum CacheCommand {
+    /// Clear the cached player list (reloaded on next launch)
+    Clear,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommand {
+    /// Show settings in effect (TOML, or JSON with `-o json`)
+    Show,
+}
+
+pub async fn run_command(
+    command: Command,
+    strategies: &str,
+    format: OutputFormat,
+) -> io::Result<()> {
+    match command {
+        Command::Backtest { trades } => report::backtest(trades, format).await,
+        Command::TestStrategies => strategy::test(format).await,
+        Command::Evaluate => report::evaluate(format).await,
+        Command::Types => strategy::write_types(strategies),
+        Command::Players {
+            command: PlayersCommand::List,
+        } => player::list(format).await,
+        Command::Player {
+            command: PlayerCommand::Show { slug },
+        } => player::show(&slug, format).await,
+        Command::Prices { slug } => player::prices(&slug, format).await,
+        Command::Stats { slugs } => player::stats(&slugs, format).await,
+        Command::Strategies {
+            command: StrategiesCommand::List,
+        } => strategy::list(format).await,
+        Command::Strategies {
+            command: StrategiesCommand::Run { player },
+        } => strategy::run(&player, format).await,
+        Command::Cache {
+            command: CacheCommand::Clear,
+        } => player::clear_cache().await,
+        Command::Config {
+            command: ConfigCommand::Show,
+        } => config::show(format),
+    }
+}
+
+/// Service errors are reported as IO errors by commands
+pub(crate) fn io_error<E: Display>(e: E) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, e.to_string())
+}
@@ -0,0 +1,153 @@
+use std::io;
+
+use crate::{
+    core::{
+        model::{config::Config, currency::Currency, player::Player},
+        service::{
+            injury::InjuryService, player::PlayerService, price::PriceService, stats::StatsService,
+        },
+    },
+    output::{self, OutputFormat},
+    resolve, resolve_trait,
+};
+
+use super::io_error;
+
+pub async fn list(format: OutputFormat) -> io::Result<()> {
+    let players = resolve_trait!(PlayerService)
+        .get_players()
+        .await
+        .map_err(io_error)?;
+
+    output::print(format, &players)
+}
+
+pub async fn show(slug: &str, format: OutputFormat) -> io::Result<()> {
+    let mut players = vec![find_player(slug).await?];
+    load_details(&mut players).await?;
+    if format != OutputFormat::Table {
+        return output::print(format, &players);
+    }
+
+    let player = &players[0];
+
+    println!("{} ({})", player.display_name, player.slug);
+    println!("Team: {}", player.team.as_deref().unwrap_or("-"));
+    println!("Positions: {}", player.positions.join(", "));
+    println!(
+        "Age: {} - Country: {} - Number: {}",
+        player.age(),
+        player.country,
+        player.number
+    );
+    let price_avg_count = resolve!(Config).ui.price_avg_count;
+    match player.price_avg(Currency::Euro, price_avg_count) {
+        Some(avg) => println!(
+            "Price: {:.2} € (average of {} last sales)",
+            avg,
+            player.prices.len().min(price_avg_count)
+        ),
+        None => println!("Price: -"),
+    }
+    match &player.stats {
+        Some(stats) => println!(
+            "Score: {} ({} of {} last games played)",
+            stats.score,
+            stats.played_games_count().unwrap_or(0),
+            stats.games.len()
+        ),
+        None => println!("Score: -"),
+    }
+    match &player.injury {
+        Some(injury) => println!(
+            "Injury: {} since {} ({})",
+            injury.description, injury.date, injury.comment
+        ),
+        None => println!("Injury: -"),
+    }
+
+    Ok(())
+}
+
+pub async fn prices(slug: &str, format: OutputFormat) -> io::Result<()> {
+    let prices = resolve_trait!(PriceService)
+        .get_prices(slug)
+        .await
+        .map_err(io_error)?;
+
+    output::print(format, &prices)
+}
+
+pub async fn stats(slugs: &[String], format: OutputFormat) -> io::Result<()> {
+    let stats = resolve_trait!(StatsService)
+        .get_stats(slugs)
+        .await
+        .map_err(io_error)?;
+
+    output::print(format, &stats)
+}
+
+pub async fn clear_cache() -> io::Result<()> {
+    resolve_trait!(PlayerService)
+        .clear_storage()
+        .await
+        .map_err(io_error)?;
+    println!("Player list cleared");
+
+    Ok(())
+}
+
+/// Finds a player in the (cached) player list.
+pub(crate) async fn find_player(slug: &str) -> io::Result<Player> {
+    resolve_trait!(PlayerService)
+        .get_players()
+        .await
+        .map_err(io_error)?
+        .into_iter()
+        .find(|p| p.slug == slug)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("unknown player {}", slug)))
+}
+
+/// Loads up to date prices, stats and injury of players (by batches of players, as refreshed by the UI).
+pub(crate) async fn load_details(players: &mut [Player]) -> io::Result<()> {
+    let batch_size = resolve!(Config).refresh.batch_size;
+    let price_service = resolve_trait!(PriceService);
+    let stats_service = resolve_trait!(StatsService);
+    let injury_service = resolve_trait!(InjuryService);
+
+    let player_count = players.len();
+    for (index, chunk) in players.chunks_mut(batch_size).enumerate() {
+        if player_count > 1 {
+            eprint!(
+                "\rLoading players data: {}/{}",
+                index * batch_size,
+                player_count
+            );
+        }
+
+        let slugs = chunk.iter().map(|p| p.slug.clone()).collect::<Vec<_>>();
+        let mut batch = price_service.get_prices_batch(&slugs).await;
+        for (slugs, err) in &batch.errors {
+            log::warn!("Failed to load prices for {}: {}", slugs.join(", "), err);
+        }
+        let stats = stats_service.get_stats(&slugs).await.map_err(io_error)?;
+        let injuries = injury_service
+            .get_injuries(&slugs)
+            .await
+            .map_err(io_error)?;
+        for player in chunk.iter_mut() {
+            player.prices = batch.prices.remove(&player.slug).unwrap_or_default();
+            player.stats = stats.iter().find(|s| s.player_slug == player.slug).cloned();
+            player.injury = injuries
+                .iter()
+                .find(|i| i.player_slug == player.slug)
+                .cloned();
+            player.injury_loaded = true;
+        }
+    }
+    if player_count > 1 {
+        eprintln!("\rLoading players data: {}/{}", player_count, player_count);
+    }
+
+    Ok(())
+}
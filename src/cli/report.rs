@@ -0,0 +1,163 @@
+use std::io;
+
+use chrono::Utc;
+use serde_json::{json, Map};
+
+use crate::{
+    core::{
+        model::{
+            backtest::BacktestReport, evaluation::EvaluationReport, requirement::DataRequirement,
+        },
+        service::{
+            backtest::BacktestService, evaluation::EvaluationService, history::HistoryService,
+            injury::InjuryService, player::PlayerService, strategy::StrategyService,
+        },
+    },
+    output::{self, OutputFormat},
+    resolve_trait,
+};
+
+use super::io_error;
+
+pub async fn backtest(trades: bool, format: OutputFormat) -> io::Result<()> {
+    let report = resolve_trait!(BacktestService)
+        .run()
+        .await
+        .map_err(io_error)?;
+    // Backtest period, in JSON documents
+    let mut period = Map::new();
+    period.insert("start_date".to_string(), json!(report.start_date));
+    period.insert("end_date".to_string(), json!(report.end_date));
+    period.insert("days".to_string(), json!(report.days));
+    if trades {
+        output::print_with_fields(format, &report.trades, &period)?;
+    } else if format == OutputFormat::Table {
+        print_backtest_report(&report);
+    } else {
+        output::print_with_fields(format, &report.strategies, &period)?;
+    }
+
+    Ok(())
+}
+
+pub async fn evaluate(format: OutputFormat) -> io::Result<()> {
+    let players = resolve_trait!(PlayerService)
+        .get_players()
+        .await
+        .map_err(io_error)?;
+
+    // Players as they looked on last recorded prices and stats
+    let history_service = resolve_trait!(HistoryService);
+    let now = Utc::now();
+    let mut snapshots = vec![];
+    for player in &players {
+        let history = history_service
+            .get_history(&player.slug)
+            .await
+            .map_err(io_error)?;
+        snapshots.push(history.snapshot(player, &now));
+    }
+
+    // Injuries are not recorded: load them only if required by a strategy
+    let requirements = resolve_trait!(StrategyService)
+        .requirements()
+        .await
+        .map_err(io_error)?;
+    if requirements
+        .iter()
+        .any(|r| r.contains(&DataRequirement::Injury))
+    {
+        let slugs = snapshots.iter().map(|p| p.slug.clone()).collect::<Vec<_>>();
+        let injuries = resolve_trait!(InjuryService)
+            .get_injuries(&slugs)
+            .await
+            .map_err(io_error)?;
+        for player in &mut snapshots {
+            player.injury = injuries
+                .iter()
+                .find(|i| i.player_slug == player.slug)
+                .cloned();
+            player.injury_loaded = true;
+        }
+    }
+
+    let report = resolve_trait!(EvaluationService)
+        .evaluate_all(snapshots, &|progress| {
+            eprint!(
+                "\rEvaluating players: {}/{}",
+                progress.evaluated_count + progress.skipped_count,
+                progress.player_count
+            );
+        })
+        .await
+        .map_err(io_error)?;
+    eprintln!();
+    match format {
+        OutputFormat::Table => print_evaluation_report(&report),
+        _ => {
+            output::print(format, &report.decisions)?;
+            for error in &report.errors {
+                eprintln!("Error: {}", error);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn print_evaluation_report(report: &EvaluationReport) {
+    println!(
+        "{} players evaluated, {} skipped (missing prices or stats)\n",
+        report.progress.evaluated_count, report.progress.skipped_count
+    );
+
+    println!(
+        "{:<8} {:<30} {:<30} {}",
+        "Action", "Player", "Strategy", "Comment"
+    );
+    for d in &report.decisions {
+        println!(
+            "{:<8} {:<30} {:<30} {}",
+            d.action, d.player_name, d.strategy, d.comment
+        );
+    }
+
+    for error in &report.errors {
+        eprintln!("Error: {}", error);
+    }
+}
+
+fn print_backtest_report(report: &BacktestReport) {
+    match (&report.start_date, &report.end_date) {
+        (Some(start), Some(end)) => {
+            println!(
+                "Backtest from {} to {} ({} days)\n",
+                start, end, report.days
+            )
+        }
+        _ => {
+            println!("No history to backtest: history is recorded while socli is running");
+            return;
+        }
+    }
+
+    println!(
+        "{:<30} {:>7} {:>6} {:>9} {:>12} {:>12} {:>13}",
+        "Strategy", "Trades", "Open", "Hit rate", "P&L", "Realized", "Max drawdown"
+    );
+    for s in &report.strategies {
+        println!(
+            "{:<30} {:>7} {:>6} {:>9} {:>10.2} € {:>10.2} € {:>11.2} €",
+            s.strategy,
+            s.trades,
+            s.open_trades,
+            match s.hit_rate {
+                Some(r) => format!("{:.2}%", r * 100.0),
+                None => "-".to_string(),
+            },
+            s.pnl,
+            s.realized_pnl,
+            s.max_drawdown
+        );
+    }
+}
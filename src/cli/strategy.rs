@@ -0,0 +1,111 @@
+use std::{fs, io, path::Path};
+
+use crate::{
+    core::{
+        model::context::StrategyContext,
+        service::{
+            decision::DecisionService,
+            fixture::FixtureService,
+            player::PlayerService,
+            strategy::{StrategyService, TYPE_DEFINITIONS, TYPE_DEFINITIONS_FILE},
+        },
+    },
+    output::{self, OutputFormat},
+    resolve_trait,
+};
+
+use super::{io_error, player::load_details};
+
+pub async fn test(format: OutputFormat) -> io::Result<()> {
+    let report = resolve_trait!(FixtureService)
+        .run_fixtures()
+        .await
+        .map_err(io_error)?;
+    if format == OutputFormat::Table {
+        for result in &report.results {
+            match &result.failure {
+                None => println!("{} › {} ... ok", result.strategy, result.case),
+                Some(msg) => {
+                    println!("{} › {} ... FAILED: {}", result.strategy, result.case, msg)
+                }
+            }
+        }
+        println!(
+            "\n{} passed, {} failed",
+            report.passed_count(),
+            report.failed_count()
+        );
+    } else {
+        output::print(format, &report.results)?;
+    }
+    if report.failed_count() > 0 {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+pub fn write_types(strategies: &str) -> io::Result<()> {
+    let path = Path::new(strategies).join(TYPE_DEFINITIONS_FILE);
+    fs::write(&path, TYPE_DEFINITIONS)?;
+    println!("Type definitions written to {}", path.display());
+
+    Ok(())
+}
+
+pub async fn list(format: OutputFormat) -> io::Result<()> {
+    let strategies = resolve_trait!(StrategyService)
+        .list()
+        .await
+        .map_err(io_error)?;
+
+    output::print(format, &strategies)
+}
+
+/// Runs strategies on players (all players if no slug is given), with up to date data.
+/// Decisions are recorded to the decision history.
+pub async fn run(player_slugs: &[String], format: OutputFormat) -> io::Result<()> {
+    let mut players = resolve_trait!(PlayerService)
+        .get_players()
+        .await
+        .map_err(io_error)?;
+    if let Some(slug) = player_slugs
+        .iter()
+        .find(|slug| !players.iter().any(|p| &p.slug == *slug))
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("unknown player {}", slug),
+        ));
+    }
+
+    let mut selection = players
+        .iter()
+        .filter(|p| player_slugs.is_empty() || player_slugs.contains(&p.slug))
+        .cloned()
+        .collect::<Vec<_>>();
+    load_details(&mut selection).await?;
+    for player in &selection {
+        if let Some(p) = players.iter_mut().find(|p| p.slug == player.slug) {
+            *p = player.clone();
+        }
+    }
+
+    let strategy_service = resolve_trait!(StrategyService);
+    let decision_service = resolve_trait!(DecisionService);
+    let context = StrategyContext::new(&players);
+    let mut all_decisions = vec![];
+    for player in &selection {
+        match strategy_service.run_all(player, &context).await {
+            Ok(decisions) => {
+                if let Err(e) = decision_service.record(player, &decisions).await {
+                    eprintln!("Error: failed to record decisions: {}", e);
+                }
+                all_decisions.extend(decisions);
+            }
+            Err(e) => eprintln!("Error: {}: {}", player.slug, e),
+        }
+    }
+
+    output::print(format, &all_decisions)
+}
@@ -0,0 +1,387 @@
+//! Expressions of rule-based strategies, such as `score > 35 and price_avg(5) < 20 and not injured`.
+//!
+//! Supported syntax: numbers, strings (`"..."` or `'...'`), `true`, `false`, `null`,
+//! variables, function calls, arithmetic (`+ - * /`), comparisons (`< <= > >= == !=`)
+//! and boolean operators (`and`/`&&`, `or`/`||`, `not`/`!`).
+//! Missing values are `null`: comparisons with `null` are false, arithmetic gives `null`.
+
+use std::fmt::Display;
+
+/// Expression value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Val {
+    Null,
+    Number(f64),
+    Text(String),
+    Bool(bool),
+}
+
+impl Val {
+    /// Truthiness, used by boolean operators (`null`, `false`, `0` and `""` are false).
+    pub fn is_true(&self) -> bool {
+        match self {
+            Self::Null => false,
+            Self::Number(n) => *n != 0.0,
+            Self::Text(s) => !s.is_empty(),
+            Self::Bool(b) => *b,
+        }
+    }
+}
+
+impl From<Option<f64>> for Val {
+    fn from(value: Option<f64>) -> Self {
+        value.map_or(Self::Null, Self::Number)
+    }
+}
+
+impl Display for Val {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Null => write!(f, "-"),
+            Self::Number(n) if n.fract() == 0.0 => write!(f, "{}", n),
+            Self::Number(n) => write!(f, "{:.2}", n),
+            Self::Text(s) => write!(f, "{}", s),
+            Self::Bool(b) => write!(f, "{}", b),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinaryOp {
+    Or,
+    And,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+    Literal(Val),
+    Variable(String),
+    Call(String, Vec<Expr>),
+    Not(Box<Expr>),
+    Neg(Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+}
+
+/// Resolves variables and functions during evaluation.
+pub trait Scope {
+    fn variable(&self, name: &str) -> Result<Val, String>;
+    fn call(&self, name: &str, args: &[Val]) -> Result<Val, String>;
+}
+
+impl Expr {
+    pub fn eval(&self, scope: &dyn Scope) -> Result<Val, String> {
+        match self {
+            Self::Literal(v) => Ok(v.clone()),
+            Self::Variable(name) => scope.variable(name),
+            Self::Call(name, args) => {
+                let args = args
+                    .iter()
+                    .map(|a| a.eval(scope))
+                    .collect::<Result<Vec<Val>, String>>()?;
+                scope.call(name, &args)
+            }
+            Self::Not(e) => Ok(Val::Bool(!e.eval(scope)?.is_true())),
+            Self::Neg(e) => Ok(match e.eval(scope)? {
+                Val::Number(n) => Val::Number(-n),
+                _ => Val::Null,
+            }),
+            // Boolean operators are short-circuiting
+            Self::Binary(BinaryOp::And, l, r) => Ok(Val::Bool(
+                l.eval(scope)?.is_true() && r.eval(scope)?.is_true(),
+            )),
+            Self::Binary(BinaryOp::Or, l, r) => Ok(Val::Bool(
+                l.eval(scope)?.is_true() || r.eval(scope)?.is_true(),
+            )),
+            Self::Binary(op, l, r) => Ok(binary(*op, l.eval(scope)?, r.eval(scope)?)),
+        }
+    }
+}
+
+fn binary(op: BinaryOp, left: Val, right: Val) -> Val {
+    match op {
+        BinaryOp::Eq => Val::Bool(left == right),
+        BinaryOp::Ne => Val::Bool(left != right),
+        BinaryOp::Lt | BinaryOp::Le | BinaryOp::Gt | BinaryOp::Ge => {
+            let ordering = match (&left, &right) {
+                (Val::Number(l), Val::Number(r)) => l.partial_cmp(r),
+                (Val::Text(l), Val::Text(r)) => Some(l.cmp(r)),
+                _ => None,
+            };
+            Val::Bool(ordering.map_or(false, |o| match op {
+                BinaryOp::Lt => o.is_lt(),
+                BinaryOp::Le => o.is_le(),
+                BinaryOp::Gt => o.is_gt(),
+                _ => o.is_ge(),
+            }))
+        }
+        _ => match (left, right) {
+            (Val::Number(l), Val::Number(r)) => match op {
+                BinaryOp::Add => Val::Number(l + r),
+                BinaryOp::Sub => Val::Number(l - r),
+                BinaryOp::Mul => Val::Number(l * r),
+                _ if r == 0.0 => Val::Null,
+                _ => Val::Number(l / r),
+            },
+            (Val::Text(l), Val::Text(r)) if op == BinaryOp::Add => Val::Text(l + r.as_str()),
+            _ => Val::Null,
+        },
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Number(f64),
+    Text(String),
+    Ident(String),
+    Op(&'static str),
+}
+
+const OPERATORS: [&str; 16] = [
+    "&&", "||", "==", "!=", "<=", ">=", "<", ">", "+", "-", "*", "/", "!", "(", ")", ",",
+];
+
+fn tokenize(source: &str) -> Result<Vec<(usize, Token)>, String> {
+    let chars = source.char_indices().collect::<Vec<(usize, char)>>();
+    let mut tokens = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (pos, c) = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit() || c == '.' {
+            let start = i;
+            while i < chars.len() && (chars[i].1.is_ascii_digit() || chars[i].1 == '.') {
+                i += 1;
+            }
+            let text = chars[start..i].iter().map(|(_, c)| c).collect::<String>();
+            let number = text
+                .parse::<f64>()
+                .map_err(|_| format!("invalid number `{}` at position {}", text, pos))?;
+            tokens.push((pos, Token::Number(number)));
+        } else if c == '"' || c == '\'' {
+            let end = chars[i + 1..]
+                .iter()
+                .position(|(_, e)| *e == c)
+                .ok_or(format!("unterminated string at position {}", pos))?;
+            let text = chars[i + 1..i + 1 + end].iter().map(|(_, c)| c).collect();
+            tokens.push((pos, Token::Text(text)));
+            i += end + 2;
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].1.is_alphanumeric() || chars[i].1 == '_') {
+                i += 1;
+            }
+            let ident = chars[start..i].iter().map(|(_, c)| c).collect();
+            tokens.push((pos, Token::Ident(ident)));
+        } else {
+            let op = OPERATORS
+                .iter()
+                .copied()
+                .find(|op| source[pos..].starts_with(*op))
+                .ok_or(format!("unexpected `{}` at position {}", c, pos))?;
+            tokens.push((pos, Token::Op(op)));
+            i += op.len();
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Recursive descent parser, by increasing precedence:
+/// or, and, not, comparison, additive, multiplicative, unary, primary.
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    pos: usize,
+    len: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(_, t)| t)
+    }
+
+    /// Position in source of the current token (for error messages).
+    fn position(&self) -> usize {
+        self.tokens.get(self.pos).map_or(self.len, |(p, _)| *p)
+    }
+
+    /// Consumes the current token if it is one of `ops` (keywords or operators).
+    /// Keywords are returned as their operator alias (`and` as `&&`...).
+    fn accept(&mut self, ops: &[&'static str]) -> Option<&'static str> {
+        let found = ops.iter().copied().find(|op| match self.peek() {
+            Some(Token::Op(t)) => t == op,
+            Some(Token::Ident(t)) => t == op,
+            _ => false,
+        })?;
+        self.pos += 1;
+        Some(match found {
+            "and" => "&&",
+            "or" => "||",
+            "not" => "!",
+            op => op,
+        })
+    }
+
+    fn expect(&mut self, op: &'static str) -> Result<(), String> {
+        match self.accept(&[op]) {
+            Some(_) => Ok(()),
+            None => Err(format!("expected `{}` at position {}", op, self.position())),
+        }
+    }
+
+    fn or(&mut self) -> Result<Expr, String> {
+        let mut left = self.and()?;
+        while self.accept(&["or", "||"]).is_some() {
+            left = Expr::Binary(BinaryOp::Or, Box::new(left), Box::new(self.and()?));
+        }
+        Ok(left)
+    }
+
+    fn and(&mut self) -> Result<Expr, String> {
+        let mut left = self.not()?;
+        while self.accept(&["and", "&&"]).is_some() {
+            left = Expr::Binary(BinaryOp::And, Box::new(left), Box::new(self.not()?));
+        }
+        Ok(left)
+    }
+
+    fn not(&mut self) -> Result<Expr, String> {
+        if self.accept(&["not", "!"]).is_some() {
+            return Ok(Expr::Not(Box::new(self.not()?)));
+        }
+        self.comparison()
+    }
+
+    fn comparison(&mut self) -> Result<Expr, String> {
+        let left = self.additive()?;
+        let op = match self.accept(&["==", "!=", "<=", ">=", "<", ">"]) {
+            Some("==") => BinaryOp::Eq,
+            Some("!=") => BinaryOp::Ne,
+            Some("<=") => BinaryOp::Le,
+            Some(">=") => BinaryOp::Ge,
+            Some("<") => BinaryOp::Lt,
+            Some(">") => BinaryOp::Gt,
+            _ => return Ok(left),
+        };
+        Ok(Expr::Binary(op, Box::new(left), Box::new(self.additive()?)))
+    }
+
+    fn additive(&mut self) -> Result<Expr, String> {
+        let mut left = self.multiplicative()?;
+        while let Some(op) = self.accept(&["+", "-"]) {
+            let op = if op == "+" {
+                BinaryOp::Add
+            } else {
+                BinaryOp::Sub
+            };
+            left = Expr::Binary(op, Box::new(left), Box::new(self.multiplicative()?));
+        }
+        Ok(left)
+    }
+
+    fn multiplicative(&mut self) -> Result<Expr, String> {
+        let mut left = self.unary()?;
+        while let Some(op) = self.accept(&["*", "/"]) {
+            let op = if op == "*" {
+                BinaryOp::Mul
+            } else {
+                BinaryOp::Div
+            };
+            left = Expr::Binary(op, Box::new(left), Box::new(self.unary()?));
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<Expr, String> {
+        if self.accept(&["-"]).is_some() {
+            return Ok(Expr::Neg(Box::new(self.unary()?)));
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Expr, String> {
+        let position = self.position();
+        let token = self.peek().cloned();
+        self.pos += 1;
+        match token {
+            Some(Token::Number(n)) => Ok(Expr::Literal(Val::Number(n))),
+            Some(Token::Text(s)) => Ok(Expr::Literal(Val::Text(s))),
+            Some(Token::Ident(ident)) => match ident.as_str() {
+                "true" => Ok(Expr::Literal(Val::Bool(true))),
+                "false" => Ok(Expr::Literal(Val::Bool(false))),
+                "null" => Ok(Expr::Literal(Val::Null)),
+                "and" | "or" | "not" => {
+                    Err(format!("unexpected `{}` at position {}", ident, position))
+                }
+                _ => {
+                    if self.accept(&["("]).is_none() {
+                        return Ok(Expr::Variable(ident));
+                    }
+                    let mut args = vec![];
+                    if self.accept(&[")"]).is_none() {
+                        loop {
+                            args.push(self.or()?);
+                            if self.accept(&[","]).is_none() {
+                                break;
+                            }
+                        }
+                        self.expect(")")?;
+                    }
+                    Ok(Expr::Call(ident, args))
+                }
+            },
+            Some(Token::Op("(")) => {
+                let expr = self.or()?;
+                self.expect(")")?;
+                Ok(expr)
+            }
+            Some(Token::Op(op)) => Err(format!("unexpected `{}` at position {}", op, position)),
+            None => Err("unexpected end of expression".to_string()),
+        }
+    }
+}
+
+/// Parses an expression.
+pub fn parse(source: &str) -> Result<Expr, String> {
+    let mut parser = Parser {
+        tokens: tokenize(source)?,
+        pos: 0,
+        len: source.len(),
+    };
+    let expr = parser.or()?;
+    if parser.pos < parser.tokens.len() {
+        return Err(format!(
+            "unexpected token at position {}",
+            parser.position()
+        ));
+    }
+    Ok(expr)
+}
+
+/// Replaces `{expression}` placeholders of a template by their value.
+pub fn render(template: &str, scope: &dyn Scope) -> Result<String, String> {
+    let mut output = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let end = rest[start..]
+            .find('}')
+            .ok_or(format!("unterminated placeholder in `{}`", template))?;
+        output.push_str(&rest[..start]);
+        let value = parse(&rest[start + 1..start + end])?.eval(scope)?;
+        output.push_str(&value.to_string());
+        rest = &rest[start + end + 1..];
+    }
+    output.push_str(rest);
+    Ok(output)
+}
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+use super::expression::{parse, render, Scope, Val};
+
+struct TestScope {
+    variables: HashMap<&'static str, Val>,
+}
+
+impl Scope for TestScope {
+    fn variable(&self, name: &str) -> Result<Val, String> {
+        self.variables
+            .get(name)
+            .cloned()
+            .ok_or(format!("unknown variable `{}`", name))
+    }
+
+    fn call(&self, name: &str, args: &[Val]) -> Result<Val, String> {
+        match (name, args) {
+            ("double", [Val::Number(n)]) => Ok(Val::Number(n * 2.0)),
+            _ => Err(format!("unknown function `{}`", name)),
+        }
+    }
+}
+
+fn eval(source: &str) -> Result<Val, String> {
+    let scope = TestScope {
+        variables: HashMap::from([
+            ("score", Val::Number(40.0)),
+            ("price", Val::Number(12.5)),
+            ("injured", Val::Bool(false)),
+            ("team", Val::Text("Lakers".to_string())),
+            ("rank", Val::Null),
+        ]),
+    };
+    parse(source)?.eval(&scope)
+}
+
+#[test]
+fn eval_arithmetic() {
+    assert_eq!(eval("1 + 2 * 3").unwrap(), Val::Number(7.0));
+    assert_eq!(eval("(1 + 2) * 3").unwrap(), Val::Number(9.0));
+    assert_eq!(eval("-score / 4").unwrap(), Val::Number(-10.0));
+    assert_eq!(eval("double(price) - 5").unwrap(), Val::Number(20.0));
+    assert_eq!(eval("1 / 0").unwrap(), Val::Null);
+}
+
+#[test]
+fn eval_conditions() {
+    assert_eq!(
+        eval("score > 35 and price < 20 and not injured").unwrap(),
+        Val::Bool(true)
+    );
+    assert_eq!(
+        eval("score >= 50 || team == 'Lakers'").unwrap(),
+        Val::Bool(true)
+    );
+    assert_eq!(eval("!(score != 40)").unwrap(), Val::Bool(true));
+    assert_eq!(eval("score < 35 or injured").unwrap(), Val::Bool(false));
+}
+
+#[test]
+fn eval_null() {
+    // Comparisons with missing values are false
+    assert_eq!(eval("rank < 10").unwrap(), Val::Bool(false));
+    assert_eq!(eval("rank >= 10").unwrap(), Val::Bool(false));
+    assert_eq!(eval("rank + 1").unwrap(), Val::Null);
+    assert_eq!(eval("rank == null").unwrap(), Val::Bool(true));
+}
+
+#[test]
+fn eval_errors() {
+    assert!(eval("unknown > 1").is_err());
+    assert!(eval("triple(2)").is_err());
+    assert!(eval("score >").is_err());
+    assert!(eval("(score > 1").is_err());
+    assert!(eval("score > 1 2").is_err());
+    assert!(eval("'unterminated").is_err());
+}
+
+#[test]
+fn render_template() {
+    let scope = TestScope {
+        variables: HashMap::from([("score", Val::Number(40.0)), ("price", Val::Number(12.345))]),
+    };
+    assert_eq!(
+        render("score {score}, price {price * 2}", &scope).unwrap(),
+        "score 40, price 24.69"
+    );
+    assert_eq!(render("no placeholder", &scope).unwrap(), "no placeholder");
+    assert!(render("{score", &scope).is_err());
+}
@@ -0,0 +1,280 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use mlua::{
+    Function, HookTriggers, Lua, LuaOptions, LuaSerdeExt, SerializeOptions, StdLib, Table,
+    Value as LuaValue, Variadic,
+};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::core::{
+    model::{context::StrategyContext, player::Player},
+    service::strategy::StrategyError,
+};
+
+use super::{
+    compute_helper, limit_exceeded, parse_decision, resolve_script_params, ScriptInput,
+    ScriptLogger, ScriptOutput, StrategyEngine, HELPERS, MAX_OPERATIONS,
+};
+
+/// Instructions run between two execution limit checks
+const HOOK_INSTRUCTIONS: u32 = 1000;
+
+/// Error raised by the hook once the execution limit is reached
+const LIMIT_EXCEEDED: &str = "execution limit exceeded";
+
+impl From<mlua::Error> for StrategyError {
+    fn from(e: mlua::Error) -> Self {
+        Self::Script(e.to_string())
+    }
+}
+
+/// Lua engine (Lua 5.4).
+///
+/// Scripts declare a global `decide(player, slug, context, params)` function and optionally a
+/// global `params` table. State, logs and helpers are available as the `state`, `log` and
+/// `socli` tables.
+pub struct LuaEngine {}
+
+impl StrategyEngine for LuaEngine {
+    fn name(&self) -> &str {
+        "lua"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["lua"]
+    }
+
+    fn run(
+        &self,
+        input: &ScriptInput,
+        logger: ScriptLogger,
+    ) -> Result<ScriptOutput, StrategyError> {
+        let state = Rc::new(RefCell::new(match input.state {
+            Value::Object(map) => map.clone(),
+            _ => Map::new(),
+        }));
+
+        // No access to files or processes
+        let lua = Lua::new_with(
+            StdLib::TABLE | StdLib::STRING | StdLib::MATH | StdLib::UTF8,
+            LuaOptions::default(),
+        )?;
+        let globals = lua.globals();
+        for name in ["dofile", "loadfile", "require"] {
+            globals.set(name, LuaValue::Nil)?;
+        }
+        expose_logs(&lua, logger)?;
+        expose_state(&lua, &state)?;
+        expose_helpers(&lua)?;
+        limit_instructions(&lua);
+
+        lua.load(input.code)
+            .set_name(input.strategy_name)
+            .exec()
+            .map_err(|e| script_error(input.strategy_name, e))?;
+
+        // Resolve parameters declared by the script
+        let specs = match globals.get::<_, LuaValue>("params")? {
+            LuaValue::Nil => None,
+            specs => Some(lua.from_value::<Value>(specs)?),
+        };
+        let params = resolve_script_params(
+            input.strategy_name,
+            specs,
+            input.overrides,
+            input.saved_params,
+        )?;
+
+        let decide: Function = globals.get("decide")?;
+        let res: LuaValue = decide
+            .call((
+                to_lua(&lua, input.player)?,
+                input.player.slug.clone(),
+                context_to_lua(&lua, input.context)?,
+                to_lua(&lua, &params)?,
+            ))
+            .map_err(|e| script_error(input.strategy_name, e))?;
+        let value = match res {
+            LuaValue::Nil => None,
+            res => Some(lua.from_value::<Value>(res)?),
+        };
+
+        let state = state.borrow().clone();
+        Ok(ScriptOutput {
+            decision: parse_decision(input.strategy_name, value.clone())?,
+            value,
+            state: Some(Value::Object(state)),
+            params,
+        })
+    }
+}
+
+/// Stops scripts once `MAX_OPERATIONS` instructions are run.
+fn limit_instructions(lua: &Lua) {
+    let count = Cell::new(0u64);
+    lua.set_hook(
+        HookTriggers::new().every_nth_instruction(HOOK_INSTRUCTIONS),
+        move |_, _| {
+            count.set(count.get() + HOOK_INSTRUCTIONS as u64);
+            if count.get() > MAX_OPERATIONS {
+                Err(mlua::Error::RuntimeError(LIMIT_EXCEEDED.to_string()))
+            } else {
+                Ok(())
+            }
+        },
+    );
+}
+
+fn script_error(strategy_name: &str, e: mlua::Error) -> StrategyError {
+    if e.to_string().contains(LIMIT_EXCEEDED) {
+        limit_exceeded(strategy_name)
+    } else {
+        e.into()
+    }
+}
+
+/// Converts a value to Lua, mapping missing values to `nil`.
+fn to_lua<'lua, T: Serialize + ?Sized>(lua: &'lua Lua, value: &T) -> mlua::Result<LuaValue<'lua>> {
+    lua.to_value_with(
+        value,
+        SerializeOptions::new()
+            .serialize_none_to_null(false)
+            .serialize_unit_to_null(false),
+    )
+}
+
+/// Builds the `context` table (players and ranks are looked up by slug).
+fn context_to_lua<'lua>(lua: &'lua Lua, context: &StrategyContext) -> mlua::Result<Table<'lua>> {
+    let table = lua.create_table()?;
+    table.set("league", to_lua(lua, &context.league)?)?;
+    let players = context.clone();
+    table.set(
+        "player",
+        lua.create_function(move |lua, slug: String| match players.player(&slug) {
+            Some(player) => to_lua(lua, player),
+            None => Ok(LuaValue::Nil),
+        })?,
+    )?;
+    let ranks = context.clone();
+    table.set(
+        "rank",
+        lua.create_function(move |_, slug: String| Ok(ranks.rank(&slug)))?,
+    )?;
+    Ok(table)
+}
+
+/// Formats log arguments (tables are formatted as JSON).
+fn format_args<'lua>(lua: &'lua Lua, args: Variadic<LuaValue<'lua>>) -> mlua::Result<String> {
+    let mut parts = vec![];
+    for arg in args {
+        parts.push(match arg {
+            LuaValue::String(s) => s.to_str()?.to_string(),
+            LuaValue::Table(_) => lua.from_value::<Value>(arg)?.to_string(),
+            LuaValue::Nil => "nil".to_string(),
+            LuaValue::Boolean(b) => b.to_string(),
+            LuaValue::Integer(i) => i.to_string(),
+            LuaValue::Number(n) => n.to_string(),
+            other => other.type_name().to_string(),
+        });
+    }
+    Ok(parts.join(" "))
+}
+
+/// Bridges `print` and the `log` table to the application log.
+fn expose_logs(lua: &Lua, logger: ScriptLogger) -> mlua::Result<()> {
+    let print_logger = logger.clone();
+    lua.globals().set(
+        "print",
+        lua.create_function(move |lua, args: Variadic<LuaValue>| {
+            print_logger.log("info", &format_args(lua, args)?);
+            Ok(())
+        })?,
+    )?;
+
+    let log = lua.create_table()?;
+    for level in ["debug", "info", "warn", "error"] {
+        let logger = logger.clone();
+        log.set(
+            level,
+            lua.create_function(move |lua, args: Variadic<LuaValue>| {
+                logger.log(level, &format_args(lua, args)?);
+                Ok(())
+            })?,
+        )?;
+    }
+    lua.globals().set("log", log)
+}
+
+/// Exposes strategy state as the `state` table (`get`, `set`, `delete` and `keys`).
+fn expose_state(lua: &Lua, state: &Rc<RefCell<Map<String, Value>>>) -> mlua::Result<()> {
+    let table: Table = lua.create_table()?;
+
+    let data = state.clone();
+    table.set(
+        "get",
+        lua.create_function(move |lua, (key, fallback): (String, LuaValue)| {
+            match data.borrow().get(&key) {
+                Some(value) => to_lua(lua, value),
+                None => Ok(fallback),
+            }
+        })?,
+    )?;
+    let data = state.clone();
+    table.set(
+        "set",
+        lua.create_function(move |lua, (key, value): (String, LuaValue)| {
+            let value = lua.from_value::<Value>(value)?;
+            data.borrow_mut().insert(key, value);
+            Ok(())
+        })?,
+    )?;
+    let data = state.clone();
+    table.set(
+        "delete",
+        lua.create_function(move |_, key: String| {
+            data.borrow_mut().remove(&key);
+            Ok(())
+        })?,
+    )?;
+    let data = state.clone();
+    table.set(
+        "keys",
+        lua.create_function(move |_, ()| Ok(data.borrow().keys().cloned().collect::<Vec<_>>()))?,
+    )?;
+
+    lua.globals().set("state", table)
+}
+
+/// Exposes native helpers as the `socli` table.
+fn expose_helpers(lua: &Lua) -> mlua::Result<()> {
+    let socli = lua.create_table()?;
+    for name in HELPERS {
+        socli.set(
+            name,
+            lua.create_function(move |lua, args: Variadic<LuaValue>| {
+                let mut args = args.into_iter();
+                let player: Player = lua.from_value(args.next().unwrap_or(LuaValue::Nil))?;
+                // `price_avg(player, count, currency)` or `price_delta_ratio(player, currency)`
+                let (count, currency) = match name {
+                    "price_avg" => (args.next(), args.next()),
+                    _ => (None, args.next()),
+                };
+                let count = match count {
+                    Some(LuaValue::Integer(c)) => Some(c.max(0) as usize),
+                    Some(LuaValue::Number(c)) => Some(c.max(0.0) as usize),
+                    _ => None,
+                };
+                let currency = match currency {
+                    Some(LuaValue::String(s)) => Some(s.to_str()?.to_string()),
+                    _ => None,
+                };
+                Ok(compute_helper(name, &player, currency.as_deref(), count))
+            })?,
+        )?;
+    }
+    lua.globals().set("socli", socli)
+}
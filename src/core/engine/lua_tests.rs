@@ -0,0 +1,140 @@
+use std::{path::Path, sync::Arc, time::Duration};
+
+use serde_json::{json, Value};
+
+use crate::core::{
+    model::{
+        context::StrategyContext, params::ParamValues, player::Player,
+        player_builder::PlayerBuilder,
+    },
+    service::strategy::StrategyError,
+};
+
+use super::{
+    lua::LuaEngine, LogRateLimiter, ScriptInput, ScriptLogger, ScriptOutput, StrategyEngine,
+};
+
+fn create_player() -> Player {
+    PlayerBuilder::new("slug")
+        .price("2023-07-22T16:15:13Z", "40", "50")
+        .price("2023-07-21T16:15:13Z", "20", "25")
+        .score(40)
+        .build()
+}
+
+fn run(code: &str, overrides: &ParamValues, state: &Value) -> ScriptOutput {
+    try_run(code, overrides, state).unwrap()
+}
+
+fn try_run(
+    code: &str,
+    overrides: &ParamValues,
+    state: &Value,
+) -> Result<ScriptOutput, StrategyError> {
+    let player = create_player();
+    let context = StrategyContext::new(&[player.clone()]);
+    let logger = ScriptLogger::new(
+        "test",
+        &player.slug,
+        Arc::new(LogRateLimiter::new(10, Duration::from_secs(60))),
+    );
+    LuaEngine {}.run(
+        &ScriptInput {
+            strategy_name: "test",
+            path: Path::new("test.lua"),
+            code,
+            player: &player,
+            context: &context,
+            overrides,
+            saved_params: &ParamValues::new(),
+            state,
+        },
+        logger,
+    )
+}
+
+#[test]
+fn decide_with_params_and_helpers() {
+    let code = r#"
+        params = { min_score = { type = "number", default = 35 } }
+
+        function decide(player, slug, context, params)
+            if player.team == nil and player.stats.score > params.min_score then
+                return {
+                    action = "Buy",
+                    comment = slug .. " " .. socli.price_avg(player, 2) .. " " .. context.league.player_count,
+                }
+            end
+        end
+    "#;
+
+    let output = run(code, &ParamValues::new(), &json!({}));
+    let decision = output.decision.unwrap();
+    assert_eq!(decision.action, "Buy");
+    assert_eq!(decision.comment, "slug 30.0 1");
+
+    // Overridden parameter
+    let overrides = json!({ "min_score": 45 }).as_object().unwrap().clone();
+    assert!(run(code, &overrides, &json!({})).decision.is_none());
+}
+
+#[test]
+fn state() {
+    let code = r#"
+        function decide(player, slug)
+            state.set(slug, state.get(slug, 0) + 1)
+            state.delete("old")
+            print("count", state.get(slug), { a = 1 })
+        end
+    "#;
+
+    let output = run(
+        code,
+        &ParamValues::new(),
+        &json!({ "slug": 1, "old": true }),
+    );
+    assert!(output.decision.is_none());
+    assert_eq!(output.state, Some(json!({ "slug": 2 })));
+}
+
+#[test]
+fn sandbox() {
+    let code = r#"
+        function decide()
+            return { action = tostring(io) .. " " .. tostring(os) .. " " .. tostring(dofile), comment = "" }
+        end
+    "#;
+
+    let output = run(code, &ParamValues::new(), &json!({}));
+    assert_eq!(output.decision.unwrap().action, "nil nil nil");
+}
+
+#[test]
+fn context_lookups() {
+    let code = r#"
+        function decide(player, slug, context)
+            return {
+                action = "Buy",
+                comment = context.player(slug).display_name .. " " .. context.rank(slug)
+                    .. " " .. tostring(context.player("unknown")),
+            }
+        end
+    "#;
+
+    let output = run(code, &ParamValues::new(), &json!({}));
+    assert_eq!(output.decision.unwrap().comment, "name 1 nil");
+}
+
+#[test]
+fn execution_limit() {
+    let code = r#"
+        function decide(player, slug, context)
+            while true do end
+        end
+    "#;
+
+    assert!(matches!(
+        try_run(code, &ParamValues::new(), &json!({})),
+        Err(StrategyError::Script(msg)) if msg == "test: execution limit exceeded"
+    ));
+}
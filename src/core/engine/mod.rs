@@ -0,0 +1,277 @@
+pub mod expression;
+pub mod lua;
+pub mod quickjs;
+pub mod rhai;
+pub mod rules;
+pub mod typescript;
+
+#[cfg(test)]
+pub mod expression_tests;
+#[cfg(test)]
+pub mod lua_tests;
+#[cfg(test)]
+pub mod rhai_tests;
+#[cfg(test)]
+pub mod rules_tests;
+#[cfg(test)]
+pub mod typescript_tests;
+
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use serde_json::{from_value, Value};
+
+use crate::core::{
+    model::{
+        context::StrategyContext,
+        currency::Currency,
+        decision::ScriptDecision,
+        params::{resolve_params, ParamSpecs, ParamValues},
+        player::Player,
+        requirement::{parse_requires_directive, Requirements},
+    },
+    service::strategy::StrategyError,
+};
+
+/// Operations a script can run in one evaluation (Rhai operations, Lua instructions)
+pub const MAX_OPERATIONS: u64 = 10_000_000;
+
+/// Time a script can run in one evaluation (JavaScript)
+pub const MAX_DURATION: Duration = Duration::from_secs(2);
+
+/// Error of a script interrupted by execution limits.
+pub fn limit_exceeded(strategy_name: &str) -> StrategyError {
+    StrategyError::Script(format!("{}: execution limit exceeded", strategy_name))
+}
+
+/// Input of a strategy evaluation, shared by all engines.
+/// Data is mapped to script values with serde, so that every engine sees the same objects.
+pub struct ScriptInput<'a> {
+    pub strategy_name: &'a str,
+    pub path: &'a Path,
+    pub code: &'a str,
+    pub player: &'a Player,
+    pub context: &'a StrategyContext,
+    pub overrides: &'a ParamValues,
+    /// Parameter values saved from the UI (ignored if not declared anymore)
+    pub saved_params: &'a ParamValues,
+    /// Strategy state (JSON object)
+    pub state: &'a Value,
+}
+
+pub struct ScriptOutput {
+    pub decision: Option<ScriptDecision>,
+    /// Value returned by the script (`None` if nothing returned)
+    pub value: Option<Value>,
+    /// Strategy state after evaluation (`None` if not available)
+    pub state: Option<Value>,
+    /// Resolved parameter values
+    pub params: ParamValues,
+}
+
+/// Script language runtime.
+pub trait StrategyEngine: Send + Sync {
+    fn name(&self) -> &str;
+
+    /// Script file extensions handled by the engine.
+    fn extensions(&self) -> &[&str];
+
+    /// Evaluates a script: reads its parameter declarations and calls its `decide` function.
+    fn run(&self, input: &ScriptInput, logger: ScriptLogger)
+        -> Result<ScriptOutput, StrategyError>;
+
+    /// Player data required by a script, read without running it (`None` if not declared).
+    /// Defaults to the `@requires` comment directive.
+    fn requirements(
+        &self,
+        strategy_name: &str,
+        code: &str,
+    ) -> Result<Option<Requirements>, StrategyError> {
+        parse_requires_directive(code)
+            .map_err(|e| StrategyError::Config(format!("{}: {}", strategy_name, e)))
+    }
+}
+
+/// Available engines.
+pub fn default_engines() -> Vec<Box<dyn StrategyEngine>> {
+    vec![
+        Box::new(quickjs::QuickJsEngine {}),
+        Box::new(rhai::RhaiEngine {}),
+        Box::new(lua::LuaEngine {}),
+        Box::new(rules::RulesEngine {}),
+    ]
+}
+
+/// Finds the engine handling a script file (by extension).
+pub fn engine_for<'a>(
+    engines: &'a [Box<dyn StrategyEngine>],
+    path: &Path,
+) -> Option<&'a dyn StrategyEngine> {
+    let extension = path.extension()?.to_str()?;
+    engines
+        .iter()
+        .find(|e| e.extensions().contains(&extension))
+        .map(|e| e.as_ref())
+}
+
+/// Resolves script parameters from their declarations (`None` if not declared).
+pub fn resolve_script_params(
+    strategy_name: &str,
+    specs: Option<Value>,
+    overrides: &ParamValues,
+    saved: &ParamValues,
+) -> Result<ParamValues, StrategyError> {
+    let specs: ParamSpecs = match specs {
+        Some(v) => from_value(v).map_err(|e| {
+            StrategyError::Config(format!("invalid params in {}: {}", strategy_name, e))
+        })?,
+        None => ParamSpecs::new(),
+    };
+    resolve_params(&specs, overrides, saved)
+        .map_err(|e| StrategyError::Config(format!("{}: {}", strategy_name, e)))
+}
+
+/// Reads the decision returned by a script (`None` if nothing returned).
+pub fn parse_decision(
+    strategy_name: &str,
+    value: Option<Value>,
+) -> Result<Option<ScriptDecision>, StrategyError> {
+    match value {
+        None | Some(Value::Null) => Ok(None),
+        Some(v) => from_value(v).map(Some).map_err(|e| {
+            StrategyError::Script(format!("invalid decision in {}: {}", strategy_name, e))
+        }),
+    }
+}
+
+pub fn parse_currency(currency: &str) -> Currency {
+    match currency.to_lowercase().as_str() {
+        "usd" => Currency::Usd,
+        _ => Currency::Euro,
+    }
+}
+
+/// Native helpers, shared by all engines
+pub const HELPERS: [&str; 5] = [
+    "price_delta_ratio",
+    "price_avg",
+    "age",
+    "sales_hours_interval_avg",
+    "played_games_ratio",
+];
+
+/// Computes a native helper (`None` if not available for the player).
+/// `currency` defaults to EUR, `count` (number of prices) to 5.
+pub fn compute_helper(
+    name: &str,
+    player: &Player,
+    currency: Option<&str>,
+    count: Option<usize>,
+) -> Option<f64> {
+    let currency = parse_currency(currency.unwrap_or("eur"));
+    match name {
+        "price_delta_ratio" => player.try_price_delta_ratio(currency),
+        "price_avg" => player.try_price_avg(currency, count.unwrap_or(5)),
+        "age" => player.try_age().map(|a| a as f64),
+        "sales_hours_interval_avg" => player.sales_hours_interval_avg(),
+        "played_games_ratio" => player.stats.as_ref().and_then(|s| s.played_games_ratio()),
+        _ => None,
+    }
+}
+
+/// Outcome of a log rate limit check.
+#[derive(Debug, PartialEq, Eq)]
+pub enum LogPermit {
+    Allowed,
+    /// Limit was just reached: message is dropped
+    LimitReached,
+    Dropped,
+}
+
+/// Limits the number of log messages emitted by each strategy, over a time window.
+pub struct LogRateLimiter {
+    max_messages: u32,
+    window: Duration,
+    counters: std::sync::Mutex<HashMap<String, (Instant, u32)>>,
+}
+
+impl LogRateLimiter {
+    pub fn new(max_messages: u32, window: Duration) -> Self {
+        Self {
+            max_messages,
+            window,
+            counters: std::sync::Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn acquire(&self, key: &str) -> LogPermit {
+        let mut counters = self.counters.lock().unwrap();
+        let now = Instant::now();
+        let (start, count) = counters.entry(key.to_string()).or_insert((now, 0));
+
+        // Start a new window
+        if now.duration_since(*start) >= self.window {
+            *start = now;
+            *count = 0;
+        }
+
+        *count += 1;
+        if *count <= self.max_messages {
+            LogPermit::Allowed
+        } else if *count == self.max_messages + 1 {
+            LogPermit::LimitReached
+        } else {
+            LogPermit::Dropped
+        }
+    }
+}
+
+/// Bridges script logs to the application log (displayed in the log panel).
+#[derive(Clone)]
+pub struct ScriptLogger {
+    strategy_name: String,
+    player_slug: String,
+    limiter: Arc<LogRateLimiter>,
+}
+
+impl ScriptLogger {
+    pub fn new(strategy_name: &str, player_slug: &str, limiter: Arc<LogRateLimiter>) -> Self {
+        Self {
+            strategy_name: strategy_name.to_string(),
+            player_slug: player_slug.to_string(),
+            limiter,
+        }
+    }
+
+    /// Logs a message, `level` being one of `debug`, `info`, `warn` or `error`.
+    pub fn log(&self, level: &str, message: &str) {
+        match self.limiter.acquire(&self.strategy_name) {
+            LogPermit::Allowed => {
+                let level = match level {
+                    "error" => log::Level::Error,
+                    "warn" => log::Level::Warn,
+                    "debug" => log::Level::Debug,
+                    _ => log::Level::Info,
+                };
+                log::log!(
+                    target: "strategy",
+                    level,
+                    "[{}] [{}] {}",
+                    self.strategy_name,
+                    self.player_slug,
+                    message
+                );
+            }
+            LogPermit::LimitReached => log::warn!(
+                target: "strategy",
+                "[{}] Log rate limit reached, dropping messages",
+                self.strategy_name
+            ),
+            LogPermit::Dropped => (),
+        }
+    }
+}
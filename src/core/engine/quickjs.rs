@@ -0,0 +1,264 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
+
+use rquickjs::{
+    loader::{BuiltinLoader, BuiltinResolver},
+    Context, Ctx, Function, Object, Runtime,
+};
+use serde_json::{from_str, to_value, Value};
+
+use crate::core::{
+    model::{context::StrategyContext, player::Player},
+    service::strategy::StrategyError,
+};
+
+use super::{
+    compute_helper, limit_exceeded, parse_decision, resolve_script_params, typescript::strip_types,
+    ScriptInput, ScriptLogger, ScriptOutput, StrategyEngine, MAX_DURATION,
+};
+
+/// Builds the `state` object exposed to scripts, wrapping state data
+const STATE_PRELUDE: &str = r#"
+(function (data) {
+    return Object.freeze({
+        get: (key, fallback) => (key in data ? data[key] : fallback),
+        set: (key, value) => { data[key] = value; },
+        delete: (key) => { delete data[key]; },
+        keys: () => Object.keys(data),
+    });
+})
+"#;
+
+/// Builds the `console` and `log` objects exposed to scripts, wrapping a native log function
+const CONSOLE_PRELUDE: &str = r#"
+(function (log) {
+    const format = (args) => args
+        .map((a) => (typeof a === "object" ? JSON.stringify(a) : String(a)))
+        .join(" ");
+    const logger = (level) => (...args) => log(level, format(args));
+    return {
+        console: Object.freeze({
+            log: logger("info"),
+            info: logger("info"),
+            debug: logger("debug"),
+            warn: logger("warn"),
+            error: logger("error"),
+        }),
+        log: Object.freeze({
+            debug: logger("debug"),
+            info: logger("info"),
+            warn: logger("warn"),
+            error: logger("error"),
+        }),
+    };
+})
+"#;
+
+/// Builds the `context` object exposed to scripts, wrapping native player lookups
+const CONTEXT_PRELUDE: &str = r#"
+(function (league, player, rank) {
+    return Object.freeze({
+        league,
+        player: (slug) => {
+            const json = player(slug);
+            return json == null ? null : JSON.parse(json);
+        },
+        rank: (slug) => rank(slug) ?? null,
+    });
+})
+"#;
+
+/// Helper library, importable by scripts as `socli`
+const SOCLI_MODULE: &str = include_str!("../js/socli.js");
+
+impl From<rquickjs::Error> for StrategyError {
+    fn from(e: rquickjs::Error) -> Self {
+        Self::Script(e.to_string())
+    }
+}
+
+/// JavaScript engine (QuickJS), also running TypeScript once types are stripped.
+pub struct QuickJsEngine {}
+
+impl StrategyEngine for QuickJsEngine {
+    fn name(&self) -> &str {
+        "javascript"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["js", "ts"]
+    }
+
+    fn run(
+        &self,
+        input: &ScriptInput,
+        logger: ScriptLogger,
+    ) -> Result<ScriptOutput, StrategyError> {
+        let code = if input.path.extension().map_or(false, |ext| ext == "ts") {
+            strip_types(input.code)
+                .map_err(|e| StrategyError::Script(format!("{}: {}", input.path.display(), e)))?
+        } else {
+            input.code.to_string()
+        };
+
+        let rt = Runtime::new()?;
+        rt.set_loader(
+            BuiltinResolver::default().with_module("socli"),
+            BuiltinLoader::default().with_module("socli", SOCLI_MODULE),
+        );
+        // Scripts are interrupted once running for too long
+        let interrupted = Arc::new(AtomicBool::new(false));
+        let start = Instant::now();
+        let flag = interrupted.clone();
+        rt.set_interrupt_handler(Some(Box::new(move || {
+            let exceeded = start.elapsed() > MAX_DURATION;
+            if exceeded {
+                flag.store(true, Ordering::Relaxed);
+            }
+            exceeded
+        })));
+
+        let ctx = Context::full(&rt)?;
+        let output = ctx.with(|ctx| {
+            expose_console(ctx, logger)?;
+            expose_helpers(ctx)?;
+
+            // Expose state
+            let state_data = json_to_js(ctx, input.state)?;
+            let make_state: Function = ctx.eval(STATE_PRELUDE)?;
+            let state_object: Object = make_state.call((state_data.clone(),))?;
+            ctx.globals().set("state", state_object)?;
+
+            let module = ctx.compile("strategy".to_string(), code)?;
+
+            // Resolve parameters declared by the script
+            let specs = js_to_json(ctx, module.get("params")?)?;
+            let params = resolve_script_params(
+                input.strategy_name,
+                specs,
+                input.overrides,
+                input.saved_params,
+            )?;
+
+            let decide: Function = module.get("decide")?;
+            let res: rquickjs::Value = decide.call((
+                json_to_js(ctx, &serialize(input.player)?)?,
+                input.player.slug.clone(),
+                context_to_js(ctx, input.context)?,
+                json_to_js(ctx, &Value::Object(params.clone()))?,
+            ))?;
+
+            let value = js_to_json(ctx, res)?;
+            Ok(ScriptOutput {
+                decision: parse_decision(input.strategy_name, value.clone())?,
+                value,
+                state: js_to_json(ctx, state_data)?,
+                params,
+            })
+        });
+        if interrupted.load(Ordering::Relaxed) {
+            return Err(limit_exceeded(input.strategy_name));
+        }
+        output
+    }
+}
+
+/// Exposes `console` and `log` to scripts, bridged to the application log.
+fn expose_console(ctx: Ctx<'_>, logger: ScriptLogger) -> Result<(), StrategyError> {
+    let native_log = Function::new(ctx, move |level: String, message: String| {
+        logger.log(&level, &message)
+    })?;
+
+    let make_console: Function = ctx.eval(CONSOLE_PRELUDE)?;
+    let bridge: Object = make_console.call((native_log,))?;
+    ctx.globals()
+        .set("console", bridge.get::<_, Object>("console")?)?;
+    ctx.globals().set("log", bridge.get::<_, Object>("log")?)?;
+
+    Ok(())
+}
+
+/// Exposes native helpers used by the `socli` module (players are passed as JSON).
+fn expose_helpers(ctx: Ctx<'_>) -> Result<(), StrategyError> {
+    let helpers = Object::new(ctx)?;
+    helpers.set(
+        "price_delta_ratio",
+        Function::new(ctx, |player: String, currency: String| {
+            parse_player(&player)
+                .and_then(|p| compute_helper("price_delta_ratio", &p, Some(&currency), None))
+        })?,
+    )?;
+    helpers.set(
+        "price_avg",
+        Function::new(ctx, |player: String, currency: String, count: u32| {
+            parse_player(&player).and_then(|p| {
+                compute_helper("price_avg", &p, Some(&currency), Some(count as usize))
+            })
+        })?,
+    )?;
+    for name in ["age", "sales_hours_interval_avg", "played_games_ratio"] {
+        helpers.set(
+            name,
+            Function::new(ctx, move |player: String| {
+                parse_player(&player).and_then(|p| compute_helper(name, &p, None, None))
+            })?,
+        )?;
+    }
+    ctx.globals().set("__socli", helpers)?;
+
+    Ok(())
+}
+
+/// Builds the `context` object (players and ranks are looked up by slug).
+fn context_to_js<'js>(
+    ctx: Ctx<'js>,
+    context: &StrategyContext,
+) -> Result<rquickjs::Value<'js>, StrategyError> {
+    let players = context.clone();
+    let player = Function::new(ctx, move |slug: String| {
+        players
+            .player(&slug)
+            .and_then(|p| serde_json::to_string(p).ok())
+    })?;
+    let ranks = context.clone();
+    let rank = Function::new(ctx, move |slug: String| ranks.rank(&slug).map(|r| r as u32))?;
+
+    let make_context: Function = ctx.eval(CONTEXT_PRELUDE)?;
+    Ok(make_context.call((json_to_js(ctx, &serialize(&context.league)?)?, player, rank))?)
+}
+
+fn parse_player(json: &str) -> Option<Player> {
+    from_str(json).ok()
+}
+
+fn serialize<T: serde::Serialize>(value: &T) -> Result<Value, StrategyError> {
+    to_value(value).map_err(|e| StrategyError::Data(e.to_string()))
+}
+
+/// Converts a script value to JSON (`None` if undefined).
+fn js_to_json<'js>(
+    ctx: Ctx<'js>,
+    value: rquickjs::Value<'js>,
+) -> Result<Option<Value>, StrategyError> {
+    let json: Object = ctx.globals().get("JSON")?;
+    let stringify: Function = json.get("stringify")?;
+    let text: Option<String> = stringify.call((value,))?;
+    match text {
+        Some(t) => from_str(&t)
+            .map(Some)
+            .map_err(|e| StrategyError::Script(e.to_string())),
+        None => Ok(None),
+    }
+}
+
+/// Converts JSON to a script value.
+fn json_to_js<'js>(ctx: Ctx<'js>, value: &Value) -> Result<rquickjs::Value<'js>, StrategyError> {
+    let json: Object = ctx.globals().get("JSON")?;
+    let parse: Function = json.get("parse")?;
+    Ok(parse.call((value.to_string(),))?)
+}
@@ -0,0 +1,231 @@
+use std::{cell::RefCell, rc::Rc};
+
+use rhai::{
+    serde::{from_dynamic, to_dynamic},
+    Array, CallFnOptions, Dynamic, Engine, EvalAltResult, ParseError, Scope, INT,
+};
+use serde_json::{Map, Value};
+
+use crate::core::{
+    model::{context::StrategyContext, player::Player},
+    service::strategy::StrategyError,
+};
+
+use super::{
+    compute_helper, limit_exceeded, parse_decision, resolve_script_params, ScriptInput,
+    ScriptLogger, ScriptOutput, StrategyEngine, HELPERS, MAX_OPERATIONS,
+};
+
+impl From<Box<EvalAltResult>> for StrategyError {
+    fn from(e: Box<EvalAltResult>) -> Self {
+        Self::Script(e.to_string())
+    }
+}
+
+impl From<ParseError> for StrategyError {
+    fn from(e: ParseError) -> Self {
+        Self::Script(e.to_string())
+    }
+}
+
+/// Rhai engine.
+///
+/// Scripts declare a `decide(player, slug, context, params)` function and optionally a
+/// `params` variable. State, logs and helpers are available as functions
+/// (`state_get`, `log_info`, `price_avg`...).
+pub struct RhaiEngine {}
+
+impl StrategyEngine for RhaiEngine {
+    fn name(&self) -> &str {
+        "rhai"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["rhai"]
+    }
+
+    fn run(
+        &self,
+        input: &ScriptInput,
+        logger: ScriptLogger,
+    ) -> Result<ScriptOutput, StrategyError> {
+        let state = Rc::new(RefCell::new(match input.state {
+            Value::Object(map) => map.clone(),
+            _ => Map::new(),
+        }));
+
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_OPERATIONS);
+        register_logs(&mut engine, logger);
+        register_state(&mut engine, &state);
+        register_helpers(&mut engine);
+        register_context(&mut engine);
+
+        let ast = engine.compile(input.code)?;
+        let mut scope = Scope::new();
+        engine
+            .run_ast_with_scope(&mut scope, &ast)
+            .map_err(|e| script_error(input.strategy_name, e))?;
+
+        // Resolve parameters declared by the script
+        let specs = match scope.get("params") {
+            Some(specs) => Some(from_dynamic::<Value>(specs)?),
+            None => None,
+        };
+        let params = resolve_script_params(
+            input.strategy_name,
+            specs,
+            input.overrides,
+            input.saved_params,
+        )?;
+
+        let res: Dynamic = engine
+            .call_fn_with_options(
+                CallFnOptions::new().eval_ast(false),
+                &mut scope,
+                &ast,
+                "decide",
+                (
+                    to_dynamic(input.player)?,
+                    input.player.slug.clone(),
+                    Dynamic::from(input.context.clone()),
+                    to_dynamic(Value::Object(params.clone()))?,
+                ),
+            )
+            .map_err(|e| script_error(input.strategy_name, e))?;
+        let value = if res.is_unit() {
+            None
+        } else {
+            Some(from_dynamic::<Value>(&res)?)
+        };
+
+        let state = state.borrow().clone();
+        Ok(ScriptOutput {
+            decision: parse_decision(input.strategy_name, value.clone())?,
+            value,
+            state: Some(Value::Object(state)),
+            params,
+        })
+    }
+}
+
+fn script_error(strategy_name: &str, e: Box<EvalAltResult>) -> StrategyError {
+    match *e {
+        EvalAltResult::ErrorTooManyOperations(_) => limit_exceeded(strategy_name),
+        _ => e.into(),
+    }
+}
+
+/// Bridges `print`, `debug` and `log_*` functions to the application log.
+fn register_logs(engine: &mut Engine, logger: ScriptLogger) {
+    let print_logger = logger.clone();
+    engine.on_print(move |s| print_logger.log("info", s));
+    let debug_logger = logger.clone();
+    engine.on_debug(move |s, _, _| debug_logger.log("debug", s));
+    for level in ["debug", "info", "warn", "error"] {
+        let logger = logger.clone();
+        engine.register_fn(format!("log_{}", level), move |message: Dynamic| {
+            logger.log(level, &message.to_string())
+        });
+    }
+}
+
+/// Exposes strategy state as `state_get`, `state_set`, `state_delete` and `state_keys`.
+fn register_state(engine: &mut Engine, state: &Rc<RefCell<Map<String, Value>>>) {
+    let data = state.clone();
+    engine.register_fn(
+        "state_get",
+        move |key: &str, fallback: Dynamic| -> Result<Dynamic, Box<EvalAltResult>> {
+            match data.borrow().get(key) {
+                Some(value) => to_dynamic(value),
+                None => Ok(fallback),
+            }
+        },
+    );
+    let data = state.clone();
+    engine.register_fn(
+        "state_get",
+        move |key: &str| -> Result<Dynamic, Box<EvalAltResult>> {
+            match data.borrow().get(key) {
+                Some(value) => to_dynamic(value),
+                None => Ok(Dynamic::UNIT),
+            }
+        },
+    );
+    let data = state.clone();
+    engine.register_fn(
+        "state_set",
+        move |key: &str, value: Dynamic| -> Result<(), Box<EvalAltResult>> {
+            data.borrow_mut()
+                .insert(key.to_string(), from_dynamic(&value)?);
+            Ok(())
+        },
+    );
+    let data = state.clone();
+    engine.register_fn("state_delete", move |key: &str| {
+        data.borrow_mut().remove(key);
+    });
+    let data = state.clone();
+    engine.register_fn("state_keys", move || -> Array {
+        data.borrow()
+            .keys()
+            .map(|k| Dynamic::from(k.clone()))
+            .collect()
+    });
+}
+
+/// Exposes the context type: `league` stats, `player(slug)` and `rank(slug)` lookups.
+fn register_context(engine: &mut Engine) {
+    engine
+        .register_type_with_name::<StrategyContext>("StrategyContext")
+        .register_get(
+            "league",
+            |context: &mut StrategyContext| -> Result<Dynamic, Box<EvalAltResult>> {
+                to_dynamic(&context.league)
+            },
+        )
+        .register_fn(
+            "player",
+            |context: &mut StrategyContext, slug: &str| -> Result<Dynamic, Box<EvalAltResult>> {
+                context.player(slug).map_or(Ok(Dynamic::UNIT), to_dynamic)
+            },
+        )
+        .register_fn("rank", |context: &mut StrategyContext, slug: &str| {
+            context
+                .rank(slug)
+                .map_or(Dynamic::UNIT, |r| Dynamic::from(r as INT))
+        });
+}
+
+/// Exposes native helpers as functions taking the player as first argument.
+fn register_helpers(engine: &mut Engine) {
+    for name in HELPERS {
+        engine.register_fn(name, move |player: Dynamic| {
+            helper(name, &player, None, None)
+        });
+    }
+    engine.register_fn("price_delta_ratio", |player: Dynamic, currency: &str| {
+        helper("price_delta_ratio", &player, Some(currency), None)
+    });
+    engine.register_fn("price_avg", |player: Dynamic, count: INT| {
+        helper("price_avg", &player, None, Some(count))
+    });
+    engine.register_fn(
+        "price_avg",
+        |player: Dynamic, count: INT, currency: &str| {
+            helper("price_avg", &player, Some(currency), Some(count))
+        },
+    );
+}
+
+/// Computes a helper, returning `()` if not available.
+fn helper(
+    name: &str,
+    player: &Dynamic,
+    currency: Option<&str>,
+    count: Option<INT>,
+) -> Result<Dynamic, Box<EvalAltResult>> {
+    let player: Player = from_dynamic(player)?;
+    let count = count.map(|c| c.max(0) as usize);
+    Ok(compute_helper(name, &player, currency, count).map_or(Dynamic::UNIT, Dynamic::from))
+}
@@ -0,0 +1,127 @@
+use std::{path::Path, sync::Arc, time::Duration};
+
+use serde_json::{json, Value};
+
+use crate::core::{
+    model::{
+        context::StrategyContext, params::ParamValues, player::Player,
+        player_builder::PlayerBuilder,
+    },
+    service::strategy::StrategyError,
+};
+
+use super::{
+    rhai::RhaiEngine, LogRateLimiter, ScriptInput, ScriptLogger, ScriptOutput, StrategyEngine,
+};
+
+fn create_player() -> Player {
+    PlayerBuilder::new("slug")
+        .price("2023-07-22T16:15:13Z", "40", "50")
+        .price("2023-07-21T16:15:13Z", "20", "25")
+        .score(40)
+        .build()
+}
+
+fn run(code: &str, overrides: &ParamValues, state: &Value) -> ScriptOutput {
+    try_run(code, overrides, state).unwrap()
+}
+
+fn try_run(
+    code: &str,
+    overrides: &ParamValues,
+    state: &Value,
+) -> Result<ScriptOutput, StrategyError> {
+    let player = create_player();
+    let context = StrategyContext::new(&[player.clone()]);
+    let logger = ScriptLogger::new(
+        "test",
+        &player.slug,
+        Arc::new(LogRateLimiter::new(10, Duration::from_secs(60))),
+    );
+    RhaiEngine {}.run(
+        &ScriptInput {
+            strategy_name: "test",
+            path: Path::new("test.rhai"),
+            code,
+            player: &player,
+            context: &context,
+            overrides,
+            saved_params: &ParamValues::new(),
+            state,
+        },
+        logger,
+    )
+}
+
+#[test]
+fn decide_with_params_and_helpers() {
+    let code = r#"
+        // `default` is a reserved keyword: it must be quoted
+        let params = #{ min_score: #{ type: "number", "default": 35 } };
+
+        fn decide(player, slug, context, params) {
+            if player.stats.score > params.min_score {
+                return #{
+                    action: "Buy",
+                    comment: `${slug} ${price_avg(player, 2)} ${context.league.player_count}`,
+                };
+            }
+        }
+    "#;
+
+    let output = run(code, &ParamValues::new(), &json!({}));
+    let decision = output.decision.unwrap();
+    assert_eq!(decision.action, "Buy");
+    assert_eq!(decision.comment, "slug 30.0 1");
+
+    // Overridden parameter
+    let overrides = json!({ "min_score": 45 }).as_object().unwrap().clone();
+    assert!(run(code, &overrides, &json!({})).decision.is_none());
+}
+
+#[test]
+fn state() {
+    let code = r#"
+        fn decide(player, slug, context, params) {
+            state_set(slug, state_get(slug, 0) + 1);
+            state_delete("old");
+        }
+    "#;
+
+    let output = run(
+        code,
+        &ParamValues::new(),
+        &json!({ "slug": 1, "old": true }),
+    );
+    assert!(output.decision.is_none());
+    assert_eq!(output.state, Some(json!({ "slug": 2 })));
+}
+
+#[test]
+fn context_lookups() {
+    let code = r#"
+        fn decide(player, slug, context, params) {
+            #{
+                action: "Buy",
+                comment: `${context.player(slug).display_name} ${context.rank(slug)} ${context.player("unknown") == ()}`,
+            }
+        }
+    "#;
+
+    let output = run(code, &ParamValues::new(), &json!({}));
+    assert_eq!(output.decision.unwrap().comment, "name 1 true");
+}
+
+#[test]
+fn execution_limit() {
+    let code = r#"
+        fn decide(player, slug, context, params) {
+            loop {}
+        }
+    "#;
+
+    assert!(matches!(
+        try_run(code, &ParamValues::new(), &json!({})),
+        Err(StrategyError::Script(msg)) if msg == "test: execution limit exceeded"
+    ));
+}
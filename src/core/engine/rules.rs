@@ -0,0 +1,208 @@
+use serde_json::{json, Value};
+
+use crate::core::{
+    model::{
+        context::StrategyContext, currency::Currency, decision::ScriptDecision,
+        params::ParamValues, player::Player, requirement::Requirements, rules::RuleSet,
+    },
+    service::strategy::StrategyError,
+};
+
+use super::{
+    compute_helper,
+    expression::{parse, render, Scope, Val},
+    resolve_script_params, ScriptInput, ScriptLogger, ScriptOutput, StrategyEngine,
+};
+
+/// Rule-based strategies engine, for declarative strategies (`.toml` files).
+pub struct RulesEngine {}
+
+impl StrategyEngine for RulesEngine {
+    fn name(&self) -> &str {
+        "rules"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["toml"]
+    }
+
+    fn run(
+        &self,
+        input: &ScriptInput,
+        logger: ScriptLogger,
+    ) -> Result<ScriptOutput, StrategyError> {
+        let rule_set = parse_rule_set(input.strategy_name, input.code)?;
+        let specs = serde_json::to_value(&rule_set.params)
+            .map_err(|e| StrategyError::Config(e.to_string()))?;
+        let params = resolve_script_params(
+            input.strategy_name,
+            Some(specs),
+            input.overrides,
+            input.saved_params,
+        )?;
+
+        let scope = PlayerScope {
+            player: input.player,
+            context: input.context,
+            params: &params,
+        };
+        for (index, rule) in rule_set.rules.iter().enumerate() {
+            let script_error =
+                |e: String| StrategyError::Script(format!("rule {}: {}", index + 1, e));
+            let matched = parse(&rule.when)
+                .and_then(|e| e.eval(&scope))
+                .map_err(script_error)?
+                .is_true();
+            if matched {
+                logger.log(
+                    "debug",
+                    &format!("rule {} matched: {}", index + 1, rule.when),
+                );
+                let comment = match &rule.comment {
+                    Some(template) => render(template, &scope).map_err(script_error)?,
+                    None => rule.when.clone(),
+                };
+                return Ok(ScriptOutput {
+                    value: Some(json!({
+                        "rule": index + 1,
+                        "action": rule.action,
+                        "comment": comment,
+                    })),
+                    decision: Some(ScriptDecision {
+                        action: rule.action.clone(),
+                        comment,
+                    }),
+                    state: None,
+                    params,
+                });
+            }
+        }
+
+        Ok(ScriptOutput {
+            decision: None,
+            value: None,
+            state: None,
+            params,
+        })
+    }
+
+    fn requirements(
+        &self,
+        strategy_name: &str,
+        code: &str,
+    ) -> Result<Option<Requirements>, StrategyError> {
+        Ok(parse_rule_set(strategy_name, code)?.requires)
+    }
+}
+
+fn parse_rule_set(strategy_name: &str, code: &str) -> Result<RuleSet, StrategyError> {
+    toml::from_str(code)
+        .map_err(|e| StrategyError::Config(format!("invalid rules in {}: {}", strategy_name, e)))
+}
+
+/// Player fields and computed metrics, available to rule expressions.
+pub struct PlayerScope<'a> {
+    pub player: &'a Player,
+    pub context: &'a StrategyContext,
+    pub params: &'a ParamValues,
+}
+
+impl<'a> PlayerScope<'a> {
+    fn last_price(&self, currency: Currency) -> Val {
+        self.player
+            .prices
+            .first()
+            .and_then(|p| match currency {
+                Currency::Euro => p.eur.parse::<f64>().ok(),
+                Currency::Usd => p.usd.parse::<f64>().ok(),
+            })
+            .into()
+    }
+}
+
+impl<'a> Scope for PlayerScope<'a> {
+    fn variable(&self, name: &str) -> Result<Val, String> {
+        let player = self.player;
+        let text = |value: &str| Val::Text(value.to_string());
+        Ok(match name {
+            "score" => player.stats.as_ref().map(|s| s.score as f64).into(),
+            "price" => self.last_price(Currency::Euro),
+            "price_usd" => self.last_price(Currency::Usd),
+            "sales_count" => Val::Number(player.prices.len() as f64),
+            "injured" => Val::Bool(player.injury.is_some()),
+            "rank" => self.context.rank(&player.slug).map(|r| r as f64).into(),
+            "age" | "price_delta_ratio" | "sales_hours_interval_avg" | "played_games_ratio" => {
+                compute_helper(name, player, None, None).into()
+            }
+            "slug" => text(&player.slug),
+            "name" => text(&player.display_name),
+            "team" => player.team.as_deref().map_or(Val::Null, text),
+            "country" => text(&player.country),
+            "number" => Val::Number(player.number as f64),
+            _ => match self.params.get(name) {
+                Some(Value::Number(n)) => n.as_f64().into(),
+                Some(Value::String(s)) => text(s),
+                Some(Value::Bool(b)) => Val::Bool(*b),
+                Some(_) => Val::Null,
+                None => return Err(format!("unknown variable `{}`", name)),
+            },
+        })
+    }
+
+    fn call(&self, name: &str, args: &[Val]) -> Result<Val, String> {
+        let player = self.player;
+        let arity_error = || format!("invalid arguments for `{}`", name);
+        Ok(match (name, args) {
+            ("price_avg", [Val::Number(count)]) => {
+                compute_helper(name, player, None, Some(*count as usize)).into()
+            }
+            ("price_avg", [Val::Number(count), Val::Text(currency)]) => {
+                compute_helper(name, player, Some(currency), Some(*count as usize)).into()
+            }
+            ("score_avg", [Val::Number(count)]) => {
+                // Average score of the last played games
+                let scores = player
+                    .stats
+                    .as_ref()
+                    .map(|s| {
+                        s.games
+                            .iter()
+                            .take(*count as usize)
+                            .filter(|g| g.did_play)
+                            .map(|g| g.score as f64)
+                            .collect::<Vec<f64>>()
+                    })
+                    .unwrap_or_default();
+                if scores.is_empty() {
+                    Val::Null
+                } else {
+                    Val::Number(scores.iter().sum::<f64>() / scores.len() as f64)
+                }
+            }
+            ("has_position", [Val::Text(position)]) => {
+                Val::Bool(player.positions.iter().any(|p| p == position))
+            }
+            ("league_score", [Val::Number(p)]) => self
+                .context
+                .league
+                .score_percentiles
+                .get(&format!("p{}", p))
+                .copied()
+                .into(),
+            ("league_price_median", []) => self.context.league.price_median.into(),
+            ("league_price_median", [Val::Text(position)]) => self
+                .context
+                .league
+                .price_median_by_position
+                .get(position)
+                .copied()
+                .into(),
+            ("abs", [Val::Number(n)]) => Val::Number(n.abs()),
+            ("min", [Val::Number(a), Val::Number(b)]) => Val::Number(a.min(*b)),
+            ("max", [Val::Number(a), Val::Number(b)]) => Val::Number(a.max(*b)),
+            // Missing values propagate
+            (_, args) if args.contains(&Val::Null) => Val::Null,
+            _ => return Err(arity_error()),
+        })
+    }
+}
@@ -0,0 +1,104 @@
+use std::{path::Path, sync::Arc, time::Duration};
+
+use serde_json::json;
+
+use crate::core::{
+    model::{
+        context::StrategyContext, params::ParamValues, player::Player,
+        player_builder::PlayerBuilder,
+    },
+    service::strategy::StrategyError,
+};
+
+use super::{
+    rules::RulesEngine, LogRateLimiter, ScriptInput, ScriptLogger, ScriptOutput, StrategyEngine,
+};
+
+fn create_player() -> Player {
+    PlayerBuilder::new("slug")
+        .team("Lakers")
+        .position("NBA_GUARD")
+        .price("2023-07-22T16:15:13Z", "40", "50")
+        .price("2023-07-21T16:15:13Z", "20", "25")
+        .score(40)
+        .game("2023-07-22", true, 50, 30)
+        .game("2023-07-20", false, 0, 0)
+        .game("2023-07-18", true, 30, 25)
+        .build()
+}
+
+fn run(code: &str, overrides: &ParamValues) -> Result<ScriptOutput, StrategyError> {
+    let player = create_player();
+    let context = StrategyContext::new(&[player.clone()]);
+    let logger = ScriptLogger::new(
+        "test",
+        &player.slug,
+        Arc::new(LogRateLimiter::new(10, Duration::from_secs(60))),
+    );
+    RulesEngine {}.run(
+        &ScriptInput {
+            strategy_name: "test",
+            path: Path::new("test.toml"),
+            code,
+            player: &player,
+            context: &context,
+            overrides,
+            saved_params: &ParamValues::new(),
+            state: &json!({}),
+        },
+        logger,
+    )
+}
+
+const RULES: &str = r#"
+[params]
+min_score = { type = "number", default = 35 }
+
+[[rules]]
+when = "injured or played_games_ratio < 0.5"
+action = "Sell"
+
+[[rules]]
+when = "score > min_score and price_avg(2) < 40 and has_position('NBA_GUARD')"
+action = "Buy"
+comment = "{slug}: score {score}, avg {score_avg(3)}, price {price_avg(2)}"
+"#;
+
+#[test]
+fn first_matching_rule() {
+    let output = run(RULES, &ParamValues::new()).unwrap();
+    let decision = output.decision.unwrap();
+    assert_eq!(decision.action, "Buy");
+    assert_eq!(decision.comment, "slug: score 40, avg 40, price 30");
+    assert!(output.state.is_none());
+
+    // Overridden parameter: no rule matches
+    let overrides = json!({ "min_score": 45 }).as_object().unwrap().clone();
+    assert!(run(RULES, &overrides).unwrap().decision.is_none());
+}
+
+#[test]
+fn default_comment() {
+    let code = r#"
+        [[rules]]
+        when = "rank == 1 and team == 'Lakers'"
+        action = "Watch"
+    "#;
+    let decision = run(code, &ParamValues::new()).unwrap().decision.unwrap();
+    assert_eq!(decision.comment, "rank == 1 and team == 'Lakers'");
+}
+
+#[test]
+fn invalid_rules() {
+    assert!(matches!(
+        run("[[rules]]\nwhen = 1", &ParamValues::new()),
+        Err(StrategyError::Config(_))
+    ));
+    assert!(matches!(
+        run(
+            "[[rules]]\nwhen = \"unknown > 1\"\naction = \"Buy\"",
+            &ParamValues::new()
+        ),
+        Err(StrategyError::Script(_))
+    ));
+}
@@ -0,0 +1,689 @@
+//! Minimal TypeScript support: type annotations and declarations are stripped from
+//! the source (replaced with whitespace, to preserve error positions), leaving
+//! plain JavaScript.
+//!
+//! Supported syntax: variable, parameter and return type annotations, optional
+//! parameters, function, method and arrow function generics, `as` assertions
+//! (including `as const`), non-null assertions, `type` aliases, interfaces and
+//! `import type` statements.
+//!
+//! Other syntax is rejected with an error, rather than left to fail (or worse,
+//! to run differently) as JavaScript: enums, namespaces, ambient (`declare`)
+//! declarations, decorators, abstract classes, `implements` clauses, class member
+//! modifiers, generic classes, angle bracket assertions, `satisfies` operators
+//! and type arguments (`f<T>(x)`, which TypeScript parses as a call with type
+//! arguments, not as comparisons).
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Ident,
+    Number,
+    Str,
+    Template,
+    Regex,
+    Punct,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Token<'a> {
+    kind: Kind,
+    text: &'a str,
+    start: usize,
+    end: usize,
+    newline_before: bool,
+}
+
+/// Multi-character punctuators (longest first)
+const PUNCTUATORS: [&str; 22] = [
+    "...", "===", "!==", "**=", "=>", "==", "!=", "&&", "||", "??", "?.", "++", "--", "+=", "-=",
+    "*=", "/=", "%=", "&=", "|=", "^=", "**",
+];
+
+/// Keywords after which a `/` starts a regular expression
+const EXPRESSION_KEYWORDS: [&str; 10] = [
+    "return", "typeof", "case", "in", "of", "new", "delete", "void", "throw", "yield",
+];
+
+/// Keywords followed by parentheses that are not parameter lists
+const CONTROL_KEYWORDS: [&str; 5] = ["if", "while", "for", "switch", "with"];
+
+/// Keywords starting unsupported syntax when followed by a name (or `class`), with the syntax description
+const UNSUPPORTED_KEYWORDS: [(&str, &str); 10] = [
+    ("enum", "enums"),
+    ("namespace", "namespaces"),
+    ("module", "namespaces"),
+    ("declare", "ambient declarations"),
+    ("abstract", "abstract classes"),
+    ("implements", "implements clauses"),
+    ("public", "class member modifiers"),
+    ("private", "class member modifiers"),
+    ("protected", "class member modifiers"),
+    ("readonly", "class member modifiers"),
+];
+
+/// Type operators that can start the type of an `as` assertion
+const TYPE_OPERATORS: [&str; 4] = ["keyof", "typeof", "readonly", "unique"];
+
+fn tokenize(source: &str) -> Result<Vec<Token<'_>>, String> {
+    let bytes = source.as_bytes();
+    let mut tokens: Vec<Token> = vec![];
+    let mut pos = 0;
+    let mut newline_before = false;
+
+    while pos < bytes.len() {
+        let c = bytes[pos];
+
+        // Whitespace
+        if c.is_ascii_whitespace() {
+            if c == b'\n' {
+                newline_before = true;
+            }
+            pos += 1;
+            continue;
+        }
+
+        // Comments
+        if source[pos..].starts_with("//") {
+            pos = source[pos..].find('\n').map_or(bytes.len(), |i| pos + i);
+            continue;
+        }
+        if source[pos..].starts_with("/*") {
+            let end = source[pos + 2..].find("*/").ok_or("unterminated comment")?;
+            if source[pos..pos + end + 2].contains('\n') {
+                newline_before = true;
+            }
+            pos += end + 4;
+            continue;
+        }
+
+        let start = pos;
+        let kind = if c == b'_' || c == b'$' || c.is_ascii_alphabetic() || c >= 0x80 {
+            while pos < bytes.len()
+                && (bytes[pos] == b'_'
+                    || bytes[pos] == b'$'
+                    || bytes[pos].is_ascii_alphanumeric()
+                    || bytes[pos] >= 0x80)
+            {
+                pos += 1;
+            }
+            Kind::Ident
+        } else if c.is_ascii_digit() {
+            while pos < bytes.len() && (bytes[pos].is_ascii_alphanumeric() || bytes[pos] == b'.') {
+                pos += 1;
+            }
+            Kind::Number
+        } else if c == b'"' || c == b'\'' {
+            pos = skip_string(bytes, pos)?;
+            Kind::Str
+        } else if c == b'`' {
+            pos = skip_template(bytes, pos)?;
+            Kind::Template
+        } else if c == b'/' && regex_allowed(tokens.last()) {
+            pos = skip_regex(bytes, pos)?;
+            Kind::Regex
+        } else {
+            pos += PUNCTUATORS
+                .iter()
+                .find(|p| source[pos..].starts_with(*p))
+                .map_or(1, |p| p.len());
+            Kind::Punct
+        };
+
+        tokens.push(Token {
+            kind,
+            text: &source[start..pos],
+            start,
+            end: pos,
+            newline_before,
+        });
+        newline_before = false;
+    }
+
+    Ok(tokens)
+}
+
+fn skip_string(bytes: &[u8], start: usize) -> Result<usize, String> {
+    let quote = bytes[start];
+    let mut pos = start + 1;
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'\\' => pos += 2,
+            b'\n' => break,
+            c if c == quote => return Ok(pos + 1),
+            _ => pos += 1,
+        }
+    }
+    Err("unterminated string".to_string())
+}
+
+fn skip_template(bytes: &[u8], start: usize) -> Result<usize, String> {
+    let mut pos = start + 1;
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'\\' => pos += 2,
+            b'`' => return Ok(pos + 1),
+            b'$' if bytes.get(pos + 1) == Some(&b'{') => {
+                // Embedded expression: skip until matching brace
+                pos += 2;
+                let mut depth = 1;
+                while pos < bytes.len() && depth > 0 {
+                    match bytes[pos] {
+                        b'{' => {
+                            depth += 1;
+                            pos += 1;
+                        }
+                        b'}' => {
+                            depth -= 1;
+                            pos += 1;
+                        }
+                        b'"' | b'\'' => pos = skip_string(bytes, pos)?,
+                        b'`' => pos = skip_template(bytes, pos)?,
+                        _ => pos += 1,
+                    }
+                }
+            }
+            _ => pos += 1,
+        }
+    }
+    Err("unterminated template literal".to_string())
+}
+
+fn skip_regex(bytes: &[u8], start: usize) -> Result<usize, String> {
+    let mut pos = start + 1;
+    let mut in_class = false;
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'\\' => pos += 2,
+            b'[' => {
+                in_class = true;
+                pos += 1
+            }
+            b']' => {
+                in_class = false;
+                pos += 1
+            }
+            b'/' if !in_class => {
+                pos += 1;
+                // Flags
+                while pos < bytes.len() && bytes[pos].is_ascii_alphabetic() {
+                    pos += 1;
+                }
+                return Ok(pos);
+            }
+            b'\n' => break,
+            _ => pos += 1,
+        }
+    }
+    Err("unterminated regular expression".to_string())
+}
+
+fn regex_allowed(previous: Option<&Token>) -> bool {
+    match previous {
+        None => true,
+        Some(t) => match t.kind {
+            Kind::Punct => !matches!(t.text, ")" | "]" | "}"),
+            Kind::Ident => EXPRESSION_KEYWORDS.contains(&t.text),
+            _ => false,
+        },
+    }
+}
+
+/// Open bracket kinds
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Open {
+    /// Parameter list, of an arrow function or not
+    Params {
+        arrow: bool,
+    },
+    Paren,
+    Brace,
+    Bracket,
+}
+
+struct Stripper<'a> {
+    source: &'a str,
+    tokens: Vec<Token<'a>>,
+    /// Ranges (token indexes) to remove
+    removed: Vec<bool>,
+}
+
+impl<'a> Stripper<'a> {
+    fn text(&self, i: usize) -> &str {
+        self.tokens.get(i).map_or("", |t| t.text)
+    }
+
+    fn is_ident(&self, i: usize) -> bool {
+        self.tokens.get(i).map_or(false, |t| t.kind == Kind::Ident)
+    }
+
+    fn remove(&mut self, from: usize, to: usize) {
+        for r in self.removed.iter_mut().take(to).skip(from) {
+            *r = true;
+        }
+    }
+
+    /// Index of the token closing the bracket opened at `i`.
+    fn matching(&self, i: usize) -> Option<usize> {
+        let (open, close) = match self.text(i) {
+            "(" => ("(", ")"),
+            "[" => ("[", "]"),
+            "{" => ("{", "}"),
+            "<" => ("<", ">"),
+            _ => return None,
+        };
+        let mut depth = 0;
+        for j in i..self.tokens.len() {
+            if self.text(j) == open {
+                depth += 1;
+            } else if self.text(j) == close {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(j);
+                }
+            }
+        }
+        None
+    }
+
+    /// Skips a type starting at `i`, until a terminator at depth 0.
+    /// When `stop_at_newline` is set, the type also ends at a line break.
+    fn skip_type(&self, i: usize, terminators: &[&str], stop_at_newline: bool) -> usize {
+        let mut depth = 0;
+        let mut j = i;
+        while j < self.tokens.len() {
+            let text = self.text(j);
+            if depth == 0 && j > i {
+                let previous = self.text(j - 1);
+                let continued = matches!(previous, "|" | "&" | "=>" | ":" | "," | "." | "?");
+                if terminators.contains(&text) && !continued {
+                    break;
+                }
+                if stop_at_newline
+                    && self.tokens[j].newline_before
+                    && !continued
+                    && !matches!(text, "|" | "&" | "." | "[" | "<" | "=>")
+                {
+                    break;
+                }
+            }
+            match text {
+                "(" | "[" | "{" | "<" => depth += 1,
+                ")" | "]" | "}" | ">" => {
+                    if depth == 0 {
+                        break;
+                    }
+                    depth -= 1;
+                }
+                ";" if depth == 0 => break,
+                _ => (),
+            }
+            j += 1;
+        }
+        j
+    }
+
+    /// Checks whether the parenthesis at `i` opens a parameter list.
+    fn params_at(&self, i: usize) -> Option<Open> {
+        let close = self.matching(i)?;
+        let previous = if i > 0 { self.text(i - 1) } else { "" };
+
+        // Arrow function: `(...) =>` or `(...): Type =>`
+        let next = self.text(close + 1);
+        if next == "=>" {
+            return Some(Open::Params { arrow: true });
+        }
+        if next == ":" {
+            let end = self.skip_type(close + 2, &["=>", "{", ";", ",", ")"], false);
+            if self.text(end) == "=>" {
+                return Some(Open::Params { arrow: true });
+            }
+        }
+
+        // Function or method: `function (...)`, `function name(...)`, `name(...) {`
+        if previous == "function" || (i > 1 && self.text(i - 2) == "function") {
+            return Some(Open::Params { arrow: false });
+        }
+        let body_follows = next == "{"
+            || (next == ":" && self.text(self.skip_type(close + 2, &["{"], true)) == "{");
+        if i > 0
+            && (self.is_ident(i - 1) || previous == ">")
+            && !CONTROL_KEYWORDS.contains(&previous)
+            && body_follows
+        {
+            return Some(Open::Params { arrow: false });
+        }
+
+        None
+    }
+
+    /// Describes the unsupported syntax starting at `i`, if any.
+    fn unsupported(&self, i: usize) -> Option<&'static str> {
+        let token = self.tokens[i];
+        if token.kind == Kind::Punct && token.text == "@" {
+            return Some("decorators");
+        }
+        let member = i > 0 && matches!(self.text(i - 1), "." | "?.");
+        let next = self.tokens.get(i + 1)?;
+        // `value satisfies Type` (a type can start with a name, literal or bracket)
+        if token.text == "satisfies"
+            && !member
+            && i > 0
+            && self.ends_expression(i - 1)
+            && (next.kind != Kind::Punct || matches!(next.text, "{" | "[" | "("))
+        {
+            return Some("`satisfies` operators");
+        }
+        if token.kind != Kind::Ident || member || next.kind != Kind::Ident || next.newline_before {
+            return None;
+        }
+        UNSUPPORTED_KEYWORDS
+            .iter()
+            .find(|(keyword, _)| *keyword == token.text)
+            .map(|(_, syntax)| *syntax)
+    }
+
+    /// Checks whether the token at `i` can end an expression.
+    fn ends_expression(&self, i: usize) -> bool {
+        let token = self.tokens[i];
+        match token.kind {
+            Kind::Ident => {
+                !EXPRESSION_KEYWORDS.contains(&token.text)
+                    && !matches!(token.text, "let" | "const" | "var")
+            }
+            Kind::Punct => matches!(token.text, ")" | "]" | "}"),
+            _ => true,
+        }
+    }
+
+    /// Checks whether the tokens between `<` at `i` and the matching `>` at `end` can be
+    /// type arguments (names, literals, arrays, unions and nested generics).
+    fn type_arguments(&self, i: usize, end: usize) -> bool {
+        end > i + 1
+            && (i + 1..end).all(|j| {
+                self.tokens[j].kind != Kind::Punct
+                    || matches!(self.text(j), "," | "." | "[" | "]" | "|" | "&" | "<" | ">")
+            })
+    }
+
+    fn error(&self, i: usize, message: &str) -> String {
+        let line = self.source[..self.tokens[i].start].matches('\n').count() + 1;
+        format!("line {}: {}", line, message)
+    }
+
+    fn at_statement_start(&self, i: usize) -> bool {
+        i == 0 || self.tokens[i].newline_before || matches!(self.text(i - 1), ";" | "{" | "}")
+    }
+
+    fn strip(&mut self) -> Result<(), String> {
+        let mut stack: Vec<Open> = vec![];
+        // Default value expression in parameter list
+        let mut in_default = false;
+        // Import / export specifiers (where `as` renames)
+        let mut in_module_specifier = false;
+
+        let mut i = 0;
+        while i < self.tokens.len() {
+            let text = self.text(i);
+            let top = stack.last().copied();
+
+            if !self.removed[i] {
+                if let Some(syntax) = self.unsupported(i) {
+                    return Err(self.error(i, &format!("{} are not supported", syntax)));
+                }
+            }
+
+            // Declarations: `type X = ...`, `interface X {...}`, `import type ...`
+            let statement_start = self.at_statement_start(i);
+            let export = text == "export" && matches!(self.text(i + 1), "type" | "interface");
+            let decl = if export { i + 1 } else { i };
+            if (statement_start || export) && self.is_ident(decl + 1) {
+                match self.text(decl) {
+                    "type" if matches!(self.text(decl + 2), "=" | "<") => {
+                        let mut end = decl + 2;
+                        if self.text(end) == "<" {
+                            end = self.matching(end).map_or(end, |e| e + 1);
+                        }
+                        end = self.skip_type(end + 1, &[";"], true);
+                        if self.text(end) == ";" {
+                            end += 1;
+                        }
+                        self.remove(i, end);
+                        i = end;
+                        continue;
+                    }
+                    "interface" => {
+                        let body = (decl..self.tokens.len()).find(|j| self.text(*j) == "{");
+                        if let Some(end) = body.and_then(|b| self.matching(b)) {
+                            self.remove(i, end + 1);
+                            i = end + 1;
+                            continue;
+                        }
+                    }
+                    _ => (),
+                }
+            }
+            if statement_start && text == "import" && self.text(i + 1) == "type" {
+                let end = (i..self.tokens.len())
+                    .find(|j| self.text(*j) == ";" || self.tokens[*j].kind == Kind::Str)
+                    .map_or(self.tokens.len(), |j| j + 1);
+                let end = if self.text(end) == ";" { end + 1 } else { end };
+                self.remove(i, end);
+                i = end;
+                continue;
+            }
+
+            // Module specifiers
+            if statement_start && (text == "import" || text == "export") {
+                in_module_specifier = true;
+            } else if in_module_specifier
+                && (text == ";" || self.tokens[i].kind == Kind::Str || text == "function")
+            {
+                in_module_specifier = false;
+            }
+
+            match text {
+                "(" => {
+                    let open = self.params_at(i).unwrap_or(Open::Paren);
+                    stack.push(open);
+                    in_default = false;
+                }
+                "{" => stack.push(Open::Brace),
+                "[" => stack.push(Open::Bracket),
+                ")" => {
+                    let closed = stack.pop();
+                    in_default = false;
+                    // Return type
+                    if let Some(Open::Params { arrow }) = closed {
+                        if self.text(i + 1) == ":" {
+                            let terminators: &[&str] = if arrow { &["=>"] } else { &["{"] };
+                            let end = self.skip_type(i + 2, terminators, false);
+                            self.remove(i + 1, end);
+                            i = end;
+                            continue;
+                        }
+                    }
+                }
+                "}" | "]" => {
+                    stack.pop();
+                }
+                "," if matches!(top, Some(Open::Params { .. })) => in_default = false,
+                "=" if matches!(top, Some(Open::Params { .. })) => in_default = true,
+                // Optional parameter
+                "?" if matches!(top, Some(Open::Params { .. }))
+                    && !in_default
+                    && self.text(i + 1) == ":" =>
+                {
+                    self.remove(i, i + 1);
+                }
+                // Parameter type
+                ":" if matches!(top, Some(Open::Params { .. })) && !in_default => {
+                    let end = self.skip_type(i + 1, &[",", ")", "="], false);
+                    self.remove(i, end);
+                    i = end;
+                    continue;
+                }
+                // Variable type
+                "let" | "const" | "var" => {
+                    let mut name_end = i + 1;
+                    if matches!(self.text(i + 1), "{" | "[") {
+                        name_end = self.matching(i + 1).unwrap_or(i + 1);
+                    }
+                    if self.text(name_end + 1) == ":" {
+                        // Keep destructuring patterns
+                        if name_end > i + 1 {
+                            self.remove(name_end + 1, name_end + 2);
+                            let end = self.skip_type(name_end + 2, &["=", ";", ","], true);
+                            self.remove(name_end + 2, end);
+                            i = name_end + 1;
+                            continue;
+                        }
+                        let end = self.skip_type(name_end + 2, &["=", ";", ","], true);
+                        self.remove(name_end + 1, end);
+                    }
+                }
+                // Arrow function generics (`<T>(items: T[]) => ...`), or `<T>value` assertions
+                "<" if !self.removed[i]
+                    && (regex_allowed(i.checked_sub(1).map(|p| &self.tokens[p]))
+                        || self.text(i - 1) == "async") =>
+                {
+                    let end = self
+                        .matching(i)
+                        .filter(|end| self.text(end + 1) == "(")
+                        .filter(|end| {
+                            self.params_at(end + 1) == Some(Open::Params { arrow: true })
+                        });
+                    match end {
+                        Some(end) => {
+                            self.remove(i, end + 1);
+                            i = end + 1;
+                            continue;
+                        }
+                        None => {
+                            return Err(self
+                                .error(i, "angle bracket assertions are not supported (use `as`)"))
+                        }
+                    }
+                }
+                // Method generics (`name<T>(items: T[]) {`), type arguments and generic classes
+                "<" if !self.removed[i] && i > 0 && self.is_ident(i - 1) => {
+                    if let Some(end) = self.matching(i).filter(|end| self.type_arguments(i, *end)) {
+                        if i > 1 && self.text(i - 2) == "class" {
+                            return Err(self.error(i, "generic classes are not supported"));
+                        }
+                        if self.text(end + 1) == "(" {
+                            if self.params_at(end + 1) == Some(Open::Params { arrow: false }) {
+                                self.remove(i, end + 1);
+                                i = end + 1;
+                                continue;
+                            }
+                            return Err(self.error(i, "type arguments are not supported"));
+                        }
+                    }
+                }
+                // Function generics
+                "function" => {
+                    let generics = if self.text(i + 1) == "<" {
+                        Some(i + 1)
+                    } else if self.is_ident(i + 1) && self.text(i + 2) == "<" {
+                        Some(i + 2)
+                    } else {
+                        None
+                    };
+                    if let Some(end) = generics.and_then(|g| self.matching(g)) {
+                        self.remove(generics.unwrap(), end + 1);
+                    }
+                }
+                // Type assertion
+                "as" if !in_module_specifier && i > 0 && !self.tokens[i].newline_before => {
+                    let end = self.skip_assertion(i + 1);
+                    self.remove(i, end);
+                    i = end;
+                    continue;
+                }
+                // Non-null assertion
+                "!" if i > 0
+                    && !self.tokens[i].newline_before
+                    && (self.is_ident(i - 1) || matches!(self.text(i - 1), ")" | "]"))
+                    && matches!(self.text(i + 1), "." | ")" | "," | ";" | "]" | "?." | "[") =>
+                {
+                    self.remove(i, i + 1);
+                }
+                _ => (),
+            }
+
+            i += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Skips the type of an `as` assertion (such as `Foo.Bar<T>[] | null`).
+    fn skip_assertion(&self, i: usize) -> usize {
+        let mut j = i;
+        loop {
+            while TYPE_OPERATORS.contains(&self.text(j)) {
+                j += 1;
+            }
+            // Type name (or `const`)
+            if self.text(j) == "(" || self.text(j) == "{" || self.text(j) == "[" {
+                j = self.matching(j).map_or(j + 1, |e| e + 1);
+            } else if self.is_ident(j) || self.tokens.get(j).map_or(false, |t| t.kind == Kind::Str)
+            {
+                j += 1;
+                while self.text(j) == "." && self.is_ident(j + 1) {
+                    j += 2;
+                }
+            } else {
+                return j;
+            }
+            // Generics and arrays
+            if self.text(j) == "<" {
+                j = self.matching(j).map_or(j + 1, |e| e + 1);
+            }
+            while self.text(j) == "[" && self.text(j + 1) == "]" {
+                j += 2;
+            }
+            // Union and intersection
+            if matches!(self.text(j), "|" | "&") {
+                j += 1;
+            } else {
+                return j;
+            }
+        }
+    }
+}
+
+/// Strips TypeScript type annotations and declarations, producing JavaScript.
+pub fn strip_types(source: &str) -> Result<String, String> {
+    let tokens = tokenize(source)?;
+    let mut stripper = Stripper {
+        source,
+        removed: vec![false; tokens.len()],
+        tokens,
+    };
+    stripper.strip()?;
+
+    // Blank removed tokens (and whitespace between them), keeping line breaks
+    let mut output = source.as_bytes().to_vec();
+    let mut i = 0;
+    while i < stripper.tokens.len() {
+        if stripper.removed[i] {
+            let start = stripper.tokens[i].start;
+            let mut j = i;
+            while j + 1 < stripper.tokens.len() && stripper.removed[j + 1] {
+                j += 1;
+            }
+            let end = stripper.tokens[j].end;
+            for b in output.iter_mut().take(end).skip(start) {
+                if *b != b'\n' {
+                    *b = b' ';
+                }
+            }
+            i = j + 1;
+        } else {
+            i += 1;
+        }
+    }
+
+    String::from_utf8(output).map_err(|e| e.to_string())
+}
@@ -0,0 +1,167 @@
+use super::typescript::strip_types;
+
+fn strip(source: &str) -> String {
+    // Compare without blanked whitespace
+    strip_types(source)
+        .unwrap()
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .replace(" )", ")")
+        .replace(" ;", ";")
+        .replace(" ,", ",")
+        .replace(" .", ".")
+}
+
+#[test]
+fn strip_annotations() {
+    assert_eq!(
+        strip("const x: number = 1; let y: Array<string> = [];"),
+        "const x = 1; let y = [];"
+    );
+    assert_eq!(
+        strip("function f(a: number, b?: string, c: number = 2): boolean { return a > c ? true : false; }"),
+        "function f(a, b, c = 2) { return a > c ? true : false; }"
+    );
+    assert_eq!(
+        strip("const f = (p: Player, n: number): number => p.prices.length * n;"),
+        "const f = (p, n) => p.prices.length * n;"
+    );
+    assert_eq!(
+        strip("export function decide(player: Player, slug: string, context: StrategyContext, params: { min: number }): Decision | null {}"),
+        "export function decide(player, slug, context, params) {}"
+    );
+    assert_eq!(
+        strip("function first<T>(items: T[]): T | undefined { return items[0]; }"),
+        "function first (items) { return items[0]; }"
+    );
+}
+
+#[test]
+fn strip_declarations() {
+    assert_eq!(
+        strip("import type { Player } from \"socli\";\nimport { age as a } from \"socli\";"),
+        "import { age as a } from \"socli\";"
+    );
+    assert_eq!(
+        strip("type Action = \"Buy\" | \"Sell\";\ninterface Foo extends Bar { a: number; b: { c: string } }\nconst z = 1;"),
+        "const z = 1;"
+    );
+    assert_eq!(
+        strip("export type Params = { min: number }\nexport const x = 1"),
+        "export const x = 1"
+    );
+}
+
+#[test]
+fn strip_assertions() {
+    assert_eq!(
+        strip("const n = (player.stats as Stats).score as number;"),
+        "const n = (player.stats).score;"
+    );
+    assert_eq!(
+        strip("const s = player.stats!.score;"),
+        "const s = player.stats.score;"
+    );
+    assert_eq!(strip("if (a != b && !c) {}"), "if (a != b && !c) {}");
+}
+
+#[test]
+fn keep_javascript() {
+    let source = "const o = { a: 1, b: c ? d : e, f: g ? h(1) : i };\nconst s = `${o.a}: ${'x'}`;\nconst r = /a:b/g;\nfoo(function (x) { return x; });\n// type: comment\n";
+    assert_eq!(strip_types(source).unwrap(), source);
+}
+
+#[test]
+fn keep_positions() {
+    let stripped = strip_types("let a: number = 1;\nthrow new Error();").unwrap();
+    assert_eq!(stripped, "let a         = 1;\nthrow new Error();");
+}
+
+#[test]
+fn strip_generic_arrows_and_const_assertions() {
+    assert_eq!(
+        strip("const first = <T,>(items: T[]): T => items[0];"),
+        "const first = (items) => items[0];"
+    );
+    assert_eq!(
+        strip("const f = async <T extends Array<number>>(x: T) => x;"),
+        "const f = async (x) => x;"
+    );
+    assert_eq!(
+        strip("const ACTIONS = [\"Buy\", \"Sell\"] as const;\nconst k = key as keyof typeof o;"),
+        "const ACTIONS = [\"Buy\", \"Sell\"]; const k = key;"
+    );
+    assert_eq!(strip("if (a < b && c > d) {}"), "if (a < b && c > d) {}");
+    assert_eq!(
+        strip("const o = { first<T>(items: T[]): T { return items[0]; } };"),
+        "const o = { first (items) { return items[0]; } };"
+    );
+    assert_eq!(
+        strip("for (let i = 0; i < n; i++) { f(i > (n - 1)); }"),
+        "for (let i = 0; i < n; i++) { f(i > (n - 1)); }"
+    );
+}
+
+#[test]
+fn reject_unsupported_syntax() {
+    let error = |source: &str| strip_types(source).unwrap_err();
+    assert_eq!(
+        error("const x = 1;\nenum Action { Buy, Sell }"),
+        "line 2: enums are not supported"
+    );
+    assert_eq!(
+        error("const enum Action { Buy }"),
+        "line 1: enums are not supported"
+    );
+    assert_eq!(
+        error("namespace Utils { export const x = 1; }"),
+        "line 1: namespaces are not supported"
+    );
+    assert_eq!(
+        error("declare const x: number;"),
+        "line 1: ambient declarations are not supported"
+    );
+    assert_eq!(
+        error("@sealed\nclass A {}"),
+        "line 1: decorators are not supported"
+    );
+    assert_eq!(
+        error("class A {\n  private x = 1;\n}"),
+        "line 2: class member modifiers are not supported"
+    );
+    assert_eq!(
+        error("const n = <number>value;"),
+        "line 1: angle bracket assertions are not supported (use `as`)"
+    );
+    assert_eq!(
+        error("const p = { a: 1 } satisfies Params;"),
+        "line 1: `satisfies` operators are not supported"
+    );
+    assert_eq!(
+        error("const prices = new Map<string, number>();"),
+        "line 1: type arguments are not supported"
+    );
+    // Parsed as type arguments by TypeScript too
+    assert_eq!(
+        error("const x = f(a < b, c > (d));"),
+        "line 1: type arguments are not supported"
+    );
+    assert_eq!(
+        error("class Box<T> {}"),
+        "line 1: generic classes are not supported"
+    );
+    // Keywords used as names are not rejected
+    assert_eq!(
+        strip("const o = { enum: 1, private: 2 };\no.module = o.enum;"),
+        "const o = { enum: 1, private: 2 }; o.module = o.enum;"
+    );
+    assert_eq!(
+        strip("let satisfies = 1; f(satisfies);"),
+        "let satisfies = 1; f(satisfies);"
+    );
+    assert_eq!(
+        strip("function f(a: readonly string[]): void {}"),
+        "function f(a) {}"
+    );
+}
@@ -0,0 +1,148 @@
+// SoCli strategy type definitions.
+// Generated by `socli types`: do not edit, run the command again after upgrading SoCli.
+
+/** Sale price of a player card (amounts are decimal strings) */
+interface Price {
+  player_slug: string;
+  /** RFC 3339 date */
+  date: string;
+  eur: string;
+  usd: string;
+}
+
+/** Game played (or not) by a player */
+interface Game {
+  /** RFC 3339 date */
+  date: string;
+  did_play: boolean;
+  score: number;
+  minutes_played: number;
+}
+
+/** Player stats over the last games */
+interface Stats {
+  player_slug: string;
+  /** Average score */
+  score: number;
+  /** Last games, most recent first */
+  games: Game[];
+}
+
+/** Current injury of a player */
+interface Injury {
+  player_slug: string;
+  /** RFC 3339 date */
+  date: string;
+  update_date: string | null;
+  description: string;
+  comment: string;
+}
+
+/** Player, as passed to strategies */
+interface Player {
+  slug: string;
+  display_name: string;
+  /** RFC 3339 date */
+  birth_date: string;
+  team: string | null;
+  /** Last sale prices, most recent first */
+  prices: Price[];
+  stats: Stats | null;
+  /** Null when healthy, or when injuries are not loaded yet (see `@requires injury`) */
+  injury: Injury | null;
+  positions: string[];
+  country: string;
+  number: number;
+}
+
+/** League wide aggregates, computed from all loaded players */
+interface LeagueStats {
+  player_count: number;
+  scored_count: number;
+  priced_count: number;
+  /** Score percentiles */
+  score_percentiles: { p10?: number; p25?: number; p50?: number; p75?: number; p90?: number };
+  /** Median of the last sale prices (EUR) */
+  price_median: number | null;
+  /** Median of the last sale prices (EUR), by position */
+  price_median_by_position: Record<string, number>;
+}
+
+/** Read-only view of all loaded players */
+interface StrategyContext {
+  league: LeagueStats;
+  /** Loaded player by slug */
+  player(slug: string): Player | null;
+  /** Player rank by score (only available once all scores are loaded) */
+  rank(slug: string): number | null;
+}
+
+/** Decision returned by a strategy */
+interface Decision {
+  /** Action, such as "Buy" or "Sell" */
+  action: string;
+  comment: string;
+}
+
+/** Parameter declaration, exported as `params` */
+interface ParamSpec {
+  type: "number" | "string" | "boolean";
+  default: number | string | boolean;
+}
+
+type ParamSpecs = Record<string, ParamSpec>;
+
+/** Resolved parameter values */
+type ParamValues = Record<string, number | string | boolean>;
+
+/** Signature of the `decide` function exported by strategies */
+type Decide<P = ParamValues> = (
+  player: Player,
+  slug: string,
+  context: StrategyContext,
+  params: P
+) => Decision | null | undefined | void;
+
+/** Per strategy key-value store, persisted across sessions */
+declare const state: {
+  get<T = any>(key: string, fallback?: T): T;
+  set(key: string, value: unknown): void;
+  delete(key: string): void;
+  keys(): string[];
+};
+
+/** Strategy logs, displayed in the log panel */
+declare const log: {
+  debug(...args: unknown[]): void;
+  info(...args: unknown[]): void;
+  warn(...args: unknown[]): void;
+  error(...args: unknown[]): void;
+};
+
+/** Helper library */
+declare module "socli" {
+  /** Ratio between the last and oldest sale price */
+  export function price_delta_ratio(player: Player, currency?: "eur" | "usd"): number | null;
+  /** Average of the `count` last sale prices */
+  export function price_avg(player: Player, count?: number, currency?: "eur" | "usd"): number | null;
+  /** Player age in years */
+  export function age(player: Player): number;
+  /** Average interval between sales, in hours */
+  export function sales_hours_interval_avg(player: Player): number | null;
+  /** Ratio of played games among the last games */
+  export function played_games_ratio(player: Player): number | null;
+  /** Sale prices as numbers, most recent first */
+  export function prices(player: Player, currency?: "eur" | "usd"): number[];
+  /** Last sale price as a number */
+  export function last_price(player: Player, currency?: "eur" | "usd"): number | undefined;
+  /** Game scores, most recent first */
+  export function game_scores(player: Player): number[];
+  /** Moving averages over `window` values */
+  export function moving_average(values: number[], window: number): number[];
+  /** Least squares trend of values */
+  export function linear_trend(values: number[]): { slope: number; intercept: number } | undefined;
+  /** `p`th percentile (0 - 100) of values */
+  export function percentile(values: number[], p: number): number | undefined;
+  /** Percentage of values lower than or equal to `value` */
+  export function percentile_rank(values: number[], value: number): number | undefined;
+}
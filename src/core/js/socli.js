@@ -0,0 +1,95 @@
+// SoCli helper library for strategy scripts.
+// Import with: import { price_avg, age } from "socli";
+
+const native = globalThis.__socli;
+
+// Player computations (shared with the Rust side)
+
+export function price_delta_ratio(player, currency = "eur") {
+  return native.price_delta_ratio(JSON.stringify(player), currency);
+}
+
+export function price_avg(player, count = 5, currency = "eur") {
+  return native.price_avg(JSON.stringify(player), currency, count);
+}
+
+export function age(player) {
+  return native.age(JSON.stringify(player));
+}
+
+export function sales_hours_interval_avg(player) {
+  return native.sales_hours_interval_avg(JSON.stringify(player));
+}
+
+export function played_games_ratio(player) {
+  return native.played_games_ratio(JSON.stringify(player));
+}
+
+// Data access
+
+// Sale prices as numbers, most recent first
+export function prices(player, currency = "eur") {
+  return player.prices.map((p) => parseFloat(p[currency]));
+}
+
+// Last sale price as a number (undefined if no sale)
+export function last_price(player, currency = "eur") {
+  return player.prices.length > 0
+    ? parseFloat(player.prices[0][currency])
+    : undefined;
+}
+
+// Game scores, most recent first
+export function game_scores(player) {
+  return player.stats ? player.stats.games.map((g) => g.score) : [];
+}
+
+// Series
+
+// Moving averages over `window` values (empty if not enough values)
+export function moving_average(values, window) {
+  const averages = [];
+  for (let i = 0; i + window <= values.length; i++) {
+    const slice = values.slice(i, i + window);
+    averages.push(slice.reduce((a, b) => a + b, 0) / window);
+  }
+  return averages;
+}
+
+// Least squares linear trend of values (x is the value index)
+export function linear_trend(values) {
+  const n = values.length;
+  if (n < 2) {
+    return undefined;
+  }
+  const meanX = (n - 1) / 2;
+  const meanY = values.reduce((a, b) => a + b, 0) / n;
+  let num = 0;
+  let den = 0;
+  values.forEach((y, x) => {
+    num += (x - meanX) * (y - meanY);
+    den += (x - meanX) * (x - meanX);
+  });
+  const slope = num / den;
+  return { slope, intercept: meanY - slope * meanX };
+}
+
+// `p`th percentile (0 - 100) of values, using linear interpolation
+export function percentile(values, p) {
+  if (values.length === 0) {
+    return undefined;
+  }
+  const sorted = [...values].sort((a, b) => a - b);
+  const rank = (Math.min(Math.max(p, 0), 100) / 100) * (sorted.length - 1);
+  const lower = Math.floor(rank);
+  const upper = Math.ceil(rank);
+  return sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower);
+}
+
+// Percentage (0 - 100) of values lower than or equal to `value`
+export function percentile_rank(values, value) {
+  if (values.length === 0) {
+    return undefined;
+  }
+  return (values.filter((v) => v <= value).length / values.length) * 100;
+}
@@ -0,0 +1,198 @@
+use std::{sync::OnceLock, time::Duration};
+
+use prometheus::{
+    core::Collector, histogram_opts, opts, Encoder, HistogramTimer, HistogramVec, IntCounterVec,
+    IntGauge, IntGaugeVec, Registry, TextEncoder,
+};
+
+/// Operational metrics, exposed in Prometheus text format.
+pub struct Metrics {
+    registry: Registry,
+    graphql_requests: IntCounterVec,
+    graphql_duration: HistogramVec,
+    errors: IntCounterVec,
+    task_duration: HistogramVec,
+    running_tasks: IntGauge,
+    task_failures: IntCounterVec,
+    strategy_duration: HistogramVec,
+    decisions: IntCounterVec,
+    players: IntGaugeVec,
+}
+
+/// Process wide metrics (not registered in the container: strategies are also run without it).
+pub fn metrics() -> &'static Metrics {
+    static METRICS: OnceLock<Metrics> = OnceLock::new();
+    METRICS.get_or_init(Metrics::new)
+}
+
+impl Metrics {
+    pub(crate) fn new() -> Self {
+        let registry = Registry::new_custom(Some("socli".to_string()), None)
+            .expect("failed to create metrics registry");
+
+        let graphql_requests = IntCounterVec::new(
+            opts!("graphql_requests_total", "Sorare GraphQL requests"),
+            &["repository", "status"],
+        )
+        .unwrap();
+        let graphql_duration = HistogramVec::new(
+            histogram_opts!(
+                "graphql_request_duration_seconds",
+                "Sorare GraphQL requests duration"
+            ),
+            &["repository"],
+        )
+        .unwrap();
+        let errors =
+            IntCounterVec::new(opts!("errors_total", "Errors, by kind"), &["kind"]).unwrap();
+        let task_duration = HistogramVec::new(
+            histogram_opts!(
+                "task_duration_seconds",
+                "Background tasks duration",
+                vec![0.01, 0.05, 0.1, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 300.0]
+            ),
+            &["task"],
+        )
+        .unwrap();
+        let running_tasks =
+            IntGauge::with_opts(opts!("running_tasks", "Background tasks running")).unwrap();
+        let task_failures = IntCounterVec::new(
+            opts!("task_failures_total", "Background task failures"),
+            &["task"],
+        )
+        .unwrap();
+        let strategy_duration = HistogramVec::new(
+            histogram_opts!(
+                "strategy_duration_seconds",
+                "Strategy scripts execution time",
+                vec![0.0005, 0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0]
+            ),
+            &["strategy"],
+        )
+        .unwrap();
+        let decisions = IntCounterVec::new(
+            opts!("decisions_total", "Decisions returned by strategies"),
+            &["strategy", "action"],
+        )
+        .unwrap();
+        let players = IntGaugeVec::new(
+            opts!(
+                "players",
+                "Loaded players, by loaded data (all, prices, stats, injury)"
+            ),
+            &["data"],
+        )
+        .unwrap();
+
+        registry
+            .register(Box::new(graphql_requests.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(graphql_duration.clone()))
+            .unwrap();
+        registry.register(Box::new(errors.clone())).unwrap();
+        registry.register(Box::new(task_duration.clone())).unwrap();
+        registry.register(Box::new(running_tasks.clone())).unwrap();
+        registry.register(Box::new(task_failures.clone())).unwrap();
+        registry
+            .register(Box::new(strategy_duration.clone()))
+            .unwrap();
+        registry.register(Box::new(decisions.clone())).unwrap();
+        registry.register(Box::new(players.clone())).unwrap();
+
+        Self {
+            registry,
+            graphql_requests,
+            graphql_duration,
+            errors,
+            task_duration,
+            running_tasks,
+            task_failures,
+            strategy_duration,
+            decisions,
+            players,
+        }
+    }
+
+    /// Records a GraphQL request of a repository (failed if no response was received).
+    pub fn graphql_request(&self, repository: &str, duration: Duration, success: bool) {
+        let status = if success { "ok" } else { "error" };
+        self.graphql_requests
+            .with_label_values(&[repository, status])
+            .inc();
+        self.graphql_duration
+            .with_label_values(&[repository])
+            .observe(duration.as_secs_f64());
+        if !success {
+            self.error("http");
+        }
+    }
+
+    /// Counts an error (such as `http`, `graphql`, `strategy` or `notification`).
+    pub fn error(&self, kind: &str) {
+        self.errors.with_label_values(&[kind]).inc();
+    }
+
+    /// Measures a task run, counted as running until the timer is dropped.
+    pub fn task_timer(&self, task: &str) -> TaskTimer {
+        self.running_tasks.inc();
+        TaskTimer {
+            _timer: self.task_duration.with_label_values(&[task]).start_timer(),
+            running_tasks: self.running_tasks.clone(),
+        }
+    }
+
+    pub fn task_failed(&self, task: &str) {
+        self.task_failures.with_label_values(&[task]).inc();
+    }
+
+    pub fn running_tasks(&self) -> i64 {
+        self.running_tasks.get()
+    }
+
+    /// Task failures since startup, all tasks included.
+    pub fn task_failures(&self) -> u64 {
+        self.task_failures
+            .collect()
+            .iter()
+            .flat_map(|family| family.get_metric())
+            .map(|metric| metric.get_counter().get_value() as u64)
+            .sum()
+    }
+
+    pub fn strategy_run(&self, strategy: &str, duration: Duration) {
+        self.strategy_duration
+            .with_label_values(&[strategy])
+            .observe(duration.as_secs_f64());
+    }
+
+    pub fn decision(&self, strategy: &str, action: &str) {
+        self.decisions.with_label_values(&[strategy, action]).inc();
+    }
+
+    /// Sets the count of loaded players with some data (`all` for every player).
+    pub fn set_players(&self, data: &str, count: usize) {
+        self.players.with_label_values(&[data]).set(count as i64);
+    }
+
+    /// Metrics in Prometheus text format.
+    pub fn encode(&self) -> Result<String, String> {
+        let mut buffer = vec![];
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .map_err(|e| e.to_string())?;
+        String::from_utf8(buffer).map_err(|e| e.to_string())
+    }
+}
+
+/// Running task, see `Metrics::task_timer`.
+pub struct TaskTimer {
+    _timer: HistogramTimer,
+    running_tasks: IntGauge,
+}
+
+impl Drop for TaskTimer {
+    fn drop(&mut self) {
+        self.running_tasks.dec();
+    }
+}
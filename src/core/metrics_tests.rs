@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use crate::core::metrics::Metrics;
+
+#[test]
+fn encode_recorded_metrics() {
+    let metrics = Metrics::new();
+    metrics.graphql_request("price", Duration::from_millis(120), true);
+    metrics.graphql_request("price", Duration::from_millis(80), false);
+    metrics.strategy_run("momentum", Duration::from_millis(2));
+    metrics.decision("momentum", "Buy");
+    metrics.set_players("all", 42);
+    {
+        let _timer = metrics.task_timer("load player prices");
+        assert_eq!(metrics.running_tasks(), 1);
+    }
+    assert_eq!(metrics.running_tasks(), 0);
+    metrics.task_failed("load player prices");
+    metrics.task_failed("run strategies");
+    assert_eq!(metrics.task_failures(), 2);
+
+    let text = metrics.encode().unwrap();
+
+    assert!(text.contains(r#"socli_graphql_requests_total{repository="price",status="ok"} 1"#));
+    assert!(text.contains(r#"socli_graphql_requests_total{repository="price",status="error"} 1"#));
+    assert!(text.contains(r#"socli_errors_total{kind="http"} 1"#));
+    assert!(text.contains(r#"socli_graphql_request_duration_seconds_count{repository="price"} 2"#));
+    assert!(text.contains(r#"socli_strategy_duration_seconds_count{strategy="momentum"} 1"#));
+    assert!(text.contains(r#"socli_decisions_total{action="Buy",strategy="momentum"} 1"#));
+    assert!(text.contains(r#"socli_players{data="all"} 42"#));
+    assert!(text.contains(r#"socli_task_duration_seconds_count{task="load player prices"} 1"#));
+    assert!(text.contains(r#"socli_task_failures_total{task="run strategies"} 1"#));
+    assert!(text.contains("socli_running_tasks 0"));
+}
@@ -1,14 +1,22 @@
+pub mod engine;
+pub mod metrics;
 pub mod model;
+pub mod notification;
 pub mod repository;
 pub mod service;
 
+#[cfg(test)]
+pub mod metrics_tests;
+
 use dilib::{
     add_singleton_trait,
     global::{init_container, InitContainerError},
 };
 use quartermaster::{manager::TaskManager, store::memory::InMemoryTaskStore};
+use tokio::sync::broadcast;
 
 use crate::core::{
+    model::{config::Config, decision::Decision},
     repository::{
         injury::{InjuryRepo, InjuryRepoImpl},
         player::{PlayerRepo, PlayerRepoImpl},
@@ -17,7 +25,13 @@ use crate::core::{
         storage::{StorageRepo, StorageRepoImpl},
     },
     service::{
+        backtest::{BacktestService, BacktestServiceImpl},
+        decision::{DecisionService, DecisionServiceImpl},
+        evaluation::{EvaluationService, EvaluationServiceImpl},
+        fixture::{FixtureService, FixtureServiceImpl},
+        history::{HistoryService, HistoryServiceImpl},
         injury::{InjuryService, InjuryServiceImpl},
+        notification::{NotificationService, NotificationServiceImpl},
         player::{PlayerService, PlayerServiceImpl},
         price::{PriceService, PriceServiceImpl},
         stats::{StatsService, StatsServiceImpl},
@@ -58,16 +72,32 @@ macro_rules! resolve {
 
 pub type MainTaskManager = TaskManager<InMemoryTaskStore>;
 
+/// Broadcasts new decisions (to HTTP API event streams)
+pub type DecisionSender = broadcast::Sender<Decision>;
+
 /// Setup depedency injection.
 /// Provided by https://github.com/Neo-Ciber94/dilib-rs#bind-trait-to-implementation.
-pub async fn setup_container<'a>(strategies_dir: &str) -> Result<(), InitContainerError> {
+pub async fn setup_container<'a>(
+    strategies_dir: &str,
+    config: Config,
+    notification_service: NotificationServiceImpl,
+) -> Result<(), InitContainerError> {
     let init_result = init_container(|container| {
         // Task manager
-        let task_manager = TaskManager::new(InMemoryTaskStore::new("task manager"), 2);
+        let task_manager =
+            TaskManager::new(InMemoryTaskStore::new("task manager"), config.tasks.workers);
         container.add_singleton(task_manager).unwrap();
 
+        // Settings
+        container.add_singleton(config.clone()).unwrap();
+
+        // New decisions channel
+        let (decision_sender, _) = broadcast::channel::<Decision>(100);
+        container.add_singleton(decision_sender).unwrap();
+
         // Repositories
-        add_singleton_trait!(container, PlayerRepo => PlayerRepoImpl::new()).unwrap();
+        add_singleton_trait!(container, PlayerRepo => PlayerRepoImpl::new(config.players.clone()))
+            .unwrap();
         add_singleton_trait!(container, PriceRepo => PriceRepoImpl::new()).unwrap();
         add_singleton_trait!(container, StorageRepo => StorageRepoImpl::new()).unwrap();
         add_singleton_trait!(container, StatsRepo => StatsRepoImpl::new()).unwrap();
@@ -79,6 +109,13 @@ pub async fn setup_container<'a>(strategies_dir: &str) -> Result<(), InitContain
         add_singleton_trait!(container, StatsService => StatsServiceImpl{}).unwrap();
         add_singleton_trait!(container, InjuryService => InjuryServiceImpl{}).unwrap();
         add_singleton_trait!(container, StrategyService => StrategyServiceImpl::new(strategies_dir)).unwrap();
+        add_singleton_trait!(container, HistoryService => HistoryServiceImpl::new()).unwrap();
+        add_singleton_trait!(container, BacktestService => BacktestServiceImpl::new(strategies_dir)).unwrap();
+        add_singleton_trait!(container, FixtureService => FixtureServiceImpl::new(strategies_dir))
+            .unwrap();
+        add_singleton_trait!(container, EvaluationService => EvaluationServiceImpl::new(strategies_dir)).unwrap();
+        add_singleton_trait!(container, DecisionService => DecisionServiceImpl::new()).unwrap();
+        add_singleton_trait!(container, NotificationService => notification_service).unwrap();
     });
 
     // Start task manager
@@ -0,0 +1,167 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+/// A simulated position, opened on a Buy decision and closed on a Sell decision.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Trade {
+    pub strategy: String,
+    pub player_slug: String,
+    pub buy_date: String,
+    pub buy_price: f64,
+    pub sell_date: Option<String>,
+    pub sell_price: Option<f64>,
+}
+
+impl Trade {
+    pub fn is_open(&self) -> bool {
+        self.sell_price.is_none()
+    }
+
+    /// Profit and loss: realized if closed, marked at `mark_price` otherwise.
+    pub fn pnl(&self, mark_price: Option<f64>) -> f64 {
+        match self.sell_price {
+            Some(sell_price) => sell_price - self.buy_price,
+            None => mark_price.unwrap_or(self.buy_price) - self.buy_price,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StrategyReport {
+    pub strategy: String,
+    pub trades: usize,
+    pub open_trades: usize,
+    pub winning_trades: usize,
+    /// Ratio of winning trades (open trades are marked at their last sale price)
+    pub hit_rate: Option<f64>,
+    /// Realized profit and loss (EUR)
+    pub realized_pnl: f64,
+    /// Realized and unrealized profit and loss (EUR)
+    pub pnl: f64,
+    /// Maximum peak to trough decline of the daily profit and loss (EUR)
+    pub max_drawdown: f64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BacktestReport {
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub days: usize,
+    pub strategies: Vec<StrategyReport>,
+    pub trades: Vec<Trade>,
+}
+
+/// Simulates trades from decisions, one unit per position.
+#[derive(Default)]
+pub struct Ledger {
+    trades: Vec<Trade>,
+    /// Daily profit and loss, by strategy
+    equity_curves: BTreeMap<String, Vec<f64>>,
+    /// Last sale price, by player slug
+    last_prices: HashMap<String, f64>,
+}
+
+impl Ledger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies a decision: `Buy` opens a position (if none is open), `Sell` closes it.
+    pub fn apply(
+        &mut self,
+        strategy: &str,
+        player_slug: &str,
+        action: &str,
+        date: &str,
+        price: f64,
+    ) {
+        self.equity_curves.entry(strategy.to_string()).or_default();
+        let open_trade = self
+            .trades
+            .iter_mut()
+            .find(|t| t.is_open() && t.strategy == strategy && t.player_slug == player_slug);
+
+        match (action.to_lowercase().as_str(), open_trade) {
+            ("buy", None) => self.trades.push(Trade {
+                strategy: strategy.to_string(),
+                player_slug: player_slug.to_string(),
+                buy_date: date.to_string(),
+                buy_price: price,
+                sell_date: None,
+                sell_price: None,
+            }),
+            ("sell", Some(trade)) => {
+                trade.sell_date = Some(date.to_string());
+                trade.sell_price = Some(price);
+            }
+            _ => (),
+        }
+    }
+
+    /// Updates last sale prices and records the profit and loss of each strategy (end of day).
+    pub fn mark(&mut self, last_prices: &HashMap<String, f64>) {
+        self.last_prices
+            .extend(last_prices.iter().map(|(k, v)| (k.clone(), *v)));
+        let strategies = self.equity_curves.keys().cloned().collect::<Vec<String>>();
+        for strategy in strategies {
+            let pnl = self.pnl(&strategy, false);
+            self.equity_curves.get_mut(&strategy).unwrap().push(pnl);
+        }
+    }
+
+    fn pnl(&self, strategy: &str, realized_only: bool) -> f64 {
+        self.trades
+            .iter()
+            .filter(|t| t.strategy == strategy && (!realized_only || !t.is_open()))
+            .map(|t| t.pnl(self.last_prices.get(&t.player_slug).copied()))
+            .sum()
+    }
+
+    pub fn trades(&self) -> &[Trade] {
+        &self.trades
+    }
+
+    pub fn reports(&self) -> Vec<StrategyReport> {
+        self.equity_curves
+            .iter()
+            .map(|(strategy, equity_curve)| {
+                let trades = self
+                    .trades
+                    .iter()
+                    .filter(|t| &t.strategy == strategy)
+                    .collect::<Vec<&Trade>>();
+                let winning_trades = trades
+                    .iter()
+                    .filter(|t| t.pnl(self.last_prices.get(&t.player_slug).copied()) > 0.0)
+                    .count();
+
+                StrategyReport {
+                    strategy: strategy.clone(),
+                    trades: trades.len(),
+                    open_trades: trades.iter().filter(|t| t.is_open()).count(),
+                    winning_trades,
+                    hit_rate: if trades.is_empty() {
+                        None
+                    } else {
+                        Some(winning_trades as f64 / trades.len() as f64)
+                    },
+                    realized_pnl: self.pnl(strategy, true),
+                    pnl: self.pnl(strategy, false),
+                    max_drawdown: max_drawdown(equity_curve),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Maximum peak to trough decline of an equity curve (starting at 0).
+pub fn max_drawdown(equity_curve: &[f64]) -> f64 {
+    let mut peak: f64 = 0.0;
+    let mut drawdown: f64 = 0.0;
+    for value in equity_curve {
+        peak = peak.max(*value);
+        drawdown = drawdown.max(peak - value);
+    }
+    drawdown
+}
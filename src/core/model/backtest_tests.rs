@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use super::backtest::{max_drawdown, Ledger};
+
+#[test]
+fn ledger_trades() {
+    let mut ledger = Ledger::new();
+
+    // Buy twice: only one position is opened
+    ledger.apply("s", "a", "Buy", "2023-07-01T00:00:00Z", 10.0);
+    ledger.apply("s", "a", "Buy", "2023-07-02T00:00:00Z", 12.0);
+    ledger.mark(&HashMap::from([("a".to_string(), 12.0)]));
+
+    // Sell closes the position
+    ledger.apply("s", "a", "Sell", "2023-07-03T00:00:00Z", 8.0);
+    ledger.mark(&HashMap::from([("a".to_string(), 8.0)]));
+
+    // Open position is marked at last price
+    ledger.apply("s", "b", "Buy", "2023-07-04T00:00:00Z", 5.0);
+    ledger.mark(&HashMap::from([("b".to_string(), 6.0)]));
+
+    let reports = ledger.reports();
+    assert_eq!(reports.len(), 1);
+    let report = &reports[0];
+    assert_eq!(report.trades, 2);
+    assert_eq!(report.open_trades, 1);
+    assert_eq!(report.winning_trades, 1);
+    assert_eq!(report.hit_rate, Some(0.5));
+    assert_eq!(report.realized_pnl, -2.0);
+    assert_eq!(report.pnl, -1.0);
+    assert_eq!(report.max_drawdown, 4.0);
+}
+
+#[test]
+fn ledger_sell_without_position() {
+    let mut ledger = Ledger::new();
+    ledger.apply("s", "a", "Sell", "2023-07-01T00:00:00Z", 10.0);
+
+    let reports = ledger.reports();
+    assert_eq!(reports[0].trades, 0);
+    assert_eq!(reports[0].hit_rate, None);
+}
+
+#[test]
+fn drawdown() {
+    assert_eq!(max_drawdown(&[]), 0.0);
+    assert_eq!(max_drawdown(&[1.0, 3.0, 0.0, 2.0, -1.0, 5.0]), 4.0);
+}
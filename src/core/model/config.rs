@@ -0,0 +1,274 @@
+use std::{fmt::Display, fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+use toml::Value;
+
+/// Settings file, in the `~/.socli` folder
+pub const CONFIG_FILE: &str = "config.toml";
+
+/// Prefix of settings environment variables (`SOCLI_<SECTION>_<KEY>`, such as `SOCLI_UI_TICK_RATE_MS`)
+pub const ENV_PREFIX: &str = "SOCLI_";
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Read(String),
+    Parse(String),
+    Invalid(String),
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read(msg) => write!(f, "failed to read settings: {}", msg),
+            Self::Parse(msg) => write!(f, "failed to parse settings: {}", msg),
+            Self::Invalid(msg) => write!(f, "invalid settings: {}", msg),
+        }
+    }
+}
+
+/// Runtime settings, read from `~/.socli/config.toml`.
+/// Every setting is optional, and can be overridden by an environment variable or a `--set` option.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub players: PlayersConfig,
+    pub refresh: RefreshConfig,
+    pub ui: UiConfig,
+    pub tasks: TasksConfig,
+}
+
+/// Players list loading
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PlayersConfig {
+    /// Players loaded by page (at most 50)
+    pub page_size: i64,
+    /// Delay between pages (milliseconds)
+    pub page_delay_ms: u64,
+}
+
+impl Default for PlayersConfig {
+    fn default() -> Self {
+        Self {
+            page_size: 50,
+            page_delay_ms: 2000,
+        }
+    }
+}
+
+/// Background refresh of player details: players are refreshed more often when selected,
+/// watched or with decisions, idle players less often.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RefreshConfig {
+    /// Delay between two player details refreshes (seconds)
+    pub interval_secs: u64,
+    /// Players refreshed together (with batch requests)
+    pub batch_size: usize,
+    /// Watched player slugs
+    pub watchlist: Vec<String>,
+    /// Minimum delay before refreshing a player again, by priority (seconds)
+    pub selected_interval_secs: u64,
+    pub watched_interval_secs: u64,
+    pub decision_interval_secs: u64,
+    pub player_interval_secs: u64,
+    /// Players without new sales are refreshed less often, up to this delay (seconds)
+    pub max_idle_interval_secs: u64,
+}
+
+impl Default for RefreshConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: 3,
+            batch_size: 5,
+            watchlist: vec![],
+            selected_interval_secs: 30,
+            watched_interval_secs: 120,
+            decision_interval_secs: 300,
+            player_interval_secs: 900,
+            max_idle_interval_secs: 7200,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UiConfig {
+    /// Refresh rate of the UI (milliseconds)
+    pub tick_rate_ms: u64,
+    /// Sales count of the displayed average price
+    pub price_avg_count: usize,
+}
+
+impl Default for UiConfig {
+    fn default() -> Self {
+        Self {
+            tick_rate_ms: 200,
+            price_avg_count: 5,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TasksConfig {
+    /// Background tasks run concurrently
+    pub workers: usize,
+}
+
+impl Default for TasksConfig {
+    fn default() -> Self {
+        Self { workers: 2 }
+    }
+}
+
+impl Config {
+    pub fn parse(content: &str) -> Result<Self, ConfigError> {
+        toml::from_str(content).map_err(|e| ConfigError::Parse(e.to_string()))
+    }
+
+    /// Reads a settings file (default settings if missing).
+    pub fn read(path: &Path) -> Result<Self, ConfigError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)
+            .map_err(|e| ConfigError::Read(format!("`{}`: {}", path.display(), e)))?;
+        toml::from_str(&content)
+            .map_err(|e| ConfigError::Parse(format!("`{}`: {}", path.display(), e)))
+    }
+
+    /// Setting keys (such as `ui.tick_rate_ms`)
+    pub fn keys(&self) -> Vec<String> {
+        self.to_value()
+            .iter()
+            .flat_map(|(section, value)| {
+                value
+                    .as_table()
+                    .map(|t| t.keys().map(|k| format!("{}.{}", section, k)).collect())
+                    .unwrap_or_else(Vec::new)
+            })
+            .collect()
+    }
+
+    /// Sets a setting from its key and textual value.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), ConfigError> {
+        let unknown = || ConfigError::Invalid(format!("unknown setting `{}`", key));
+        let (section, name) = key.split_once('.').ok_or_else(unknown)?;
+
+        let mut table = self.to_value();
+        let current = table
+            .get_mut(section)
+            .and_then(|s| s.get_mut(name))
+            .ok_or_else(unknown)?;
+        *current = match current {
+            Value::Integer(_) => value.trim().parse().map(Value::Integer).ok(),
+            Value::Boolean(_) => value.trim().parse().map(Value::Boolean).ok(),
+            // Comma separated values
+            Value::Array(_) => Some(Value::Array(
+                value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|v| !v.is_empty())
+                    .map(|v| Value::String(v.to_string()))
+                    .collect(),
+            )),
+            _ => Some(Value::String(value.to_string())),
+        }
+        .ok_or_else(|| {
+            ConfigError::Invalid(format!("`{}` is not a valid value for `{}`", value, key))
+        })?;
+
+        *self = Value::Table(table)
+            .try_into()
+            .map_err(|e: toml::de::Error| ConfigError::Invalid(format!("`{}`: {}", key, e)))?;
+        Ok(())
+    }
+
+    /// Applies `SOCLI_<SECTION>_<KEY>` environment variables.
+    pub fn apply_env<I: IntoIterator<Item = (String, String)>>(
+        &mut self,
+        vars: I,
+    ) -> Result<(), ConfigError> {
+        let keys = self.keys();
+        for (var, value) in vars {
+            if let Some(key) = keys.iter().find(|k| env_var(k) == var) {
+                self.set(key, &value)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies `key=value` overrides.
+    pub fn apply_overrides(&mut self, overrides: &[String]) -> Result<(), ConfigError> {
+        for o in overrides {
+            let (key, value) = o.split_once('=').ok_or_else(|| {
+                ConfigError::Invalid(format!("`{}` is not a `key=value` setting", o))
+            })?;
+            self.set(key.trim(), value)?;
+        }
+        Ok(())
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let check = |valid: bool, key: &str, expected: &str| {
+            if valid {
+                Ok(())
+            } else {
+                Err(ConfigError::Invalid(format!(
+                    "`{}` must be {}",
+                    key, expected
+                )))
+            }
+        };
+        check(
+            (1..=50).contains(&self.players.page_size),
+            "players.page_size",
+            "between 1 and 50",
+        )?;
+        check(
+            self.refresh.interval_secs > 0,
+            "refresh.interval_secs",
+            "positive",
+        )?;
+        check(
+            self.refresh.batch_size > 0,
+            "refresh.batch_size",
+            "positive",
+        )?;
+        check(
+            self.refresh.selected_interval_secs > 0
+                && self.refresh.watched_interval_secs > 0
+                && self.refresh.decision_interval_secs > 0
+                && self.refresh.player_interval_secs > 0,
+            "refresh.*_interval_secs",
+            "positive",
+        )?;
+        check(
+            self.refresh.max_idle_interval_secs >= self.refresh.player_interval_secs,
+            "refresh.max_idle_interval_secs",
+            "at least `refresh.player_interval_secs`",
+        )?;
+        check(self.ui.tick_rate_ms >= 10, "ui.tick_rate_ms", "at least 10")?;
+        check(
+            self.ui.price_avg_count > 0,
+            "ui.price_avg_count",
+            "positive",
+        )?;
+        check(self.tasks.workers > 0, "tasks.workers", "positive")
+    }
+
+    /// Settings as TOML
+    pub fn to_toml(&self) -> String {
+        toml::to_string(self).unwrap_or_default()
+    }
+
+    fn to_value(&self) -> toml::Table {
+        toml::Table::try_from(self).expect("failed to convert settings")
+    }
+}
+
+/// Environment variable of a setting key
+pub fn env_var(key: &str) -> String {
+    format!("{}{}", ENV_PREFIX, key.replace('.', "_").to_uppercase())
+}
@@ -0,0 +1,99 @@
+use super::config::{env_var, Config};
+
+#[test]
+fn parse_partial_config() {
+    let config = Config::parse(
+        r#"
+        [refresh]
+        interval_secs = 10
+
+        [tasks]
+        workers = 4
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(config.refresh.interval_secs, 10);
+    assert_eq!(config.refresh.batch_size, 5);
+    assert_eq!(config.tasks.workers, 4);
+    assert_eq!(config.players, Config::default().players);
+    assert!(Config::parse("[ui]\ntick_rate = 100").is_err());
+}
+
+#[test]
+fn read_config_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+    assert_eq!(Config::read(&path).unwrap(), Config::default());
+
+    std::fs::write(&path, "[ui]\ntick_rate = 100").unwrap();
+    let message = Config::read(&path).unwrap_err().to_string();
+    assert!(message.starts_with(&format!("failed to parse settings: `{}`: ", path.display())));
+    assert_eq!(message.matches("failed to parse settings").count(), 1);
+}
+
+#[test]
+fn set_setting() {
+    let mut config = Config::default();
+
+    config.set("ui.tick_rate_ms", "100").unwrap();
+
+    assert_eq!(config.ui.tick_rate_ms, 100);
+    assert!(config.set("ui.tick_rate", "100").is_err());
+    assert!(config.set("tick_rate_ms", "100").is_err());
+    assert!(config.set("ui.tick_rate_ms", "fast").is_err());
+    assert!(config.set("ui.tick_rate_ms", "-1").is_err());
+
+    config
+        .set("refresh.watchlist", "lebron-james, kevin-durant")
+        .unwrap();
+    assert_eq!(
+        config.refresh.watchlist,
+        vec!["lebron-james", "kevin-durant"]
+    );
+}
+
+#[test]
+fn overrides_precedence() {
+    let mut config = Config::parse("[players]\npage_size = 20").unwrap();
+
+    config
+        .apply_env(vec![
+            ("SOCLI_PLAYERS_PAGE_SIZE".to_string(), "30".to_string()),
+            ("SOCLI_UNKNOWN".to_string(), "1".to_string()),
+            ("HOME".to_string(), "/home/socli".to_string()),
+        ])
+        .unwrap();
+    assert_eq!(config.players.page_size, 30);
+
+    config
+        .apply_overrides(&["players.page_size=40".to_string()])
+        .unwrap();
+    assert_eq!(config.players.page_size, 40);
+    assert!(config
+        .apply_overrides(&["players.page_size".to_string()])
+        .is_err());
+    assert_eq!(env_var("refresh.batch_size"), "SOCLI_REFRESH_BATCH_SIZE");
+}
+
+#[test]
+fn validate_config() {
+    assert!(Config::default().validate().is_ok());
+
+    let mut config = Config::default();
+    config.players.page_size = 100;
+    assert!(config.validate().is_err());
+
+    let mut config = Config::default();
+    config.tasks.workers = 0;
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn config_to_toml() {
+    let config = Config::default();
+
+    assert_eq!(Config::parse(&config.to_toml()).unwrap(), config);
+    assert!(config.keys().contains(&"refresh.interval_secs".to_string()));
+    assert_eq!(config.keys().len(), 13);
+}
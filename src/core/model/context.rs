@@ -0,0 +1,144 @@
+use std::{collections::HashMap, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+
+use super::player::Player;
+
+/// Percentiles exposed in `LeagueStats::score_percentiles`.
+const PERCENTILES: [u32; 5] = [10, 25, 50, 75, 90];
+
+/// League wide aggregates, computed from all loaded players.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LeagueStats {
+    pub player_count: usize,
+    pub scored_count: usize,
+    pub priced_count: usize,
+    /// Score percentiles keyed by `p10`, `p25`, `p50`, `p75` and `p90`
+    pub score_percentiles: HashMap<String, f64>,
+    /// Median of the last sale prices (EUR)
+    pub price_median: Option<f64>,
+    /// Median of the last sale prices (EUR), by position
+    pub price_median_by_position: HashMap<String, f64>,
+}
+
+/// Read-only view of all loaded players, passed to strategies.
+/// Only league stats are converted to script values: players and ranks are looked up by slug,
+/// so that evaluating every player does not convert every player each time.
+#[derive(Clone, Debug, Default)]
+pub struct StrategyContext {
+    pub league: LeagueStats,
+    players: Arc<HashMap<String, Player>>,
+    ranks: Arc<HashMap<String, usize>>,
+}
+
+impl StrategyContext {
+    pub fn new(players: &[Player]) -> Self {
+        Self {
+            league: LeagueStats::new(players),
+            players: Arc::new(
+                players
+                    .iter()
+                    .map(|p| (p.slug.clone(), p.clone()))
+                    .collect(),
+            ),
+            ranks: Arc::new(ranks(players)),
+        }
+    }
+
+    pub fn player(&self, slug: &str) -> Option<&Player> {
+        self.players.get(slug)
+    }
+
+    /// Player rank by score (only available once all scores are loaded)
+    pub fn rank(&self, slug: &str) -> Option<usize> {
+        self.ranks.get(slug).copied()
+    }
+}
+
+impl LeagueStats {
+    pub fn new(players: &[Player]) -> Self {
+        // Scores
+        let mut scores = players
+            .iter()
+            .filter_map(|p| p.stats.as_ref().map(|s| s.score as f64))
+            .collect::<Vec<f64>>();
+        scores.sort_by(f64::total_cmp);
+        let score_percentiles = PERCENTILES
+            .iter()
+            .filter_map(|p| percentile(&scores, *p as f64).map(|v| (format!("p{}", p), v)))
+            .collect();
+
+        // Last sale prices, globally and by position
+        let mut prices = vec![];
+        let mut prices_by_position: HashMap<String, Vec<f64>> = HashMap::new();
+        for player in players {
+            if let Some(price) = last_price(player) {
+                prices.push(price);
+                for position in &player.positions {
+                    prices_by_position
+                        .entry(position.clone())
+                        .or_default()
+                        .push(price);
+                }
+            }
+        }
+        prices.sort_by(f64::total_cmp);
+        let price_median_by_position = prices_by_position
+            .into_iter()
+            .filter_map(|(position, mut values)| {
+                values.sort_by(f64::total_cmp);
+                percentile(&values, 50.0).map(|m| (position, m))
+            })
+            .collect();
+
+        Self {
+            player_count: players.len(),
+            scored_count: scores.len(),
+            priced_count: prices.len(),
+            score_percentiles,
+            price_median: percentile(&prices, 50.0),
+            price_median_by_position,
+        }
+    }
+}
+
+/// Player ranks by slug: same rules as `Player::rank`, but computed once for all players.
+fn ranks(players: &[Player]) -> HashMap<String, usize> {
+    let mut ranks = HashMap::new();
+    if players.iter().all(|p| p.stats.is_some()) {
+        let mut sorted_players = players.iter().collect::<Vec<&Player>>();
+        sorted_players.sort_by(|a, b| {
+            b.stats
+                .as_ref()
+                .unwrap()
+                .score
+                .cmp(&a.stats.as_ref().unwrap().score)
+        });
+        for (i, p) in sorted_players.iter().enumerate() {
+            ranks.insert(p.slug.clone(), i + 1);
+        }
+    }
+    ranks
+}
+
+/// Last sale price (EUR) of a player, if any.
+fn last_price(player: &Player) -> Option<f64> {
+    player
+        .prices
+        .first()
+        .and_then(|p| p.eur.parse::<f64>().ok())
+}
+
+/// Computes the `p`th percentile (0 - 100) of sorted values, using linear interpolation.
+pub fn percentile(sorted_values: &[f64], p: f64) -> Option<f64> {
+    if sorted_values.is_empty() {
+        return None;
+    }
+
+    let rank = (p.clamp(0.0, 100.0) / 100.0) * (sorted_values.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    let weight = rank - lower as f64;
+
+    Some(sorted_values[lower] + (sorted_values[upper] - sorted_values[lower]) * weight)
+}
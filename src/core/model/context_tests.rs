@@ -0,0 +1,62 @@
+use super::{
+    context::{percentile, LeagueStats, StrategyContext},
+    player::Player,
+    player_builder::PlayerBuilder,
+};
+
+fn create_player(slug: &str, score: Option<i64>, price: Option<&str>, position: &str) -> Player {
+    let mut player = PlayerBuilder::new(slug).position(position);
+    if let Some(score) = score {
+        player = player.score(score);
+    }
+    if let Some(eur) = price {
+        player = player.price("2023-07-22T16:15:13Z", eur, eur);
+    }
+    player.build()
+}
+
+#[test]
+fn percentile_interpolation() {
+    let values = vec![10.0, 20.0, 30.0, 40.0];
+    assert_eq!(percentile(&values, 0.0), Some(10.0));
+    assert_eq!(percentile(&values, 50.0), Some(25.0));
+    assert_eq!(percentile(&values, 100.0), Some(40.0));
+    assert_eq!(percentile(&[], 50.0), None);
+}
+
+#[test]
+fn league_stats() {
+    let players = vec![
+        create_player("a", Some(40), Some("10"), "G"),
+        create_player("b", Some(20), Some("30"), "G"),
+        create_player("c", Some(30), Some("5"), "F"),
+    ];
+
+    let stats = LeagueStats::new(&players);
+    assert_eq!(stats.player_count, 3);
+    assert_eq!(stats.scored_count, 3);
+    assert_eq!(stats.priced_count, 3);
+    assert_eq!(stats.score_percentiles.get("p50"), Some(&30.0));
+    assert_eq!(stats.price_median, Some(10.0));
+    assert_eq!(stats.price_median_by_position.get("G"), Some(&20.0));
+    assert_eq!(stats.price_median_by_position.get("F"), Some(&5.0));
+
+    let context = StrategyContext::new(&players);
+    assert_eq!(context.player("c").unwrap().slug, "c");
+    assert!(context.player("d").is_none());
+    assert_eq!(context.rank("a"), Some(1));
+    assert_eq!(context.rank("b"), Some(3));
+}
+
+#[test]
+fn ranks_require_all_scores() {
+    let players = vec![
+        create_player("a", Some(40), None, "G"),
+        create_player("b", None, None, "G"),
+    ];
+
+    let stats = LeagueStats::new(&players);
+    assert_eq!(stats.scored_count, 1);
+    assert_eq!(stats.price_median, None);
+    assert_eq!(StrategyContext::new(&players).rank("a"), None);
+}
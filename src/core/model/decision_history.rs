@@ -0,0 +1,85 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::decision::Decision;
+
+/// Maximum recorded decisions (oldest are dropped first)
+const MAX_RECORDS: usize = 2000;
+
+/// A decision, recorded across sessions.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DecisionRecord {
+    pub action: String,
+    pub player_slug: String,
+    pub player_name: String,
+    pub strategy: String,
+    /// Last comment
+    pub comment: String,
+    /// RFC 3339 date of the first evaluation returning the decision
+    pub first_seen: String,
+    /// RFC 3339 date of the last evaluation returning the decision
+    pub last_seen: String,
+    /// Player last sale price (EUR) when the decision was first seen
+    pub price: Option<f64>,
+}
+
+impl DecisionRecord {
+    /// Price change ratio since the decision was first seen.
+    pub fn price_change(&self, current_price: Option<f64>) -> Option<f64> {
+        match (self.price, current_price) {
+            (Some(price), Some(current)) if price > 0.0 => Some((current - price) / price),
+            _ => None,
+        }
+    }
+
+    /// Checks if the price moved in the direction of the decision (up for `Buy`, down for `Sell`).
+    pub fn is_successful(&self, current_price: Option<f64>) -> Option<bool> {
+        let change = self.price_change(current_price)?;
+        match self.action.as_str() {
+            "Buy" => Some(change > 0.0),
+            "Sell" => Some(change < 0.0),
+            _ => None,
+        }
+    }
+}
+
+/// Recorded decisions, most recent first.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DecisionHistory {
+    pub records: Vec<DecisionRecord>,
+}
+
+impl DecisionHistory {
+    /// Merges the decisions of a player evaluation.
+    /// A decision already returned by the last evaluation of the strategy (same action) is only
+    /// marked as seen again, other decisions are recorded with the player price.
+    pub fn merge(&mut self, decisions: &[Decision], price: Option<f64>, date: &DateTime<Utc>) {
+        let date = date.to_rfc3339();
+        for decision in decisions {
+            let last = self
+                .records
+                .iter_mut()
+                .find(|r| r.player_slug == decision.player_slug && r.strategy == decision.strategy);
+            match last {
+                Some(record) if record.action == decision.action => {
+                    record.last_seen = date.clone();
+                    record.comment = decision.comment.clone();
+                }
+                _ => self.records.insert(
+                    0,
+                    DecisionRecord {
+                        action: decision.action.clone(),
+                        player_slug: decision.player_slug.clone(),
+                        player_name: decision.player_name.clone(),
+                        strategy: decision.strategy.clone(),
+                        comment: decision.comment.clone(),
+                        first_seen: date.clone(),
+                        last_seen: date.clone(),
+                        price,
+                    },
+                ),
+            }
+        }
+        self.records.truncate(MAX_RECORDS);
+    }
+}
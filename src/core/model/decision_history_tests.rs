@@ -0,0 +1,68 @@
+use chrono::{TimeZone, Utc};
+
+use super::{decision::Decision, decision_history::DecisionHistory};
+
+fn create_decision(strategy: &str, action: &str, comment: &str) -> Decision {
+    Decision {
+        action: action.to_string(),
+        player_slug: "slug".to_string(),
+        player_name: "name".to_string(),
+        strategy: strategy.to_string(),
+        comment: comment.to_string(),
+    }
+}
+
+#[test]
+fn merge_dedupes_decisions() {
+    let mut history = DecisionHistory::default();
+    let day1 = Utc.with_ymd_and_hms(2023, 7, 1, 10, 0, 0).unwrap();
+    let day2 = Utc.with_ymd_and_hms(2023, 7, 2, 10, 0, 0).unwrap();
+    let day3 = Utc.with_ymd_and_hms(2023, 7, 3, 10, 0, 0).unwrap();
+
+    history.merge(
+        &[create_decision("a", "Buy", "score 40")],
+        Some(10.0),
+        &day1,
+    );
+    history.merge(
+        &[
+            create_decision("a", "Buy", "score 42"),
+            create_decision("b", "Buy", ""),
+        ],
+        Some(12.0),
+        &day2,
+    );
+    assert_eq!(history.records.len(), 2);
+    let record = history.records.iter().find(|r| r.strategy == "a").unwrap();
+    assert_eq!(record.first_seen, day1.to_rfc3339());
+    assert_eq!(record.last_seen, day2.to_rfc3339());
+    assert_eq!(record.price, Some(10.0));
+    assert_eq!(record.comment, "score 42");
+
+    // New action: new record, most recent first
+    history.merge(&[create_decision("a", "Sell", "")], Some(15.0), &day3);
+    assert_eq!(history.records.len(), 3);
+    assert_eq!(history.records[0].action, "Sell");
+    assert_eq!(history.records[0].price, Some(15.0));
+}
+
+#[test]
+fn performance() {
+    let mut history = DecisionHistory::default();
+    let date = Utc.with_ymd_and_hms(2023, 7, 1, 10, 0, 0).unwrap();
+    history.merge(
+        &[
+            create_decision("a", "Buy", ""),
+            create_decision("b", "Sell", ""),
+        ],
+        Some(10.0),
+        &date,
+    );
+
+    let sell = &history.records[0];
+    let buy = &history.records[1];
+    assert_eq!(buy.price_change(Some(15.0)), Some(0.5));
+    assert_eq!(buy.is_successful(Some(15.0)), Some(true));
+    assert_eq!(sell.is_successful(Some(15.0)), Some(false));
+    assert_eq!(buy.price_change(None), None);
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use super::{decision::Decision, player::Player, requirement::Requirements};
+
+/// Progress of a strategies evaluation over a player list.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvaluationProgress {
+    pub player_count: usize,
+    pub evaluated_count: usize,
+    /// Players skipped because of missing data (for every strategy)
+    pub skipped_count: usize,
+}
+
+impl EvaluationProgress {
+    /// Starts an evaluation: players missing data required by every strategy are skipped upfront.
+    pub fn new(players: &[Player], requirements: &[Requirements]) -> Self {
+        Self {
+            player_count: players.len(),
+            evaluated_count: 0,
+            skipped_count: players
+                .iter()
+                .filter(|p| !p.is_evaluable(requirements))
+                .count(),
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.evaluated_count + self.skipped_count >= self.player_count
+    }
+
+    /// Completion ratio (between 0 and 1).
+    pub fn ratio(&self) -> f64 {
+        if self.player_count == 0 {
+            1.0
+        } else {
+            (self.evaluated_count + self.skipped_count) as f64 / self.player_count as f64
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvaluationReport {
+    pub progress: EvaluationProgress,
+    pub decisions: Vec<Decision>,
+    /// Evaluation errors, by player
+    pub errors: Vec<String>,
+}
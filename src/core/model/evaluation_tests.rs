@@ -0,0 +1,39 @@
+use super::{
+    evaluation::EvaluationProgress,
+    player::Player,
+    player_builder::PlayerBuilder,
+    requirement::{default_requirements, DataRequirement, Requirements},
+};
+
+fn create_player(slug: &str, with_prices: bool) -> Player {
+    let player = PlayerBuilder::new(slug).score(40);
+    if with_prices {
+        player.price("2023-07-22T16:15:13Z", "40", "50").build()
+    } else {
+        player.build()
+    }
+}
+
+#[test]
+fn progress() {
+    let players = vec![
+        create_player("a", true),
+        create_player("b", false),
+        create_player("c", true),
+    ];
+
+    let mut progress = EvaluationProgress::new(&players, &[default_requirements()]);
+    assert_eq!(progress.skipped_count, 1);
+    assert!(!progress.is_done());
+
+    progress.evaluated_count = 2;
+    assert!(progress.is_done());
+    assert_eq!(progress.ratio(), 1.0);
+
+    assert!(EvaluationProgress::new(&[], &[default_requirements()]).is_done());
+
+    // Players are evaluated if at least one strategy can run
+    let stats_only = Requirements::from([DataRequirement::Stats]);
+    let progress = EvaluationProgress::new(&players, &[default_requirements(), stats_only]);
+    assert_eq!(progress.skipped_count, 0);
+}
@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+
+use super::{decision::Decision, params::ParamValues, player::Player};
+
+/// Expected decision of a fixture case.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExpectedDecision {
+    pub action: String,
+    /// Checked only if set
+    pub comment: Option<String>,
+}
+
+/// A strategy test case, loaded from a `<name>.test.json` file next to the script.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FixtureCase {
+    pub name: String,
+    pub player: Player,
+    /// Context players (defaults to the tested player only)
+    pub players: Option<Vec<Player>>,
+    /// Parameters overrides
+    #[serde(default)]
+    pub params: ParamValues,
+    /// Expected decision (`null` if no decision is expected)
+    pub expected: Option<ExpectedDecision>,
+}
+
+impl FixtureCase {
+    /// Checks an actual decision against the expected one.
+    pub fn check(&self, actual: Option<&Decision>) -> Result<(), String> {
+        match (&self.expected, actual) {
+            (None, None) => Ok(()),
+            (None, Some(d)) => Err(format!("expected no decision, got {}", d.action)),
+            (Some(e), None) => Err(format!("expected {}, got no decision", e.action)),
+            (Some(e), Some(d)) => {
+                if e.action != d.action {
+                    Err(format!("expected {}, got {}", e.action, d.action))
+                } else if e.comment.as_ref().map_or(false, |c| c != &d.comment) {
+                    Err(format!(
+                        "expected comment `{}`, got `{}`",
+                        e.comment.as_ref().unwrap(),
+                        d.comment
+                    ))
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixtureResult {
+    pub strategy: String,
+    pub case: String,
+    /// Failure reason (`None` if passed)
+    pub failure: Option<String>,
+}
+
+impl FixtureResult {
+    pub fn passed(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixtureReport {
+    pub results: Vec<FixtureResult>,
+}
+
+impl FixtureReport {
+    pub fn passed_count(&self) -> usize {
+        self.results.iter().filter(|r| r.passed()).count()
+    }
+
+    pub fn failed_count(&self) -> usize {
+        self.results.len() - self.passed_count()
+    }
+}
@@ -0,0 +1,53 @@
+use serde_json::{from_value, json};
+
+use super::{decision::Decision, fixture::FixtureCase};
+
+fn create_case(expected: serde_json::Value) -> FixtureCase {
+    from_value(json!({
+        "name": "case",
+        "player": {
+            "slug": "slug",
+            "display_name": "name",
+            "birth_date": "2003-07-22T17:15:13Z",
+            "prices": [],
+            "positions": [],
+            "country": "US",
+            "number": 23
+        },
+        "expected": expected
+    }))
+    .unwrap()
+}
+
+fn create_decision(action: &str, comment: &str) -> Decision {
+    Decision {
+        action: action.to_string(),
+        player_slug: "slug".to_string(),
+        player_name: "name".to_string(),
+        strategy: "strategy".to_string(),
+        comment: comment.to_string(),
+    }
+}
+
+#[test]
+fn check_no_decision() {
+    let case = create_case(json!(null));
+    assert!(case.check(None).is_ok());
+    assert!(case.check(Some(&create_decision("Buy", ""))).is_err());
+}
+
+#[test]
+fn check_decision() {
+    let case = create_case(json!({ "action": "Buy" }));
+    assert!(case.check(Some(&create_decision("Buy", "any"))).is_ok());
+    assert!(case.check(Some(&create_decision("Sell", "any"))).is_err());
+    assert!(case.check(None).is_err());
+
+    let case = create_case(json!({ "action": "Buy", "comment": "score: 40" }));
+    assert!(case
+        .check(Some(&create_decision("Buy", "score: 40")))
+        .is_ok());
+    assert!(case
+        .check(Some(&create_decision("Buy", "score: 30")))
+        .is_err());
+}
@@ -0,0 +1,119 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    player::Player,
+    price::Price,
+    stats::{Game, Stats},
+};
+
+/// Number of prices in a player snapshot (same as the API)
+const SNAPSHOT_PRICES_COUNT: usize = 5;
+/// Number of games in a player snapshot (same as the API)
+const SNAPSHOT_GAMES_COUNT: usize = 10;
+
+/// Recorded prices and games of a player, most recent first.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerHistory {
+    pub player_slug: String,
+    pub prices: Vec<Price>,
+    pub games: Vec<Game>,
+}
+
+impl PlayerHistory {
+    pub fn new(player_slug: &str) -> Self {
+        Self {
+            player_slug: player_slug.to_string(),
+            prices: vec![],
+            games: vec![],
+        }
+    }
+
+    /// Adds prices not recorded yet. Returns true if history changed.
+    pub fn merge_prices(&mut self, prices: &[Price]) -> bool {
+        let count = self.prices.len();
+        for price in prices {
+            if !self.prices.iter().any(|p| p.date == price.date) {
+                self.prices.push(price.clone());
+            }
+        }
+        self.prices
+            .sort_by(|a, b| parse_date(&b.date).cmp(&parse_date(&a.date)));
+        count != self.prices.len()
+    }
+
+    /// Adds games not recorded yet. Returns true if history changed.
+    pub fn merge_games(&mut self, games: &[Game]) -> bool {
+        let count = self.games.len();
+        for game in games {
+            if !self.games.iter().any(|g| g.date == game.date) {
+                self.games.push(game.clone());
+            }
+        }
+        self.games
+            .sort_by(|a, b| parse_date(&b.date).cmp(&parse_date(&a.date)));
+        count != self.games.len()
+    }
+
+    /// Dates (prices and games) covered by the history.
+    pub fn dates(&self) -> Vec<DateTime<Utc>> {
+        self.prices
+            .iter()
+            .map(|p| &p.date)
+            .chain(self.games.iter().map(|g| &g.date))
+            .filter_map(|d| parse_date(d))
+            .collect()
+    }
+
+    /// Returns the player as it looked at `date`: last prices and games before that date.
+    /// The score is approximated as the average score of the played games.
+    pub fn snapshot(&self, player: &Player, date: &DateTime<Utc>) -> Player {
+        let prices = self
+            .prices
+            .iter()
+            .filter(|p| parse_date(&p.date).map_or(false, |d| d <= *date))
+            .take(SNAPSHOT_PRICES_COUNT)
+            .cloned()
+            .collect::<Vec<Price>>();
+
+        let games = self
+            .games
+            .iter()
+            .filter(|g| parse_date(&g.date).map_or(false, |d| d <= *date))
+            .take(SNAPSHOT_GAMES_COUNT)
+            .cloned()
+            .collect::<Vec<Game>>();
+        let played_scores = games
+            .iter()
+            .filter(|g| g.did_play)
+            .map(|g| g.score as f64)
+            .collect::<Vec<f64>>();
+
+        let mut snapshot = player.clone();
+        snapshot.prices = prices;
+        snapshot.stats = if games.is_empty() {
+            None
+        } else {
+            Some(Stats {
+                player_slug: player.slug.clone(),
+                score: if played_scores.is_empty() {
+                    0
+                } else {
+                    (played_scores.iter().sum::<f64>() / played_scores.len() as f64).round() as i64
+                },
+                games,
+            })
+        };
+        // Injuries are not recorded
+        snapshot.injury = None;
+        snapshot.injury_loaded = false;
+
+        snapshot
+    }
+}
+
+pub fn parse_date(date: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(date)
+        .ok()
+        .map(|d| d.with_timezone(&Utc))
+}
@@ -0,0 +1,77 @@
+use chrono::{TimeZone, Utc};
+
+use super::{
+    history::PlayerHistory, player::Player, player_builder::PlayerBuilder, price::Price,
+    stats::Game,
+};
+
+fn create_player() -> Player {
+    PlayerBuilder::new("slug").build()
+}
+
+fn create_price(date: &str, eur: &str) -> Price {
+    Price {
+        player_slug: "slug".to_string(),
+        date: date.to_string(),
+        eur: eur.to_string(),
+        usd: eur.to_string(),
+    }
+}
+
+fn create_game(date: &str, score: u64, did_play: bool) -> Game {
+    Game {
+        date: date.to_string(),
+        did_play,
+        score,
+        minutes_played: 20,
+    }
+}
+
+#[test]
+fn merge_dedupes_and_sorts() {
+    let mut history = PlayerHistory::new("slug");
+    assert!(history.merge_prices(&[
+        create_price("2023-07-01T10:00:00Z", "10"),
+        create_price("2023-07-03T10:00:00Z", "30"),
+    ]));
+    assert!(history.merge_prices(&[
+        create_price("2023-07-03T10:00:00Z", "30"),
+        create_price("2023-07-02T10:00:00Z", "20"),
+    ]));
+    assert!(!history.merge_prices(&[create_price("2023-07-02T10:00:00Z", "20")]));
+
+    let prices = history
+        .prices
+        .iter()
+        .map(|p| p.eur.as_str())
+        .collect::<Vec<&str>>();
+    assert_eq!(prices, vec!["30", "20", "10"]);
+}
+
+#[test]
+fn snapshot() {
+    let mut history = PlayerHistory::new("slug");
+    history.merge_prices(&[
+        create_price("2023-07-01T10:00:00Z", "10"),
+        create_price("2023-07-03T10:00:00Z", "30"),
+    ]);
+    history.merge_games(&[
+        create_game("2023-07-01T02:00:00Z", 30, true),
+        create_game("2023-07-02T02:00:00Z", 0, false),
+        create_game("2023-07-02T03:00:00Z", 40, true),
+        create_game("2023-07-04T02:00:00Z", 50, true),
+    ]);
+
+    let date = Utc.with_ymd_and_hms(2023, 7, 2, 23, 59, 59).unwrap();
+    let snapshot = history.snapshot(&create_player(), &date);
+    assert_eq!(snapshot.prices.len(), 1);
+    assert_eq!(snapshot.prices[0].eur, "10");
+    let stats = snapshot.stats.unwrap();
+    assert_eq!(stats.games.len(), 3);
+    assert_eq!(stats.score, 35);
+
+    let date = Utc.with_ymd_and_hms(2023, 6, 30, 0, 0, 0).unwrap();
+    let snapshot = history.snapshot(&create_player(), &date);
+    assert!(snapshot.prices.is_empty());
+    assert!(snapshot.stats.is_none());
+}
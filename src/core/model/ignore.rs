@@ -0,0 +1,72 @@
+use regex::Regex;
+
+/// Ignore file of the strategy directory
+pub const IGNORE_FILE: &str = ".socliignore";
+
+/// Files excluded from strategy discovery, read from a `.socliignore` file: one glob pattern
+/// per line (`*`, `?` and `**`), `#` for comments.
+///
+/// Patterns without `/` match file or folder names at any depth (`drafts`, `*.wip.js`),
+/// other patterns match paths relative to the strategy directory (`momentum/old/*`).
+/// A trailing `/` matches folders only.
+#[derive(Debug, Default)]
+pub struct IgnoreRules {
+    patterns: Vec<IgnorePattern>,
+}
+
+#[derive(Debug)]
+struct IgnorePattern {
+    regex: Regex,
+    /// Matched against the whole relative path (instead of the name)
+    anchored: bool,
+    dir_only: bool,
+}
+
+impl IgnoreRules {
+    pub fn parse(content: &str) -> Self {
+        let patterns = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let dir_only = line.ends_with('/');
+                let pattern = line.trim_end_matches('/');
+                let anchored = pattern.contains('/');
+                let regex = Regex::new(&glob_to_regex(pattern.trim_start_matches('/'))).ok()?;
+                Some(IgnorePattern {
+                    regex,
+                    anchored,
+                    dir_only,
+                })
+            })
+            .collect();
+
+        Self { patterns }
+    }
+
+    /// Checks if a path (relative to the strategy directory, with `/` separators) is ignored.
+    pub fn is_ignored(&self, path: &str, is_dir: bool) -> bool {
+        let name = path.rsplit('/').next().unwrap_or(path);
+        self.patterns.iter().any(|p| {
+            (is_dir || !p.dir_only) && p.regex.is_match(if p.anchored { path } else { name })
+        })
+    }
+}
+
+pub(crate) fn glob_to_regex(glob: &str) -> String {
+    let mut regex = String::from("^");
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                regex.push_str(".*");
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push('$');
+    regex
+}
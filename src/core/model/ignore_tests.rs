@@ -0,0 +1,34 @@
+use super::ignore::IgnoreRules;
+
+#[test]
+fn ignore_patterns() {
+    let rules = IgnoreRules::parse(
+        r#"
+        # Work in progress
+        *.wip.js
+        drafts/
+        momentum/old/**
+        "#,
+    );
+
+    assert!(rules.is_ignored("test.wip.js", false));
+    assert!(rules.is_ignored("momentum/test.wip.js", false));
+    assert!(!rules.is_ignored("test.js", false));
+
+    // Folders only
+    assert!(rules.is_ignored("drafts", true));
+    assert!(rules.is_ignored("momentum/drafts", true));
+    assert!(!rules.is_ignored("drafts", false));
+
+    // Anchored to the strategy directory
+    assert!(rules.is_ignored("momentum/old/v1.js", false));
+    assert!(rules.is_ignored("momentum/old/2023/v1.js", false));
+    assert!(!rules.is_ignored("other/momentum/old/v1.js", false));
+    assert!(!rules.is_ignored("momentum/v2.js", false));
+}
+
+#[test]
+fn empty_rules() {
+    let rules = IgnoreRules::parse("\n# Nothing\n");
+    assert!(!rules.is_ignored("test.js", false));
+}
@@ -4,6 +4,47 @@ pub mod currency;
 pub mod stats;
 pub mod decision;
 pub mod injury;
+pub mod context;
+pub mod params;
+pub mod history;
+pub mod backtest;
+pub mod fixture;
+pub mod rules;
+pub mod ignore;
+pub mod strategy;
+pub mod evaluation;
+pub mod trace;
+pub mod requirement;
+pub mod decision_history;
+pub mod notification;
+pub mod config;
+pub mod schedule;
 
 #[cfg(test)]
-pub mod player_tests;
\ No newline at end of file
+pub mod player_tests;
+#[cfg(test)]
+pub mod player_builder;
+#[cfg(test)]
+pub mod context_tests;
+#[cfg(test)]
+pub mod params_tests;
+#[cfg(test)]
+pub mod history_tests;
+#[cfg(test)]
+pub mod backtest_tests;
+#[cfg(test)]
+pub mod fixture_tests;
+#[cfg(test)]
+pub mod ignore_tests;
+#[cfg(test)]
+pub mod evaluation_tests;
+#[cfg(test)]
+pub mod requirement_tests;
+#[cfg(test)]
+pub mod decision_history_tests;
+#[cfg(test)]
+pub mod notification_tests;
+#[cfg(test)]
+pub mod config_tests;
+#[cfg(test)]
+pub mod schedule_tests;
\ No newline at end of file
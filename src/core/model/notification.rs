@@ -0,0 +1,215 @@
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Duration, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::{decision::Decision, ignore::glob_to_regex};
+
+/// Notification settings, read from `~/.socli/notifications.toml`.
+/// Kept apart from `config.toml`: they hold credentials, and lists that cannot be set
+/// by environment variables or `--set` options.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotificationConfig {
+    #[serde(default)]
+    pub throttle: ThrottleConfig,
+    #[serde(default)]
+    pub sinks: Vec<SinkConfig>,
+    /// Routing rules (without rules, decisions are sent to every sink)
+    #[serde(default)]
+    pub routes: Vec<RouteConfig>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThrottleConfig {
+    /// Minimum delay before notifying the same decision again, on the same sink (seconds)
+    #[serde(default = "default_cooldown_secs")]
+    pub cooldown_secs: u64,
+    /// Maximum notifications sent by a sink within an hour
+    #[serde(default = "default_max_per_hour")]
+    pub max_per_hour: usize,
+}
+
+fn default_cooldown_secs() -> u64 {
+    3600
+}
+
+fn default_max_per_hour() -> usize {
+    20
+}
+
+impl Default for ThrottleConfig {
+    fn default() -> Self {
+        Self {
+            cooldown_secs: default_cooldown_secs(),
+            max_per_hour: default_max_per_hour(),
+        }
+    }
+}
+
+/// A notification destination, referenced by name in routes.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SinkConfig {
+    pub name: String,
+    #[serde(flatten)]
+    pub kind: SinkKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum SinkKind {
+    /// Desktop notification
+    Desktop,
+    /// HTTP POST of the decision as JSON
+    Webhook {
+        url: String,
+        #[serde(default)]
+        headers: BTreeMap<String, String>,
+    },
+    /// Email
+    Smtp {
+        host: String,
+        port: Option<u16>,
+        username: Option<String>,
+        password: Option<String>,
+        from: String,
+        to: Vec<String>,
+    },
+}
+
+/// Sends decisions matching strategies and actions to sinks.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RouteConfig {
+    /// Strategy name patterns (such as `momentum/*`), any strategy if empty
+    #[serde(default)]
+    pub strategies: Vec<String>,
+    /// Decision actions (such as `Buy`), any action if empty
+    #[serde(default)]
+    pub actions: Vec<String>,
+    pub sinks: Vec<String>,
+}
+
+impl NotificationConfig {
+    pub fn parse(content: &str) -> Result<Self, String> {
+        toml::from_str(content).map_err(|e| e.to_string())
+    }
+}
+
+/// Routes decisions to sinks, with strategy patterns compiled once.
+#[derive(Debug, Default)]
+pub struct Router {
+    /// Routes, with their compiled strategy patterns
+    routes: Vec<(Vec<Regex>, RouteConfig)>,
+    /// Every sink (decisions are sent to every sink without routes)
+    sinks: Vec<String>,
+}
+
+impl Router {
+    pub fn new(config: &NotificationConfig) -> Result<Self, String> {
+        let routes = config
+            .routes
+            .iter()
+            .map(|route| {
+                let patterns = route
+                    .strategies
+                    .iter()
+                    .map(|pattern| {
+                        Regex::new(&glob_to_regex(pattern))
+                            .map_err(|e| format!("invalid strategy pattern `{}`: {}", pattern, e))
+                    })
+                    .collect::<Result<Vec<Regex>, String>>()?;
+                Ok((patterns, route.clone()))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(Self {
+            routes,
+            sinks: config.sinks.iter().map(|s| s.name.clone()).collect(),
+        })
+    }
+
+    /// Names of the sinks a decision is sent to.
+    pub fn sinks_for(&self, decision: &Decision) -> Vec<String> {
+        if self.routes.is_empty() {
+            return self.sinks.clone();
+        }
+
+        let mut sinks: Vec<String> = vec![];
+        for (_, route) in self
+            .routes
+            .iter()
+            .filter(|(patterns, route)| Self::matches(patterns, route, decision))
+        {
+            for sink in &route.sinks {
+                if !sinks.contains(sink) {
+                    sinks.push(sink.clone());
+                }
+            }
+        }
+        sinks
+    }
+
+    fn matches(patterns: &[Regex], route: &RouteConfig, decision: &Decision) -> bool {
+        let strategy_matches =
+            patterns.is_empty() || patterns.iter().any(|r| r.is_match(&decision.strategy));
+        let action_matches = route.actions.is_empty()
+            || route
+                .actions
+                .iter()
+                .any(|a| a.eq_ignore_ascii_case(&decision.action));
+        strategy_matches && action_matches
+    }
+}
+
+/// Limits notifications: same decision within the cooldown, and sink hourly rate.
+#[derive(Debug, Default)]
+pub struct Throttle {
+    config: ThrottleConfig,
+    /// Last notification date, by sink and decision
+    last_sent: HashMap<String, DateTime<Utc>>,
+    /// Notification dates within the last hour, by sink
+    sent: HashMap<String, Vec<DateTime<Utc>>>,
+}
+
+impl Throttle {
+    pub fn new(config: ThrottleConfig) -> Self {
+        Self {
+            config,
+            ..Default::default()
+        }
+    }
+
+    /// Checks if a decision can be sent to a sink (see `record` once sent).
+    pub fn allow(&self, sink: &str, decision: &Decision, now: DateTime<Utc>) -> bool {
+        let cooldown = Duration::seconds(self.config.cooldown_secs as i64);
+        if self
+            .last_sent
+            .get(&Self::key(sink, decision))
+            .map_or(false, |d| now - *d < cooldown)
+        {
+            return false;
+        }
+
+        let sent_count = self.sent.get(sink).map_or(0, |sent| {
+            sent.iter()
+                .filter(|d| now - **d < Duration::hours(1))
+                .count()
+        });
+        sent_count < self.config.max_per_hour
+    }
+
+    /// Records a decision sent to a sink (failed notifications are not throttled).
+    pub fn record(&mut self, sink: &str, decision: &Decision, now: DateTime<Utc>) {
+        let sent = self.sent.entry(sink.to_string()).or_default();
+        sent.retain(|d| now - *d < Duration::hours(1));
+        sent.push(now);
+        self.last_sent.insert(Self::key(sink, decision), now);
+    }
+
+    fn key(sink: &str, decision: &Decision) -> String {
+        format!(
+            "{}|{}|{}|{}",
+            sink, decision.player_slug, decision.strategy, decision.action
+        )
+    }
+}
@@ -0,0 +1,131 @@
+use chrono::{Duration, TimeZone, Utc};
+
+use super::{
+    decision::Decision,
+    notification::{NotificationConfig, Router, SinkKind, Throttle, ThrottleConfig},
+};
+
+fn create_decision(strategy: &str, action: &str) -> Decision {
+    Decision {
+        action: action.to_string(),
+        player_slug: "slug".to_string(),
+        player_name: "name".to_string(),
+        strategy: strategy.to_string(),
+        comment: "".to_string(),
+    }
+}
+
+#[test]
+fn parse_config() {
+    let config = NotificationConfig::parse(
+        r#"
+        [throttle]
+        cooldown_secs = 60
+
+        [[sinks]]
+        name = "desktop"
+        type = "desktop"
+
+        [[sinks]]
+        name = "hook"
+        type = "webhook"
+        url = "http://localhost:8080/hook"
+        headers = { Authorization = "Bearer token" }
+
+        [[routes]]
+        actions = ["Buy"]
+        sinks = ["desktop", "hook"]
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(config.throttle.cooldown_secs, 60);
+    assert_eq!(config.throttle.max_per_hour, 20);
+    assert_eq!(config.sinks[0].kind, SinkKind::Desktop);
+    assert!(matches!(
+        &config.sinks[1].kind,
+        SinkKind::Webhook { url, headers } if url == "http://localhost:8080/hook" && headers.len() == 1
+    ));
+    assert!(NotificationConfig::parse("[[sinks]]\nname = \"x\"\ntype = \"pigeon\"").is_err());
+}
+
+#[test]
+fn routing() {
+    let config = NotificationConfig::parse(
+        r#"
+        [[sinks]]
+        name = "desktop"
+        type = "desktop"
+
+        [[sinks]]
+        name = "hook"
+        type = "webhook"
+        url = "http://localhost:8080/hook"
+
+        [[routes]]
+        actions = ["Buy"]
+        sinks = ["desktop"]
+
+        [[routes]]
+        strategies = ["momentum/*"]
+        sinks = ["hook", "desktop"]
+        "#,
+    )
+    .unwrap();
+    let router = Router::new(&config).unwrap();
+
+    assert_eq!(
+        router.sinks_for(&create_decision("momentum/v2", "Buy")),
+        vec!["desktop", "hook"]
+    );
+    assert_eq!(
+        router.sinks_for(&create_decision("momentum/v2", "Sell")),
+        vec!["hook", "desktop"]
+    );
+    assert_eq!(
+        router.sinks_for(&create_decision("young", "buy")),
+        vec!["desktop"]
+    );
+    assert!(router
+        .sinks_for(&create_decision("young", "Sell"))
+        .is_empty());
+
+    // Without routes: every sink
+    let config =
+        NotificationConfig::parse("[[sinks]]\nname = \"desktop\"\ntype = \"desktop\"").unwrap();
+    assert_eq!(
+        Router::new(&config)
+            .unwrap()
+            .sinks_for(&create_decision("young", "Sell")),
+        vec!["desktop"]
+    );
+}
+
+#[test]
+fn throttling() {
+    let mut throttle = Throttle::new(ThrottleConfig {
+        cooldown_secs: 600,
+        max_per_hour: 2,
+    });
+    let now = Utc.with_ymd_and_hms(2023, 7, 1, 10, 0, 0).unwrap();
+    let buy = create_decision("a", "Buy");
+
+    assert!(throttle.allow("desktop", &buy, now));
+    // Not sent (failed): not throttled
+    assert!(throttle.allow("desktop", &buy, now + Duration::minutes(1)));
+    throttle.record("desktop", &buy, now + Duration::minutes(1));
+    // Same decision within cooldown
+    assert!(!throttle.allow("desktop", &buy, now + Duration::minutes(5)));
+    // Other sink
+    assert!(throttle.allow("hook", &buy, now));
+    // Hourly limit
+    let other = create_decision("b", "Buy");
+    assert!(throttle.allow("desktop", &other, now));
+    throttle.record("desktop", &other, now);
+    assert!(!throttle.allow("desktop", &create_decision("c", "Buy"), now));
+    assert!(throttle.allow(
+        "desktop",
+        &create_decision("c", "Buy"),
+        now + Duration::hours(1)
+    ));
+}
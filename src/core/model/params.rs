@@ -0,0 +1,108 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Type of a strategy parameter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ParamType {
+    Number,
+    String,
+    Boolean,
+}
+
+impl ParamType {
+    pub fn accepts(&self, value: &Value) -> bool {
+        match self {
+            Self::Number => value.is_number(),
+            Self::String => value.is_string(),
+            Self::Boolean => value.is_boolean(),
+        }
+    }
+}
+
+impl Display for ParamType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number => write!(f, "number"),
+            Self::String => write!(f, "string"),
+            Self::Boolean => write!(f, "boolean"),
+        }
+    }
+}
+
+/// Parameter declared by a strategy, through its exported `params` object.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ParamSpec {
+    #[serde(rename = "type")]
+    pub param_type: ParamType,
+    pub default: Value,
+}
+
+/// Parameters declared by a strategy, by name.
+pub type ParamSpecs = BTreeMap<String, ParamSpec>;
+
+/// Parameter values, by name.
+pub type ParamValues = Map<String, Value>;
+
+/// Resolves parameter values: declared defaults, overridden by `overrides`, then by `saved` values.
+/// Saved values of parameters not declared anymore (or of another type) are ignored.
+pub fn resolve_params(
+    specs: &ParamSpecs,
+    overrides: &ParamValues,
+    saved: &ParamValues,
+) -> Result<ParamValues, String> {
+    let mut values = ParamValues::new();
+
+    for (name, spec) in specs {
+        if !spec.param_type.accepts(&spec.default) {
+            return Err(format!(
+                "default value of parameter `{}` is not a {}",
+                name, spec.param_type
+            ));
+        }
+        values.insert(name.clone(), spec.default.clone());
+    }
+
+    for (name, value) in overrides {
+        match specs.get(name) {
+            Some(spec) => {
+                if !spec.param_type.accepts(value) {
+                    return Err(format!(
+                        "value of parameter `{}` is not a {}",
+                        name, spec.param_type
+                    ));
+                }
+                values.insert(name.clone(), value.clone());
+            }
+            None => return Err(format!("unknown parameter `{}`", name)),
+        }
+    }
+
+    for (name, value) in saved {
+        if specs
+            .get(name)
+            .map_or(false, |spec| spec.param_type.accepts(value))
+        {
+            values.insert(name.clone(), value.clone());
+        }
+    }
+
+    Ok(values)
+}
+
+/// Number parameters are changed by steps (from the UI): 1 for integers, 0.1 otherwise.
+/// Returns `None` if not a number.
+pub fn step_param(value: &Value, steps: i64) -> Option<Value> {
+    match value {
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => Some(Value::from(i + steps)),
+            // Rounded, to avoid accumulating float errors
+            None => n
+                .as_f64()
+                .map(|f| Value::from(((f + steps as f64 * 0.1) * 1e6).round() / 1e6)),
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,56 @@
+use serde_json::{from_value, json};
+
+use super::params::{resolve_params, step_param, ParamSpecs, ParamValues};
+
+fn create_specs() -> ParamSpecs {
+    from_value(json!({
+        "min_score": { "type": "number", "default": 35 },
+        "team": { "type": "string", "default": "" },
+    }))
+    .unwrap()
+}
+
+#[test]
+fn resolve_defaults() {
+    let values = resolve_params(&create_specs(), &ParamValues::new(), &ParamValues::new()).unwrap();
+    assert_eq!(values.get("min_score"), Some(&json!(35)));
+    assert_eq!(values.get("team"), Some(&json!("")));
+}
+
+#[test]
+fn resolve_overrides() {
+    let overrides: ParamValues = from_value(json!({ "min_score": 40 })).unwrap();
+    let values = resolve_params(&create_specs(), &overrides, &ParamValues::new()).unwrap();
+    assert_eq!(values.get("min_score"), Some(&json!(40)));
+    assert_eq!(values.get("team"), Some(&json!("")));
+}
+
+#[test]
+fn resolve_invalid_overrides() {
+    let overrides: ParamValues = from_value(json!({ "min_score": "40" })).unwrap();
+    assert!(resolve_params(&create_specs(), &overrides, &ParamValues::new()).is_err());
+
+    let overrides: ParamValues = from_value(json!({ "max_age": 25 })).unwrap();
+    assert!(resolve_params(&create_specs(), &overrides, &ParamValues::new()).is_err());
+}
+
+#[test]
+fn resolve_saved_values() {
+    let overrides: ParamValues = from_value(json!({ "min_score": 40, "team": "lakers" })).unwrap();
+    // Removed parameter, and parameter of another type: ignored
+    let saved: ParamValues =
+        from_value(json!({ "min_score": 45, "team": 2, "max_age": 25 })).unwrap();
+    let values = resolve_params(&create_specs(), &overrides, &saved).unwrap();
+    assert_eq!(values.get("min_score"), Some(&json!(45)));
+    assert_eq!(values.get("team"), Some(&json!("lakers")));
+    assert_eq!(values.get("max_age"), None);
+}
+
+#[test]
+fn step_number_params() {
+    assert_eq!(step_param(&json!(35), 1), Some(json!(36)));
+    assert_eq!(step_param(&json!(35), -2), Some(json!(33)));
+    assert_eq!(step_param(&json!(0.2), 1), Some(json!(0.3)));
+    assert_eq!(step_param(&json!(0.2), -3), Some(json!(-0.1)));
+    assert_eq!(step_param(&json!("team"), 1), None);
+}
@@ -2,7 +2,13 @@ use chrono::{DateTime, FixedOffset, Utc};
 use rquickjs::IntoJs;
 use serde::{Deserialize, Serialize};
 
-use super::{currency::Currency, injury::Injury, price::Price, stats::Stats};
+use super::{
+    currency::Currency,
+    injury::Injury,
+    price::Price,
+    requirement::{DataRequirement, Requirements},
+    stats::Stats,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, IntoJs)]
 pub struct Player {
@@ -13,12 +19,39 @@ pub struct Player {
     pub prices: Vec<Price>,
     pub stats: Option<Stats>,
     pub injury: Option<Injury>,
+    /// Injury data was loaded (`injury` is `None` for healthy players).
+    /// Runtime state only: not exported, nor passed to strategies.
+    #[serde(skip)]
+    pub injury_loaded: bool,
     pub positions: Vec<String>,
     pub country: String,
     pub number: i64,
 }
 
 impl Player {
+    pub fn has_data(&self, requirement: DataRequirement) -> bool {
+        match requirement {
+            DataRequirement::Prices => !self.prices.is_empty(),
+            DataRequirement::Stats => self.stats.is_some(),
+            DataRequirement::Injury => self.injury_loaded || self.injury.is_some(),
+            DataRequirement::Schedule => false,
+        }
+    }
+
+    /// Data required by a strategy, but not loaded yet for the player.
+    pub fn missing_data(&self, requirements: &Requirements) -> Requirements {
+        requirements
+            .iter()
+            .filter(|r| !self.has_data(**r))
+            .copied()
+            .collect()
+    }
+
+    /// Checks if at least one strategy can be evaluated on the player.
+    pub fn is_evaluable(&self, requirements: &[Requirements]) -> bool {
+        requirements.iter().any(|r| self.missing_data(r).is_empty())
+    }
+
     pub fn price_delta_ratio(&self, currency: Currency) -> Option<f64> {
         if self.prices.is_empty() {
             return None;
@@ -67,6 +100,39 @@ impl Player {
         }
     }
 
+    /// Same as `price_delta_ratio`, but returns `None` when prices cannot be parsed, or the oldest price is zero.
+    pub fn try_price_delta_ratio(&self, currency: Currency) -> Option<f64> {
+        let last = self.prices.first()?.amount(&currency)?;
+        let old = self.prices.last()?.amount(&currency)?;
+        if old == 0.0 {
+            return None;
+        }
+        Some((last - old) / old)
+    }
+
+    /// Same as `price_avg`, but returns `None` when prices cannot be parsed.
+    pub fn try_price_avg(&self, currency: Currency, max_count: usize) -> Option<f64> {
+        let prices = self
+            .prices
+            .iter()
+            .take(max_count)
+            .map(|p| p.amount(&currency))
+            .collect::<Option<Vec<f64>>>()?;
+        if prices.is_empty() {
+            return None;
+        }
+        Some(prices.iter().sum::<f64>() / prices.len() as f64)
+    }
+
+    /// Same as `age`, but returns `None` when the birth date cannot be parsed.
+    pub fn try_age(&self) -> Option<u32> {
+        let birth_date = DateTime::parse_from_rfc3339(&self.birth_date)
+            .ok()?
+            .with_timezone(&Utc);
+        let now = chrono::Utc::now().date_naive();
+        Some(now.years_since(birth_date.date_naive()).unwrap_or(0))
+    }
+
     pub fn rank(&self, players: &[Player]) -> Option<usize> {
         let all_scores_loaded = players.iter().find(|p| p.stats.is_none()).is_none();
         if self.stats.is_some() {
@@ -0,0 +1,81 @@
+use super::{
+    player::Player,
+    price::Price,
+    stats::{Game, Stats},
+};
+
+/// Builds players for tests, with only the data a test needs.
+pub struct PlayerBuilder {
+    player: Player,
+}
+
+impl PlayerBuilder {
+    /// Player born on 2003-07-22, without team, prices, stats nor injury.
+    pub fn new(slug: &str) -> Self {
+        Self {
+            player: Player {
+                slug: slug.to_string(),
+                display_name: "name".to_string(),
+                birth_date: "2003-07-22T17:15:13Z".to_string(),
+                team: None,
+                prices: vec![],
+                stats: None,
+                injury: None,
+                injury_loaded: false,
+                positions: vec![],
+                country: "US".to_string(),
+                number: 23,
+            },
+        }
+    }
+
+    pub fn team(mut self, team: &str) -> Self {
+        self.player.team = Some(team.to_string());
+        self
+    }
+
+    pub fn position(mut self, position: &str) -> Self {
+        self.player.positions.push(position.to_string());
+        self
+    }
+
+    /// Adds a sale price (prices are ordered from the most recent one).
+    pub fn price(mut self, date: &str, eur: &str, usd: &str) -> Self {
+        self.player.prices.push(Price {
+            player_slug: self.player.slug.clone(),
+            date: date.to_string(),
+            eur: eur.to_string(),
+            usd: usd.to_string(),
+        });
+        self
+    }
+
+    pub fn score(mut self, score: i64) -> Self {
+        self.stats().score = score;
+        self
+    }
+
+    /// Adds a game to the stats (games are ordered from the most recent one).
+    pub fn game(mut self, date: &str, did_play: bool, score: u64, minutes_played: i64) -> Self {
+        self.stats().games.push(Game {
+            date: date.to_string(),
+            did_play,
+            score,
+            minutes_played,
+        });
+        self
+    }
+
+    pub fn build(self) -> Player {
+        self.player
+    }
+
+    fn stats(&mut self) -> &mut Stats {
+        let slug = self.player.slug.clone();
+        self.player.stats.get_or_insert_with(|| Stats {
+            player_slug: slug,
+            score: 0,
+            games: vec![],
+        })
+    }
+}
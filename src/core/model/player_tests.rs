@@ -11,6 +11,7 @@ fn create_player() -> Player {
         team: Some("team".to_string()),
         stats: None,
         injury: None,
+        injury_loaded: false,
         positions: vec![],
         country: "US".to_string(),
         number: 23,
@@ -99,3 +100,38 @@ fn sales_hours_interval_avg() {
     player.prices = vec![];
     assert_eq!(player.sales_hours_interval_avg(), None);
 }
+
+#[test]
+fn try_helpers() {
+    let mut player = create_player();
+    assert_eq!(player.try_price_avg(Currency::Euro, 5), Some(50.0));
+    assert_eq!(
+        player.try_price_delta_ratio(Currency::Usd),
+        player.price_delta_ratio(Currency::Usd)
+    );
+    assert_eq!(player.try_age(), Some(player.age()));
+
+    // Malformed data
+    player.prices[1].eur = "n/a".to_string();
+    player.birth_date = "unknown".to_string();
+    assert_eq!(player.try_price_avg(Currency::Euro, 5), None);
+    assert_eq!(player.try_price_avg(Currency::Euro, 1), Some(40.0));
+    assert_eq!(player.try_price_delta_ratio(Currency::Euro), None);
+    assert_eq!(player.try_age(), None);
+
+    // No prices
+    player.prices.clear();
+    assert_eq!(player.try_price_avg(Currency::Euro, 5), None);
+    assert_eq!(player.try_price_delta_ratio(Currency::Euro), None);
+}
+
+#[test]
+fn injury_loaded_not_serialized() {
+    let mut player = create_player();
+    player.injury_loaded = true;
+    let json = serde_json::to_value(&player).unwrap();
+    assert!(json.get("injury_loaded").is_none());
+
+    let player: Player = serde_json::from_value(json).unwrap();
+    assert!(!player.injury_loaded);
+}
@@ -1,6 +1,10 @@
+use std::collections::HashMap;
+
 use rquickjs::IntoJs;
 use serde::{Deserialize, Serialize};
 
+use super::currency::Currency;
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, IntoJs)]
 pub struct Price {
     pub player_slug: String,
@@ -8,3 +12,22 @@ pub struct Price {
     pub eur: String,
     pub usd: String,
 }
+
+impl Price {
+    /// Amount in a currency, if it can be parsed.
+    pub fn amount(&self, currency: &Currency) -> Option<f64> {
+        match currency {
+            Currency::Euro => self.eur.parse().ok(),
+            Currency::Usd => self.usd.parse().ok(),
+        }
+    }
+}
+
+/// Prices of a batch of players: players that failed to load are reported with their error.
+#[derive(Debug, Default)]
+pub struct PricesBatch {
+    /// Prices by player slug
+    pub prices: HashMap<String, Vec<Price>>,
+    /// Failed players, with the error of their request
+    pub errors: Vec<(Vec<String>, String)>,
+}
@@ -0,0 +1,89 @@
+use std::{collections::BTreeSet, fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+/// Player data a strategy needs to be evaluated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DataRequirement {
+    Prices,
+    Stats,
+    Injury,
+    /// Upcoming games: not provided by socli yet, strategies requiring it are always skipped
+    Schedule,
+}
+
+impl Display for DataRequirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Prices => write!(f, "prices"),
+            Self::Stats => write!(f, "stats"),
+            Self::Injury => write!(f, "injury"),
+            Self::Schedule => write!(f, "schedule"),
+        }
+    }
+}
+
+impl FromStr for DataRequirement {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "prices" => Ok(Self::Prices),
+            "stats" => Ok(Self::Stats),
+            "injury" => Ok(Self::Injury),
+            "schedule" => Ok(Self::Schedule),
+            other => Err(format!("unknown required data `{}`", other)),
+        }
+    }
+}
+
+/// Data required by a strategy.
+pub type Requirements = BTreeSet<DataRequirement>;
+
+/// Requirements of strategies not declaring any: prices and stats.
+pub fn default_requirements() -> Requirements {
+    BTreeSet::from([DataRequirement::Prices, DataRequirement::Stats])
+}
+
+/// Directive declaring the data required by a script, in a comment
+/// (such as `// @requires stats, injury`).
+const REQUIRES_DIRECTIVE: &str = "@requires";
+
+/// Reads the `@requires` directive of a script (`None` if not declared).
+/// `@requires none` declares a strategy running on player bio only.
+pub fn parse_requires_directive(code: &str) -> Result<Option<Requirements>, String> {
+    let directive = code.lines().map(str::trim).find_map(|line| {
+        let is_comment = ["//", "#", "--", "/*", "*"]
+            .iter()
+            .any(|prefix| line.starts_with(prefix));
+        let start = line.find(REQUIRES_DIRECTIVE)?;
+        is_comment.then(|| line[start + REQUIRES_DIRECTIVE.len()..].trim_end_matches("*/"))
+    });
+
+    match directive {
+        None => Ok(None),
+        Some(list) if list.trim() == "none" => Ok(Some(Requirements::new())),
+        Some(list) => list
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|name| !name.is_empty())
+            .map(DataRequirement::from_str)
+            .collect::<Result<Requirements, String>>()
+            .map(Some),
+    }
+}
+
+/// Skip reason for missing data (such as `missing prices and stats`).
+pub fn missing_reason(missing: &Requirements) -> String {
+    let names = missing.iter().map(|r| r.to_string()).collect::<Vec<_>>();
+    let reason = match names.split_last() {
+        None => "missing data".to_string(),
+        Some((last, [])) => format!("missing {}", last),
+        Some((last, others)) => format!("missing {} and {}", others.join(", "), last),
+    };
+    if missing.contains(&DataRequirement::Schedule) {
+        format!("{} (schedule data is not provided by socli yet)", reason)
+    } else {
+        reason
+    }
+}
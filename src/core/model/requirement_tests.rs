@@ -0,0 +1,66 @@
+use super::requirement::{missing_reason, parse_requires_directive, DataRequirement, Requirements};
+
+#[test]
+fn requires_directive() {
+    let code = r#"
+// Young players
+// @requires stats, injury
+export function decide(player) {}
+"#;
+    assert_eq!(
+        parse_requires_directive(code).unwrap(),
+        Some(Requirements::from([
+            DataRequirement::Stats,
+            DataRequirement::Injury
+        ]))
+    );
+
+    // Other comment styles
+    assert_eq!(
+        parse_requires_directive("-- @requires prices").unwrap(),
+        Some(Requirements::from([DataRequirement::Prices]))
+    );
+    assert_eq!(
+        parse_requires_directive("/* @requires none */").unwrap(),
+        Some(Requirements::new())
+    );
+
+    // Not declared, or not in a comment
+    assert_eq!(parse_requires_directive("fn decide() {}").unwrap(), None);
+    assert_eq!(
+        parse_requires_directive("let s = \"@requires stats\";").unwrap(),
+        None
+    );
+
+    assert_eq!(
+        parse_requires_directive("// @requires stats, schedule").unwrap(),
+        Some(Requirements::from([
+            DataRequirement::Stats,
+            DataRequirement::Schedule
+        ]))
+    );
+    assert!(parse_requires_directive("// @requires salary").is_err());
+}
+
+#[test]
+fn missing_reasons() {
+    assert_eq!(
+        missing_reason(&Requirements::from([DataRequirement::Prices])),
+        "missing prices"
+    );
+    assert_eq!(
+        missing_reason(&Requirements::from([
+            DataRequirement::Prices,
+            DataRequirement::Stats,
+            DataRequirement::Injury
+        ])),
+        "missing prices, stats and injury"
+    );
+    assert_eq!(
+        missing_reason(&Requirements::from([
+            DataRequirement::Stats,
+            DataRequirement::Schedule
+        ])),
+        "missing stats and schedule (schedule data is not provided by socli yet)"
+    );
+}
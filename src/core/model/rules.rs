@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use super::{params::ParamSpecs, requirement::Requirements};
+
+/// A declarative strategy, loaded from a `.toml` file: rules are checked in order,
+/// the first matching rule gives the decision.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RuleSet {
+    #[serde(default)]
+    pub params: ParamSpecs,
+    /// Player data required by the rules (defaults to prices and stats)
+    pub requires: Option<Requirements>,
+    #[serde(default)]
+    pub rules: Vec<Rule>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rule {
+    /// Condition (such as `score > 35 and price_avg(5) < 20 and not injured`)
+    pub when: String,
+    pub action: String,
+    /// Comment template: `{expression}` placeholders are replaced by their value
+    /// (defaults to the condition)
+    pub comment: Option<String>,
+}
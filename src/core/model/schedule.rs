@@ -0,0 +1,129 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use chrono::{DateTime, Duration, Utc};
+
+use super::config::RefreshConfig;
+
+/// Rate limit backoff multiplies the delay between refreshes, up to this factor
+const MAX_BACKOFF: u32 = 16;
+
+/// Refresh priority of a player (highest last)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RefreshPriority {
+    Player,
+    Decision,
+    Watched,
+    Selected,
+}
+
+#[derive(Debug, Default)]
+struct PlayerSchedule {
+    last_refresh: Option<DateTime<Utc>>,
+    /// Consecutive refreshes without new sales
+    idle_count: u32,
+}
+
+/// Chooses the next players to refresh: the most overdue players of the highest priority.
+/// Players without new sales are refreshed less often, and refreshes slow down on request failures.
+#[derive(Debug)]
+pub struct RefreshScheduler {
+    config: RefreshConfig,
+    players: HashMap<String, PlayerSchedule>,
+    backoff: u32,
+}
+
+impl RefreshScheduler {
+    pub fn new(config: RefreshConfig) -> Self {
+        Self {
+            config,
+            players: HashMap::new(),
+            backoff: 1,
+        }
+    }
+
+    pub fn priority(&self, slug: &str, selected: bool, has_decision: bool) -> RefreshPriority {
+        if selected {
+            RefreshPriority::Selected
+        } else if self.config.watchlist.iter().any(|s| s == slug) {
+            RefreshPriority::Watched
+        } else if has_decision {
+            RefreshPriority::Decision
+        } else {
+            RefreshPriority::Player
+        }
+    }
+
+    /// Minimum delay before refreshing a player again.
+    pub fn interval(&self, slug: &str, priority: RefreshPriority) -> Duration {
+        let secs = match priority {
+            RefreshPriority::Selected => self.config.selected_interval_secs,
+            RefreshPriority::Watched => self.config.watched_interval_secs,
+            RefreshPriority::Decision => self.config.decision_interval_secs,
+            RefreshPriority::Player => {
+                let idle_count = self.players.get(slug).map_or(0, |p| p.idle_count);
+                self.config
+                    .player_interval_secs
+                    .saturating_mul(1 << idle_count.min(16))
+                    .min(self.config.max_idle_interval_secs)
+            }
+        };
+        Duration::seconds(secs as i64)
+    }
+
+    /// Next players to refresh (at most `count`), among due players.
+    pub fn next(
+        &self,
+        candidates: &[(String, RefreshPriority)],
+        now: DateTime<Utc>,
+        count: usize,
+    ) -> Vec<String> {
+        let mut due = candidates
+            .iter()
+            .filter_map(|(slug, priority)| {
+                match self.players.get(slug).and_then(|p| p.last_refresh) {
+                    Some(last) => {
+                        let overdue = now - last - self.interval(slug, *priority);
+                        (overdue >= Duration::zero()).then_some((slug, priority, false, overdue))
+                    }
+                    None => Some((slug, priority, true, Duration::zero())),
+                }
+            })
+            .collect::<Vec<_>>();
+        // Never refreshed players first, then the most overdue (stable: on ties, first candidates first)
+        due.sort_by_key(|(_, priority, never, overdue)| Reverse((**priority, *never, *overdue)));
+        due.into_iter()
+            .take(count)
+            .map(|(slug, _, _, _)| slug.clone())
+            .collect()
+    }
+
+    /// Records a refresh request of a player.
+    pub fn refreshing(&mut self, slug: &str, now: DateTime<Utc>) {
+        self.players
+            .entry(slug.to_string())
+            .or_default()
+            .last_refresh = Some(now);
+    }
+
+    /// Last refresh request, all players included.
+    pub fn last_refresh(&self) -> Option<DateTime<Utc>> {
+        self.players.values().filter_map(|p| p.last_refresh).max()
+    }
+
+    /// Records refreshed prices of a player (`changed` if new sales were found).
+    pub fn refreshed(&mut self, slug: &str, changed: bool) {
+        let player = self.players.entry(slug.to_string()).or_default();
+        player.idle_count = if changed { 0 } else { player.idle_count + 1 };
+        self.backoff = (self.backoff / 2).max(1);
+    }
+
+    /// Records a failed request: refreshes slow down until requests succeed again.
+    pub fn failed(&mut self) {
+        self.backoff = (self.backoff * 2).min(MAX_BACKOFF);
+    }
+
+    /// Delay before the next refresh.
+    pub fn delay(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.config.interval_secs * self.backoff as u64)
+    }
+}
@@ -0,0 +1,134 @@
+use chrono::{Duration, TimeZone, Utc};
+
+use super::{
+    config::RefreshConfig,
+    schedule::{RefreshPriority, RefreshScheduler},
+};
+
+fn create_scheduler() -> RefreshScheduler {
+    RefreshScheduler::new(RefreshConfig {
+        watchlist: vec!["watched".to_string()],
+        ..Default::default()
+    })
+}
+
+fn candidates(players: &[(&str, RefreshPriority)]) -> Vec<(String, RefreshPriority)> {
+    players.iter().map(|(s, p)| (s.to_string(), *p)).collect()
+}
+
+#[test]
+fn priority() {
+    let scheduler = create_scheduler();
+
+    assert_eq!(
+        scheduler.priority("watched", true, false),
+        RefreshPriority::Selected
+    );
+    assert_eq!(
+        scheduler.priority("watched", false, true),
+        RefreshPriority::Watched
+    );
+    assert_eq!(
+        scheduler.priority("other", false, true),
+        RefreshPriority::Decision
+    );
+    assert_eq!(
+        scheduler.priority("other", false, false),
+        RefreshPriority::Player
+    );
+}
+
+#[test]
+fn next_by_priority_then_overdue() {
+    let mut scheduler = create_scheduler();
+    let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+    let players = candidates(&[
+        ("a", RefreshPriority::Player),
+        ("b", RefreshPriority::Player),
+        ("c", RefreshPriority::Selected),
+    ]);
+
+    // Never refreshed: by priority, then in order
+    assert_eq!(scheduler.next(&players, now, 2), vec!["c", "a"]);
+    scheduler.refreshing("c", now);
+    scheduler.refreshing("a", now);
+    assert_eq!(scheduler.next(&players, now, 2), vec!["b"]);
+    scheduler.refreshing("b", now + Duration::seconds(10));
+
+    // Nothing due
+    assert!(scheduler
+        .next(&players, now + Duration::seconds(20), 2)
+        .is_empty());
+
+    // Selected player is due first
+    assert_eq!(
+        scheduler.next(&players, now + Duration::seconds(30), 2),
+        vec!["c"]
+    );
+
+    // Most overdue players
+    let later = now + Duration::seconds(1000);
+    assert_eq!(scheduler.next(&players, later, 2), vec!["c", "a"]);
+    assert_eq!(scheduler.next(&players[..2], later, 1), vec!["a"]);
+}
+
+#[test]
+fn idle_players_backoff() {
+    let mut scheduler = create_scheduler();
+
+    assert_eq!(
+        scheduler.interval("a", RefreshPriority::Player),
+        Duration::seconds(900)
+    );
+    scheduler.refreshed("a", false);
+    assert_eq!(
+        scheduler.interval("a", RefreshPriority::Player),
+        Duration::seconds(1800)
+    );
+    for _ in 0..10 {
+        scheduler.refreshed("a", false);
+    }
+    assert_eq!(
+        scheduler.interval("a", RefreshPriority::Player),
+        Duration::seconds(7200)
+    );
+    // Watched players are not slowed down
+    assert_eq!(
+        scheduler.interval("a", RefreshPriority::Watched),
+        Duration::seconds(120)
+    );
+
+    scheduler.refreshed("a", true);
+    assert_eq!(
+        scheduler.interval("a", RefreshPriority::Player),
+        Duration::seconds(900)
+    );
+}
+
+#[test]
+fn failures_backoff() {
+    let mut scheduler = create_scheduler();
+    assert_eq!(scheduler.delay(), std::time::Duration::from_secs(3));
+
+    scheduler.failed();
+    scheduler.failed();
+    assert_eq!(scheduler.delay(), std::time::Duration::from_secs(12));
+    for _ in 0..10 {
+        scheduler.failed();
+    }
+    assert_eq!(scheduler.delay(), std::time::Duration::from_secs(48));
+
+    scheduler.refreshed("a", true);
+    assert_eq!(scheduler.delay(), std::time::Duration::from_secs(24));
+}
+
+#[test]
+fn last_refresh() {
+    let mut scheduler = create_scheduler();
+    assert_eq!(scheduler.last_refresh(), None);
+
+    let now = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+    scheduler.refreshing("b", now + Duration::seconds(10));
+    scheduler.refreshing("a", now);
+    assert_eq!(scheduler.last_refresh(), Some(now + Duration::seconds(10)));
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// A strategy script found in the strategy directory.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StrategyInfo {
+    /// Name, namespaced by sub folder (such as `momentum/v2`)
+    pub name: String,
+    /// Script path, relative to the strategy directory
+    pub path: String,
+    /// Script engine name
+    pub engine: String,
+    pub enabled: bool,
+}
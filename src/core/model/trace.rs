@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Outcome of a strategy evaluation on a player.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum TraceOutcome {
+    /// Strategy not run (missing prices or stats, disabled strategy)
+    Skipped {
+        reason: String,
+    },
+    /// Script returned no decision
+    NoDecision,
+    Decision {
+        action: String,
+        comment: String,
+    },
+    Error {
+        message: String,
+    },
+}
+
+/// Trace of the last evaluation of a strategy on a player.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EvaluationTrace {
+    pub strategy: String,
+    pub player_slug: String,
+    pub date: String,
+    /// Script execution time (milliseconds)
+    pub duration_ms: f64,
+    /// Value returned by the script
+    pub value: Option<Value>,
+    pub outcome: TraceOutcome,
+}
+
+impl EvaluationTrace {
+    /// Short status, such as `skipped` or the decision action.
+    pub fn status(&self) -> String {
+        match &self.outcome {
+            TraceOutcome::Skipped { .. } => "skipped".to_string(),
+            TraceOutcome::NoDecision => "no decision".to_string(),
+            TraceOutcome::Decision { action, .. } => action.clone(),
+            TraceOutcome::Error { .. } => "error".to_string(),
+        }
+    }
+
+    /// Details: skip reason, error message or returned value.
+    pub fn details(&self) -> String {
+        match &self.outcome {
+            TraceOutcome::Skipped { reason } => reason.clone(),
+            TraceOutcome::Error { message } => message.clone(),
+            _ => match &self.value {
+                Some(value) => value.to_string(),
+                None => "nothing returned".to_string(),
+            },
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use notify_rust::Notification;
+
+use crate::core::{model::decision::Decision, service::notification::NotificationError};
+
+use super::{body, title, NotificationSink};
+
+/// Desktop notification sink.
+pub struct DesktopSink {
+    name: String,
+}
+
+impl DesktopSink {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl NotificationSink for DesktopSink {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn send(&self, decision: &Decision) -> Result<(), NotificationError> {
+        let summary = format!("socli: {}", title(decision));
+        let body = body(decision);
+        // Notification servers are called synchronously
+        tokio::task::spawn_blocking(move || {
+            Notification::new()
+                .appname("socli")
+                .summary(&summary)
+                .body(&body)
+                .show()
+                .map(|_| ())
+                .map_err(|e| NotificationError::Send(e.to_string()))
+        })
+        .await
+        .map_err(|e| NotificationError::Send(e.to_string()))?
+    }
+}
@@ -0,0 +1,74 @@
+pub mod desktop;
+pub mod smtp;
+pub mod webhook;
+
+#[cfg(test)]
+pub mod webhook_tests;
+
+use async_trait::async_trait;
+use lettre::transport::smtp::authentication::Credentials;
+
+use crate::core::{
+    model::{
+        decision::Decision,
+        notification::{SinkConfig, SinkKind},
+    },
+    service::notification::NotificationError,
+};
+
+/// Notification destination (desktop, webhook, email...).
+#[async_trait]
+pub trait NotificationSink: Send + Sync {
+    fn name(&self) -> &str;
+
+    async fn send(&self, decision: &Decision) -> Result<(), NotificationError>;
+}
+
+/// Creates a sink from its configuration.
+pub fn create_sink(config: &SinkConfig) -> Result<Box<dyn NotificationSink>, NotificationError> {
+    Ok(match &config.kind {
+        SinkKind::Desktop => Box::new(desktop::DesktopSink::new(&config.name)),
+        SinkKind::Webhook { url, headers } => Box::new(webhook::WebhookSink::new(
+            &config.name,
+            url,
+            headers.clone(),
+        )),
+        SinkKind::Smtp {
+            host,
+            port,
+            username,
+            password,
+            from,
+            to,
+        } => {
+            let credentials = match (username, password) {
+                (Some(username), Some(password)) => {
+                    Some(Credentials::new(username.clone(), password.clone()))
+                }
+                _ => None,
+            };
+            Box::new(smtp::SmtpSink::new(
+                &config.name,
+                host,
+                *port,
+                credentials,
+                from,
+                to,
+            )?)
+        }
+    })
+}
+
+/// Notification title (such as `Buy LeBron James`).
+pub fn title(decision: &Decision) -> String {
+    format!("{} {}", decision.action, decision.player_name)
+}
+
+/// Notification text (strategy and comment).
+pub fn body(decision: &Decision) -> String {
+    if decision.comment.is_empty() {
+        decision.strategy.clone()
+    } else {
+        format!("{}: {}", decision.strategy, decision.comment)
+    }
+}
@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+use lettre::{
+    message::Mailbox, transport::smtp::authentication::Credentials, AsyncSmtpTransport,
+    AsyncTransport, Message, Tokio1Executor,
+};
+
+use crate::core::{model::decision::Decision, service::notification::NotificationError};
+
+use super::{body, title, NotificationSink};
+
+/// Email sink (SMTP, with TLS).
+pub struct SmtpSink {
+    name: String,
+    from: Mailbox,
+    to: Vec<Mailbox>,
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+fn config_error(e: impl ToString) -> NotificationError {
+    NotificationError::Config(e.to_string())
+}
+
+impl SmtpSink {
+    pub fn new(
+        name: &str,
+        host: &str,
+        port: Option<u16>,
+        credentials: Option<Credentials>,
+        from: &str,
+        to: &[String],
+    ) -> Result<Self, NotificationError> {
+        let mut transport =
+            AsyncSmtpTransport::<Tokio1Executor>::relay(host).map_err(config_error)?;
+        if let Some(port) = port {
+            transport = transport.port(port);
+        }
+        if let Some(credentials) = credentials {
+            transport = transport.credentials(credentials);
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            from: from.parse().map_err(config_error)?,
+            to: to
+                .iter()
+                .map(|address| address.parse().map_err(config_error))
+                .collect::<Result<_, _>>()?,
+            transport: transport.build(),
+        })
+    }
+}
+
+#[async_trait]
+impl NotificationSink for SmtpSink {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn send(&self, decision: &Decision) -> Result<(), NotificationError> {
+        let mut builder = Message::builder()
+            .from(self.from.clone())
+            .subject(format!("socli: {}", title(decision)));
+        for to in &self.to {
+            builder = builder.to(to.clone());
+        }
+        let message = builder.body(body(decision)).map_err(config_error)?;
+
+        self.transport
+            .send(message)
+            .await
+            .map(|_| ())
+            .map_err(|e| NotificationError::Send(e.to_string()))
+    }
+}
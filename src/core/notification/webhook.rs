@@ -0,0 +1,63 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::json;
+
+use crate::core::{model::decision::Decision, service::notification::NotificationError};
+
+use super::{body, title, NotificationSink};
+
+/// HTTP webhook sink: posts decisions as JSON.
+///
+/// The payload holds the decision and a `text` summary (displayed by Slack-like webhooks).
+pub struct WebhookSink {
+    name: String,
+    url: String,
+    headers: BTreeMap<String, String>,
+    client: Client,
+}
+
+impl WebhookSink {
+    pub fn new(name: &str, url: &str, headers: BTreeMap<String, String>) -> Self {
+        Self {
+            name: name.to_string(),
+            url: url.to_string(),
+            headers,
+            client: Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl NotificationSink for WebhookSink {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn send(&self, decision: &Decision) -> Result<(), NotificationError> {
+        let payload = json!({
+            "event": "decision",
+            "text": format!("{} ({})", title(decision), body(decision)),
+            "decision": decision,
+        });
+
+        let mut request = self.client.post(&self.url).json(&payload);
+        for (name, value) in &self.headers {
+            request = request.header(name, value);
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| NotificationError::Send(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(NotificationError::Send(format!(
+                "webhook {} answered {}",
+                self.name,
+                response.status()
+            )));
+        }
+
+        Ok(())
+    }
+}
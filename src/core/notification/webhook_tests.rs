@@ -0,0 +1,88 @@
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+    sync::oneshot,
+};
+
+use crate::core::model::decision::Decision;
+
+use super::{webhook::WebhookSink, NotificationSink};
+
+/// Starts a local HTTP listener answering a single request with `status`.
+/// Returns its URL and the received request (headers and body).
+async fn listen_once(status: &'static str) -> (String, oneshot::Receiver<(String, Value)>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/hook", listener.local_addr().unwrap());
+    let (tx, rx) = oneshot::channel();
+
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut request = vec![];
+        let mut buffer = [0; 1024];
+        // Read headers, then the body (sized by content-length)
+        let (headers, body) = loop {
+            let n = socket.read(&mut buffer).await.unwrap();
+            request.extend_from_slice(&buffer[..n]);
+            let text = String::from_utf8_lossy(&request).to_string();
+            if let Some((headers, body)) = text.split_once("\r\n\r\n") {
+                let length = headers
+                    .lines()
+                    .find_map(|l| {
+                        l.to_lowercase()
+                            .strip_prefix("content-length:")
+                            .map(|v| v.trim().to_string())
+                    })
+                    .and_then(|v| v.parse::<usize>().ok())
+                    .unwrap_or(0);
+                if body.len() >= length {
+                    break (headers.to_string(), body.to_string());
+                }
+            }
+        };
+        socket
+            .write_all(format!("HTTP/1.1 {}\r\ncontent-length: 0\r\n\r\n", status).as_bytes())
+            .await
+            .unwrap();
+        tx.send((headers, serde_json::from_str(&body).unwrap()))
+            .unwrap();
+    });
+
+    (url, rx)
+}
+
+fn create_decision() -> Decision {
+    Decision {
+        action: "Buy".to_string(),
+        player_slug: "slug".to_string(),
+        player_name: "name".to_string(),
+        strategy: "momentum/v2".to_string(),
+        comment: "score 40".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn webhook_posts_decision() {
+    let (url, request) = listen_once("200 OK").await;
+    let headers = BTreeMap::from([("X-Token".to_string(), "secret".to_string())]);
+    let sink = WebhookSink::new("hook", &url, headers);
+
+    sink.send(&create_decision()).await.unwrap();
+
+    let (headers, payload) = request.await.unwrap();
+    assert!(headers.starts_with("POST /hook"));
+    assert!(headers.to_lowercase().contains("x-token: secret"));
+    assert_eq!(payload["event"], "decision");
+    assert_eq!(payload["decision"]["strategy"], "momentum/v2");
+    assert_eq!(payload["text"], "Buy name (momentum/v2: score 40)");
+}
+
+#[tokio::test]
+async fn webhook_error_status() {
+    let (url, _request) = listen_once("500 Internal Server Error").await;
+    let sink = WebhookSink::new("hook", &url, BTreeMap::new());
+
+    assert!(sink.send(&create_decision()).await.is_err());
+}
@@ -1,8 +1,10 @@
+use std::time::Instant;
+
 use async_trait::async_trait;
 use graphql_client::{reqwest::post_graphql, GraphQLQuery};
 use reqwest::Client;
 
-use crate::core::model::injury::Injury;
+use crate::core::{metrics::metrics, model::injury::Injury};
 
 use super::error::RepoError;
 
@@ -44,16 +46,20 @@ impl InjuryRepo for InjuryRepoImpl {
             slugs: Some(player_slugs.to_vec()),
         };
 
-        let response_body = post_graphql::<GetPlayersInjury, _>(
+        let start = Instant::now();
+        let result = post_graphql::<GetPlayersInjury, _>(
             &self.client,
             "https://api.sorare.com/sports/graphql",
             variables,
         )
-        .await?;
+        .await;
+        metrics().graphql_request("injury", start.elapsed(), result.is_ok());
+        let response_body = result?;
 
         // Check errors
         if let Some(errors) = response_body.errors {
             if let Some(first_err) = errors.get(0) {
+                metrics().error("graphql");
                 return Err(RepoError::Read(first_err.to_string()));
             }
         }
@@ -6,4 +6,6 @@ pub mod stats;
 pub mod injury;
 
 #[cfg(test)]
-pub mod player_tests;
\ No newline at end of file
+pub mod player_tests;
+#[cfg(test)]
+pub mod price_tests;
\ No newline at end of file
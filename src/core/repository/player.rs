@@ -1,4 +1,7 @@
-use std::{collections::HashSet, time::Duration};
+use std::{
+    collections::HashSet,
+    time::{Duration, Instant},
+};
 
 use async_trait::async_trait;
 
@@ -7,7 +10,10 @@ use regex::Regex;
 use reqwest::Client;
 use tokio::time::sleep;
 
-use crate::core::model::player::Player;
+use crate::core::{
+    metrics::metrics,
+    model::{config::PlayersConfig, player::Player},
+};
 
 use super::error::RepoError;
 
@@ -36,15 +42,17 @@ pub trait PlayerRepo {
 
 pub struct PlayerRepoImpl {
     client: Client,
+    config: PlayersConfig,
 }
 
 impl PlayerRepoImpl {
-    pub fn new() -> Self {
+    pub fn new(config: PlayersConfig) -> Self {
         Self {
             client: Client::builder()
                 .user_agent("graphql-rust/0.10.0")
                 .build()
                 .expect("failed to build HTTP client"),
+            config,
         }
     }
 
@@ -61,16 +69,20 @@ impl PlayerRepoImpl {
         let variables = get_players_info::Variables {
             slugs: Some(player_slugs.to_vec()),
         };
-        let response_body = post_graphql::<GetPlayersInfo, _>(
+        let start = Instant::now();
+        let result = post_graphql::<GetPlayersInfo, _>(
             &self.client,
             "https://api.sorare.com/sports/graphql",
             variables,
         )
-        .await?;
+        .await;
+        metrics().graphql_request("player", start.elapsed(), result.is_ok());
+        let response_body = result?;
 
         // Check errors
         if let Some(errors) = response_body.errors {
             if let Some(first_err) = errors.get(0) {
+                metrics().error("graphql");
                 return Err(RepoError::Read(first_err.to_string()));
             }
         }
@@ -95,6 +107,7 @@ impl PlayerRepoImpl {
                 prices: vec![],
                 stats: None,
                 injury: None,
+                injury_loaded: false,
                 positions: p
                     .positions
                     .iter()
@@ -118,16 +131,20 @@ impl PlayerRepoImpl {
             size: size,
         };
 
-        let response_body = post_graphql::<GetAllTokensNBA, _>(
+        let start = Instant::now();
+        let result = post_graphql::<GetAllTokensNBA, _>(
             &self.client,
             "https://api.sorare.com/graphql",
             variables,
         )
-        .await?;
+        .await;
+        metrics().graphql_request("player", start.elapsed(), result.is_ok());
+        let response_body = result?;
 
         // Check errors
         if let Some(errors) = response_body.errors {
             if let Some(first_err) = errors.get(0) {
+                metrics().error("graphql");
                 return Err(RepoError::Read(first_err.to_string()));
             }
         }
@@ -154,7 +171,7 @@ impl PlayerRepoImpl {
 impl PlayerRepo for PlayerRepoImpl {
     async fn get_players(&self) -> Result<Vec<Player>, RepoError> {
         // The page size for paged queries
-        let page_size = 50;
+        let page_size = self.config.page_size;
         // Track if new players found after laoding anew page
         let mut new_count;
         // Track retry (new page load) without finding new players
@@ -187,7 +204,7 @@ impl PlayerRepo for PlayerRepoImpl {
             log::debug!("Loaded players count is: {}", players_set.len());
 
             // Wait a bit before next page
-            sleep(Duration::from_millis(2000)).await;
+            sleep(Duration::from_millis(self.config.page_delay_ms)).await;
         }
 
         // Convert to vec and sort results
@@ -1,8 +1,15 @@
+use std::{collections::HashMap, time::Instant};
+
 use async_trait::async_trait;
-use graphql_client::{reqwest::post_graphql, GraphQLQuery};
+use graphql_client::{reqwest::post_graphql, GraphQLQuery, Response};
 use reqwest::Client;
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
 
-use crate::core::model::price::Price;
+use crate::core::{
+    metrics::metrics,
+    model::price::{Price, PricesBatch},
+};
 
 use super::error::RepoError;
 
@@ -16,10 +23,87 @@ type ISO8601DateTime = String;
 )]
 struct GetPrices;
 
+/// Players prices loaded by a batch query (bounds the query complexity)
+pub const MAX_BATCH_SIZE: usize = 20;
+
+/// Batch query response: prices by player alias (`p0`, `p1`...), null for failed aliases
+#[derive(Debug, Deserialize)]
+pub(crate) struct GetPricesBatchData {
+    tokens: Option<HashMap<String, Option<Vec<TokenPrice>>>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TokenPrice {
+    amount_in_fiat: AmountInFiat,
+    date: ISO8601DateTime,
+}
+
+#[derive(Debug, Deserialize)]
+struct AmountInFiat {
+    eur: f64,
+    usd: f64,
+}
+
+/// Fields of the `tokenPrices` query (as in `get-prices-query.graphql`)
+const PRICE_FIELDS: &str = "amountInFiat { eur, usd } date";
+
+/// Query of the prices of `count` players, with one aliased `tokenPrices` field by player.
+pub(crate) fn batch_prices_query(count: usize) -> String {
+    let variables = (0..count)
+        .map(|i| format!("$slug{}: String!", i))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let fields = (0..count)
+        .map(|i| {
+            format!(
+                "    p{i}: tokenPrices(playerSlug: $slug{i}, rarity: limited, collection: NBA) {{ {} }}\n",
+                PRICE_FIELDS
+            )
+        })
+        .collect::<String>();
+    format!(
+        "query GetPricesBatch({}) {{\n  tokens {{\n{}  }}\n}}\n",
+        variables, fields
+    )
+}
+
+/// Prices by player slug, from a batch query response (failed or missing players are skipped).
+pub(crate) fn parse_batch_prices(
+    player_slugs: &[String],
+    data: GetPricesBatchData,
+) -> HashMap<String, Vec<Price>> {
+    let mut tokens = data.tokens.unwrap_or_default();
+    player_slugs
+        .iter()
+        .enumerate()
+        .filter_map(|(i, slug)| {
+            tokens
+                .remove(&format!("p{}", i))
+                .flatten()
+                .map(|token_prices| {
+                    let prices = token_prices
+                        .iter()
+                        .map(|tp| Price {
+                            player_slug: slug.to_string(),
+                            date: tp.date.clone(),
+                            eur: format!("{:.2}", tp.amount_in_fiat.eur),
+                            usd: format!("{:.2}", tp.amount_in_fiat.usd),
+                        })
+                        .collect();
+                    (slug.to_string(), prices)
+                })
+        })
+        .collect()
+}
+
 #[async_trait]
 pub trait PriceRepo {
     // TODO: support rarity
     async fn get_prices(&self, player_slug: &str) -> Result<Vec<Price>, RepoError>;
+    /// Prices of many players, with a request by batch of `MAX_BATCH_SIZE` players.
+    /// Failed requests do not prevent returning the prices of other players.
+    async fn get_prices_batch(&self, player_slugs: &[String]) -> PricesBatch;
 }
 
 pub struct PriceRepoImpl {
@@ -44,13 +128,17 @@ impl PriceRepo for PriceRepoImpl {
         let variables = get_prices::Variables {
             slug: player_slug.to_string(),
         };
-        let response_body =
+        let start = Instant::now();
+        let result =
             post_graphql::<GetPrices, _>(&self.client, "https://api.sorare.com/graphql", variables)
-                .await?;
+                .await;
+        metrics().graphql_request("price", start.elapsed(), result.is_ok());
+        let response_body = result?;
 
         // Check errors
         if let Some(errors) = response_body.errors {
             if let Some(first_err) = errors.get(0) {
+                metrics().error("graphql");
                 return Err(RepoError::Read(first_err.to_string()));
             }
         }
@@ -59,7 +147,7 @@ impl PriceRepo for PriceRepoImpl {
         if response_body.data.is_none() {
             return Err(RepoError::Read("no data".to_string()));
         }
-        
+
         let response_data = response_body.data.unwrap();
         let token_prices = response_data.tokens.token_prices;
         Ok(token_prices
@@ -72,4 +160,95 @@ impl PriceRepo for PriceRepoImpl {
             })
             .collect())
     }
+
+    async fn get_prices_batch(&self, player_slugs: &[String]) -> PricesBatch {
+        let mut batch = PricesBatch::default();
+        for chunk in player_slugs.chunks(MAX_BATCH_SIZE) {
+            let (prices, error) = match self.get_prices_chunk(chunk).await {
+                Ok(result) => result,
+                // GraphQL errors can null the whole response
+                Err(RepoError::Read(msg)) => (HashMap::new(), Some(RepoError::Read(msg))),
+                // Request failures are not retried (such as when rate limited)
+                Err(e) => {
+                    batch.errors.push((chunk.to_vec(), e.to_string()));
+                    continue;
+                }
+            };
+            let missing = chunk
+                .iter()
+                .filter(|s| !prices.contains_key(*s))
+                .cloned()
+                .collect::<Vec<_>>();
+            batch.prices.extend(prices);
+
+            match error {
+                None => (),
+                // Isolate failing players (such as unknown slugs): query missing players one by one
+                Some(error) if chunk.len() > 1 => {
+                    log::debug!("Retrying {} players prices: {}", missing.len(), error);
+                    for slug in missing {
+                        match self.get_prices_chunk(&[slug.clone()]).await {
+                            Ok((prices, None)) => batch.prices.extend(prices),
+                            Ok((_, Some(e))) | Err(e) => {
+                                batch.errors.push((vec![slug], e.to_string()))
+                            }
+                        }
+                    }
+                }
+                Some(error) if !missing.is_empty() => {
+                    batch.errors.push((missing, error.to_string()))
+                }
+                Some(_) => (),
+            }
+        }
+
+        batch
+    }
+}
+
+impl PriceRepoImpl {
+    /// Runs a batch query: returns loaded prices, with the GraphQL error if some players failed.
+    async fn get_prices_chunk(
+        &self,
+        player_slugs: &[String],
+    ) -> Result<(HashMap<String, Vec<Price>>, Option<RepoError>), RepoError> {
+        // Run GraphQL query with a variable by player
+        let variables = player_slugs
+            .iter()
+            .enumerate()
+            .map(|(i, slug)| (format!("slug{}", i), Value::String(slug.to_string())))
+            .collect::<Map<_, _>>();
+        let body = json!({
+            "query": batch_prices_query(player_slugs.len()),
+            "variables": variables,
+            "operationName": "GetPricesBatch",
+        });
+        let start = Instant::now();
+        let result = match self
+            .client
+            .post("https://api.sorare.com/graphql")
+            .json(&body)
+            .send()
+            .await
+        {
+            Ok(response) => response.json::<Response<GetPricesBatchData>>().await,
+            Err(e) => Err(e),
+        };
+        metrics().graphql_request("price_batch", start.elapsed(), result.is_ok());
+        let response_body = result?;
+
+        // Check errors (data of other players is still returned)
+        let error = response_body
+            .errors
+            .and_then(|errors| errors.first().map(|e| RepoError::Read(e.to_string())));
+        if error.is_some() {
+            metrics().error("graphql");
+        }
+
+        // Parse result
+        match response_body.data {
+            Some(data) => Ok((parse_batch_prices(player_slugs, data), error)),
+            None => Err(error.unwrap_or_else(|| RepoError::Read("no data".to_string()))),
+        }
+    }
 }
@@ -0,0 +1,50 @@
+use crate::core::repository::price::{batch_prices_query, parse_batch_prices, GetPricesBatchData};
+
+#[test]
+fn parse_batch_prices_without_data() {
+    let data: GetPricesBatchData = serde_json::from_str(r#"{ "tokens": null }"#).unwrap();
+
+    assert!(parse_batch_prices(&["lebron-james".to_string()], data).is_empty());
+}
+
+#[test]
+fn build_batch_prices_query() {
+    let query = batch_prices_query(2);
+
+    assert!(query.starts_with("query GetPricesBatch($slug0: String!, $slug1: String!) {"));
+    assert!(query.contains(
+        "p0: tokenPrices(playerSlug: $slug0, rarity: limited, collection: NBA) { amountInFiat { eur, usd } date }"
+    ));
+    assert!(query.contains("p1: tokenPrices(playerSlug: $slug1"));
+    assert!(!query.contains("p2:"));
+}
+
+#[test]
+fn parse_batch_prices_by_alias() {
+    let data: GetPricesBatchData = serde_json::from_str(
+        r#"{
+            "tokens": {
+                "p0": [{ "amountInFiat": { "eur": 12.5, "usd": 13.64 }, "date": "2024-01-01T00:00:00Z" }],
+                "p1": [],
+                "p2": null
+            }
+        }"#,
+    )
+    .unwrap();
+    let slugs = vec![
+        "lebron-james".to_string(),
+        "kevin-durant".to_string(),
+        "failed".to_string(),
+        "missing".to_string(),
+    ];
+
+    let prices = parse_batch_prices(&slugs, data);
+
+    assert_eq!(prices.len(), 2);
+    assert_eq!(prices["lebron-james"][0].eur, "12.50");
+    assert_eq!(prices["lebron-james"][0].usd, "13.64");
+    assert_eq!(prices["lebron-james"][0].player_slug, "lebron-james");
+    assert!(prices["kevin-durant"].is_empty());
+    assert!(!prices.contains_key("failed"));
+    assert!(!prices.contains_key("missing"));
+}
@@ -1,8 +1,13 @@
+use std::time::Instant;
+
 use async_trait::async_trait;
 use graphql_client::{reqwest::post_graphql, GraphQLQuery};
 use reqwest::Client;
 
-use crate::core::model::stats::{Game, Stats};
+use crate::core::{
+    metrics::metrics,
+    model::stats::{Game, Stats},
+};
 
 use super::error::RepoError;
 
@@ -44,16 +49,20 @@ impl StatsRepo for StatsRepoImpl {
             slugs: Some(player_slugs.to_vec()),
         };
 
-        let response_body = post_graphql::<GetPlayersStats, _>(
+        let start = Instant::now();
+        let result = post_graphql::<GetPlayersStats, _>(
             &self.client,
             "https://api.sorare.com/sports/graphql",
             variables,
         )
-        .await?;
+        .await;
+        metrics().graphql_request("stats", start.elapsed(), result.is_ok());
+        let response_body = result?;
 
         // Check errors
         if let Some(errors) = response_body.errors {
             if let Some(first_err) = errors.get(0) {
+                metrics().error("graphql");
                 return Err(RepoError::Read(first_err.to_string()));
             }
         }
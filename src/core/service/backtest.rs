@@ -0,0 +1,154 @@
+use std::{collections::HashMap, fmt::Display};
+
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+
+use crate::{
+    core::{
+        model::{
+            backtest::{BacktestReport, Ledger},
+            context::StrategyContext,
+            history::PlayerHistory,
+            player::Player,
+        },
+        service::{
+            history::{HistoryError, HistoryService},
+            player::{PlayerError, PlayerService},
+            strategy::{StrategyError, StrategyService, StrategyServiceImpl},
+        },
+    },
+    resolve_trait,
+};
+
+#[derive(Debug)]
+pub enum BacktestError {
+    Data(String),
+    Strategy(String),
+}
+
+impl Display for BacktestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Data(msg) => write!(f, "{}", msg),
+            Self::Strategy(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl From<PlayerError> for BacktestError {
+    fn from(e: PlayerError) -> Self {
+        Self::Data(e.to_string())
+    }
+}
+
+impl From<HistoryError> for BacktestError {
+    fn from(e: HistoryError) -> Self {
+        Self::Data(e.to_string())
+    }
+}
+
+impl From<StrategyError> for BacktestError {
+    fn from(e: StrategyError) -> Self {
+        Self::Strategy(e.to_string())
+    }
+}
+
+#[async_trait]
+pub trait BacktestService {
+    /// Replays stored history day by day, simulating trades from strategies decisions.
+    async fn run(&self) -> Result<BacktestReport, BacktestError>;
+}
+
+pub struct BacktestServiceImpl {
+    strategy_dir: String,
+}
+
+impl BacktestServiceImpl {
+    pub fn new(strategy_dir: &str) -> Self {
+        Self {
+            strategy_dir: strategy_dir.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl BacktestService for BacktestServiceImpl {
+    async fn run(&self) -> Result<BacktestReport, BacktestError> {
+        let player_service = resolve_trait!(PlayerService);
+        let history_service = resolve_trait!(HistoryService);
+
+        // Load history of all players
+        let mut histories: Vec<(Player, PlayerHistory)> = vec![];
+        for player in player_service.get_players().await? {
+            let history = history_service.get_history(&player.slug).await?;
+            if !history.prices.is_empty() || !history.games.is_empty() {
+                histories.push((player, history));
+            }
+        }
+
+        let dates = histories
+            .iter()
+            .flat_map(|(_, h)| h.dates())
+            .collect::<Vec<_>>();
+        let (start, end) = match (dates.iter().min(), dates.iter().max()) {
+            (Some(start), Some(end)) => (start.date_naive(), end.date_naive()),
+            _ => {
+                log::warn!("No history to backtest");
+                return Ok(BacktestReport::default());
+            }
+        };
+
+        // State is not persisted while backtesting
+        let strategy_service = StrategyServiceImpl::new(&self.strategy_dir).ephemeral();
+        let mut ledger = Ledger::new();
+        let mut days = 0;
+        let mut day = start;
+        while day <= end {
+            let end_of_day = Utc.from_utc_datetime(&day.and_hms_opt(23, 59, 59).unwrap());
+            let date = end_of_day.to_rfc3339();
+            log::debug!("Backtesting {}", day);
+
+            // Players as they looked on that day
+            let snapshots = histories
+                .iter()
+                .map(|(player, history)| history.snapshot(player, &end_of_day))
+                .collect::<Vec<Player>>();
+            let context = StrategyContext::new(&snapshots);
+
+            let mut last_prices = HashMap::new();
+            for snapshot in &snapshots {
+                let price = match snapshot
+                    .prices
+                    .first()
+                    .and_then(|p| p.eur.parse::<f64>().ok())
+                {
+                    Some(price) => price,
+                    None => continue,
+                };
+                last_prices.insert(snapshot.slug.clone(), price);
+
+                for decision in strategy_service.run_all(snapshot, &context).await? {
+                    ledger.apply(
+                        &decision.strategy,
+                        &decision.player_slug,
+                        &decision.action,
+                        &date,
+                        price,
+                    );
+                }
+            }
+            ledger.mark(&last_prices);
+
+            days += 1;
+            day = day.succ_opt().unwrap();
+        }
+
+        Ok(BacktestReport {
+            start_date: Some(start.to_string()),
+            end_date: Some(end.to_string()),
+            days,
+            strategies: ledger.reports(),
+            trades: ledger.trades().to_vec(),
+        })
+    }
+}
@@ -0,0 +1,89 @@
+use std::fmt::Display;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use serde_json::{from_value, to_value};
+use tokio::sync::Mutex;
+
+use crate::{
+    core::{
+        model::{
+            currency::Currency, decision::Decision, decision_history::DecisionHistory,
+            player::Player,
+        },
+        repository::{error::RepoError, storage::StorageRepo},
+    },
+    resolve_trait,
+};
+
+/// Storage collection holding decision history
+const HISTORY_COLLECTION: &str = "decisions_history";
+
+#[derive(Debug)]
+pub enum DecisionError {
+    Data(String),
+}
+
+impl Display for DecisionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Data(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl From<RepoError> for DecisionError {
+    fn from(e: RepoError) -> Self {
+        Self::Data(e.to_string())
+    }
+}
+
+#[async_trait]
+pub trait DecisionService {
+    /// Records the decisions of a player evaluation (with the player current price).
+    async fn record(&self, player: &Player, decisions: &[Decision]) -> Result<(), DecisionError>;
+    async fn get_history(&self) -> Result<DecisionHistory, DecisionError>;
+}
+
+pub struct DecisionServiceImpl {
+    /// Serializes history updates
+    lock: Mutex<()>,
+}
+
+impl DecisionServiceImpl {
+    pub fn new() -> Self {
+        Self {
+            lock: Mutex::new(()),
+        }
+    }
+}
+
+#[async_trait]
+impl DecisionService for DecisionServiceImpl {
+    async fn record(&self, player: &Player, decisions: &[Decision]) -> Result<(), DecisionError> {
+        if decisions.is_empty() {
+            return Ok(());
+        }
+
+        let storage_repo = resolve_trait!(StorageRepo);
+        let _lock = self.lock.lock().await;
+
+        let mut history = self.get_history().await?;
+        history.merge(decisions, player.price_avg(Currency::Euro, 1), &Utc::now());
+        let value = to_value(&history).map_err(|e| DecisionError::Data(e.to_string()))?;
+        storage_repo
+            .set_collection(HISTORY_COLLECTION, &value)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn get_history(&self) -> Result<DecisionHistory, DecisionError> {
+        let storage_repo = resolve_trait!(StorageRepo);
+
+        match storage_repo.get_collection(HISTORY_COLLECTION).await? {
+            Some(v) => from_value(v).map_err(|e| DecisionError::Data(e.to_string())),
+            None => Ok(DecisionHistory::default()),
+        }
+    }
+}
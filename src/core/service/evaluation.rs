@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::task::JoinSet;
+
+use crate::core::{
+    model::{
+        context::StrategyContext,
+        evaluation::{EvaluationProgress, EvaluationReport},
+        player::Player,
+    },
+    service::strategy::{StrategyError, StrategyService, StrategyServiceImpl},
+};
+
+#[async_trait]
+pub trait EvaluationService {
+    /// Runs enabled strategies over every player, in parallel.
+    /// Players missing data required by every strategy are skipped.
+    async fn evaluate_all(
+        &self,
+        players: Vec<Player>,
+        on_progress: &(dyn for<'a> Fn(&'a EvaluationProgress) + Send + Sync),
+    ) -> Result<EvaluationReport, StrategyError>;
+}
+
+pub struct EvaluationServiceImpl {
+    strategy_dir: String,
+}
+
+impl EvaluationServiceImpl {
+    pub fn new(strategy_dir: &str) -> Self {
+        Self {
+            strategy_dir: strategy_dir.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl EvaluationService for EvaluationServiceImpl {
+    async fn evaluate_all(
+        &self,
+        players: Vec<Player>,
+        on_progress: &(dyn for<'a> Fn(&'a EvaluationProgress) + Send + Sync),
+    ) -> Result<EvaluationReport, StrategyError> {
+        let strategy_service = Arc::new(StrategyServiceImpl::new(&self.strategy_dir));
+        // Context is computed once for all players
+        let context = Arc::new(StrategyContext::new(&players));
+        let requirements = strategy_service.requirements().await?;
+        let mut report = EvaluationReport {
+            progress: EvaluationProgress::new(&players, &requirements),
+            ..Default::default()
+        };
+        on_progress(&report.progress);
+
+        let mut tasks = JoinSet::new();
+        for player in players
+            .into_iter()
+            .filter(|p| p.is_evaluable(&requirements))
+        {
+            let strategy_service = strategy_service.clone();
+            let context = context.clone();
+            tasks.spawn(async move {
+                let result = strategy_service.run_all(&player, &context).await;
+                (player.slug, result)
+            });
+        }
+
+        while let Some(result) = tasks.join_next().await {
+            match result {
+                Ok((_, Ok(decisions))) => report.decisions.extend(decisions),
+                Ok((slug, Err(e))) => report.errors.push(format!("{}: {}", slug, e)),
+                Err(e) => report.errors.push(e.to_string()),
+            }
+            report.progress.evaluated_count += 1;
+            on_progress(&report.progress);
+        }
+
+        // Players complete in any order
+        report
+            .decisions
+            .sort_by(|a, b| (&a.player_name, &a.strategy).cmp(&(&b.player_name, &b.strategy)));
+
+        Ok(report)
+    }
+}
@@ -0,0 +1,87 @@
+use std::fs;
+
+use async_trait::async_trait;
+use serde_json::from_str;
+
+use crate::core::{
+    model::{
+        context::StrategyContext,
+        fixture::{FixtureCase, FixtureReport, FixtureResult},
+    },
+    service::strategy::{sidecar_path, StrategyError, StrategyServiceImpl},
+};
+
+#[async_trait]
+pub trait FixtureService {
+    /// Runs strategies against their fixture files (`<name>.test.json`, next to scripts).
+    async fn run_fixtures(&self) -> Result<FixtureReport, StrategyError>;
+}
+
+pub struct FixtureServiceImpl {
+    strategy_dir: String,
+}
+
+impl FixtureServiceImpl {
+    pub fn new(strategy_dir: &str) -> Self {
+        Self {
+            strategy_dir: strategy_dir.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl FixtureService for FixtureServiceImpl {
+    async fn run_fixtures(&self) -> Result<FixtureReport, StrategyError> {
+        let mut report = FixtureReport::default();
+
+        let strategy_service = StrategyServiceImpl::new(&self.strategy_dir).ephemeral();
+        for path in strategy_service.script_paths()? {
+            let strategy_name = strategy_service.extract_strategy_name(&path);
+            let fixture_path = sidecar_path(&path, "test.json");
+            if !fixture_path.exists() {
+                continue;
+            }
+
+            let json = fs::read_to_string(&fixture_path).map_err(|e| {
+                StrategyError::Config(format!(
+                    "failed to read `{}`: {}",
+                    fixture_path.display(),
+                    e
+                ))
+            })?;
+            let cases: Vec<FixtureCase> = from_str(&json).map_err(|e| {
+                StrategyError::Config(format!(
+                    "failed to parse `{}`: {}",
+                    fixture_path.display(),
+                    e
+                ))
+            })?;
+
+            for case in cases {
+                let context = StrategyContext::new(
+                    case.players
+                        .as_deref()
+                        .unwrap_or(std::slice::from_ref(&case.player)),
+                );
+
+                // Each case starts with an empty state
+                let case_service = StrategyServiceImpl::new(&self.strategy_dir).ephemeral();
+                let failure = match case_service
+                    .run_script(&path, &case.player, &context, &case.params)
+                    .await
+                {
+                    Ok(decision) => case.check(decision.as_ref()).err(),
+                    Err(e) => Some(e.to_string()),
+                };
+
+                report.results.push(FixtureResult {
+                    strategy: strategy_name.clone(),
+                    case: case.name.clone(),
+                    failure,
+                });
+            }
+        }
+
+        Ok(report)
+    }
+}
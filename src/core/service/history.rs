@@ -0,0 +1,110 @@
+use std::fmt::Display;
+
+use async_trait::async_trait;
+use serde_json::{from_value, to_value};
+use tokio::sync::Mutex;
+
+use crate::{
+    core::{
+        model::{history::PlayerHistory, price::Price, stats::Stats},
+        repository::{error::RepoError, storage::StorageRepo},
+    },
+    resolve_trait,
+};
+
+#[derive(Debug)]
+pub enum HistoryError {
+    Data(String),
+}
+
+impl Display for HistoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Data(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl From<RepoError> for HistoryError {
+    fn from(e: RepoError) -> Self {
+        Self::Data(e.to_string())
+    }
+}
+
+#[async_trait]
+pub trait HistoryService {
+    async fn record_prices(&self, player_slug: &str, prices: &[Price]) -> Result<(), HistoryError>;
+    async fn record_stats(&self, stats: &[Stats]) -> Result<(), HistoryError>;
+    async fn get_history(&self, player_slug: &str) -> Result<PlayerHistory, HistoryError>;
+}
+
+pub struct HistoryServiceImpl {
+    /// Serializes history updates
+    lock: Mutex<()>,
+}
+
+impl HistoryServiceImpl {
+    pub fn new() -> Self {
+        Self {
+            lock: Mutex::new(()),
+        }
+    }
+
+    fn collection_name(&self, player_slug: &str) -> String {
+        format!("history_{}", player_slug)
+    }
+
+    async fn update<F>(&self, player_slug: &str, update: F) -> Result<(), HistoryError>
+    where
+        F: FnOnce(&mut PlayerHistory) -> bool + Send,
+    {
+        let storage_repo = resolve_trait!(StorageRepo);
+        let _lock = self.lock.lock().await;
+
+        let mut history = self.get_history(player_slug).await?;
+        if update(&mut history) {
+            match to_value(&history) {
+                Ok(v) => {
+                    storage_repo
+                        .set_collection(&self.collection_name(player_slug), &v)
+                        .await?
+                }
+                Err(err) => return Err(HistoryError::Data(err.to_string())),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl HistoryService for HistoryServiceImpl {
+    async fn record_prices(&self, player_slug: &str, prices: &[Price]) -> Result<(), HistoryError> {
+        self.update(player_slug, |history| history.merge_prices(prices))
+            .await
+    }
+
+    async fn record_stats(&self, stats: &[Stats]) -> Result<(), HistoryError> {
+        for s in stats {
+            self.update(&s.player_slug, |history| history.merge_games(&s.games))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn get_history(&self, player_slug: &str) -> Result<PlayerHistory, HistoryError> {
+        let storage_repo = resolve_trait!(StorageRepo);
+
+        match storage_repo
+            .get_collection(&self.collection_name(player_slug))
+            .await?
+        {
+            Some(v) => match from_value(v) {
+                Ok(history) => Ok(history),
+                Err(err) => Err(HistoryError::Data(err.to_string())),
+            },
+            None => Ok(PlayerHistory::new(player_slug)),
+        }
+    }
+}
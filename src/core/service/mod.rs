@@ -3,7 +3,15 @@ pub mod price;
 pub mod stats;
 pub mod strategy;
 pub mod injury;
+pub mod history;
+pub mod backtest;
+pub mod fixture;
+pub mod evaluation;
+pub mod decision;
+pub mod notification;
 
 
 #[cfg(test)]
-pub mod strategy_tests;
\ No newline at end of file
+pub mod strategy_tests;
+#[cfg(test)]
+pub mod notification_tests;
\ No newline at end of file
@@ -0,0 +1,141 @@
+use std::{fmt::Display, fs, path::Path, sync::Mutex};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use dirs::home_dir;
+
+use crate::core::{
+    model::{
+        decision::Decision,
+        notification::{NotificationConfig, Router, Throttle},
+    },
+    notification::{create_sink, NotificationSink},
+};
+
+/// Notification settings file, in the socli folder
+pub const NOTIFICATIONS_FILE: &str = "notifications.toml";
+
+#[derive(Debug)]
+pub enum NotificationError {
+    Config(String),
+    Send(String),
+}
+
+impl Display for NotificationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Config(msg) => write!(f, "configuration error: {}", msg),
+            Self::Send(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+#[async_trait]
+pub trait NotificationService {
+    /// Sends new decisions to the sinks they are routed to (throttled).
+    /// Returns the number of notifications sent.
+    async fn notify(&self, decisions: &[Decision]) -> Result<usize, NotificationError>;
+}
+
+pub struct NotificationServiceImpl {
+    router: Router,
+    sinks: Vec<Box<dyn NotificationSink>>,
+    throttle: Mutex<Throttle>,
+}
+
+impl NotificationServiceImpl {
+    /// Loads settings from `~/.socli/notifications.toml`: notifications are disabled
+    /// if the file is missing, and an invalid file is an error.
+    pub fn new() -> Result<Self, NotificationError> {
+        let path = home_dir()
+            .expect("failed to retrieve user home directory")
+            .join(".socli")
+            .join(NOTIFICATIONS_FILE);
+        Self::load(&path)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, NotificationError> {
+        if !path.exists() {
+            return Self::with_config(NotificationConfig::default());
+        }
+
+        let content = fs::read_to_string(path).map_err(|e| {
+            NotificationError::Config(format!("failed to read `{}`: {}", path.display(), e))
+        })?;
+        let config = NotificationConfig::parse(&content).map_err(|e| {
+            NotificationError::Config(format!("failed to parse `{}`: {}", path.display(), e))
+        })?;
+        Self::with_config(config)
+    }
+
+    pub fn with_config(config: NotificationConfig) -> Result<Self, NotificationError> {
+        let sinks = config
+            .sinks
+            .iter()
+            .map(create_sink)
+            .collect::<Result<Vec<_>, _>>()?;
+        for route in &config.routes {
+            if let Some(name) = route
+                .sinks
+                .iter()
+                .find(|name| !sinks.iter().any(|s| s.name() == name.as_str()))
+            {
+                return Err(NotificationError::Config(format!(
+                    "unknown sink `{}` in routes",
+                    name
+                )));
+            }
+        }
+
+        Ok(Self {
+            router: Router::new(&config).map_err(NotificationError::Config)?,
+            throttle: Mutex::new(Throttle::new(config.throttle)),
+            sinks,
+        })
+    }
+}
+
+#[async_trait]
+impl NotificationService for NotificationServiceImpl {
+    async fn notify(&self, decisions: &[Decision]) -> Result<usize, NotificationError> {
+        let mut sent_count = 0;
+        let mut errors = vec![];
+        for decision in decisions {
+            let sink_names = self.router.sinks_for(decision);
+            for sink in self
+                .sinks
+                .iter()
+                .filter(|s| sink_names.iter().any(|name| name == s.name()))
+            {
+                let allowed = match self.throttle.lock() {
+                    Ok(throttle) => throttle.allow(sink.name(), decision, Utc::now()),
+                    Err(_) => false,
+                };
+                if !allowed {
+                    log::debug!(
+                        "Notification of {} to {} throttled",
+                        decision.player_slug,
+                        sink.name()
+                    );
+                    continue;
+                }
+
+                match sink.send(decision).await {
+                    Ok(_) => {
+                        if let Ok(mut throttle) = self.throttle.lock() {
+                            throttle.record(sink.name(), decision, Utc::now());
+                        }
+                        sent_count += 1
+                    }
+                    Err(e) => errors.push(format!("failed to notify {}: {}", sink.name(), e)),
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(sent_count)
+        } else {
+            Err(NotificationError::Send(errors.join(", ")))
+        }
+    }
+}
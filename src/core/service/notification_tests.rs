@@ -0,0 +1,30 @@
+use crate::core::{
+    model::{decision::Decision, notification::NotificationConfig},
+    service::notification::{NotificationService, NotificationServiceImpl},
+};
+
+#[tokio::test]
+async fn failed_notification_not_throttled() {
+    // Nothing listens on the webhook port: sending fails
+    let config = NotificationConfig::parse(
+        r#"
+        [[sinks]]
+        name = "hook"
+        type = "webhook"
+        url = "http://127.0.0.1:1/hook"
+        "#,
+    )
+    .unwrap();
+    let notification_service = NotificationServiceImpl::with_config(config).unwrap();
+    let decisions = vec![Decision {
+        action: "Buy".to_string(),
+        player_slug: "slug".to_string(),
+        player_name: "name".to_string(),
+        strategy: "momentum/v2".to_string(),
+        comment: "".to_string(),
+    }];
+
+    assert!(notification_service.notify(&decisions).await.is_err());
+    // Sent again (not within the cooldown): fails again
+    assert!(notification_service.notify(&decisions).await.is_err());
+}
@@ -4,8 +4,9 @@ use async_trait::async_trait;
 
 use crate::{
     core::{
-        model::price::Price,
+        model::price::{Price, PricesBatch},
         repository::{error::RepoError, price::PriceRepo},
+        service::history::HistoryService,
     },
     resolve_trait,
 };
@@ -32,6 +33,8 @@ impl From<RepoError> for PriceError {
 #[async_trait]
 pub trait PriceService {
     async fn get_prices(&self, player_slug: &str) -> Result<Vec<Price>, PriceError>;
+    /// Prices of many players (with fewer requests): failed players are reported in the batch.
+    async fn get_prices_batch(&self, player_slugs: &[String]) -> PricesBatch;
 }
 
 pub struct PriceServiceImpl {}
@@ -41,6 +44,33 @@ impl PriceService for PriceServiceImpl {
     async fn get_prices(&self, player_slug: &str) -> Result<Vec<Price>, PriceError> {
         let price_repo = resolve_trait!(PriceRepo);
 
-        Ok(price_repo.get_prices(player_slug).await?)
+        let prices = price_repo.get_prices(player_slug).await?;
+
+        // Record history (failures do not prevent returning prices)
+        let history_service = resolve_trait!(HistoryService);
+        if let Err(err) = history_service.record_prices(player_slug, &prices).await {
+            log::warn!("Failed to record price history: {}", err);
+        }
+
+        Ok(prices)
+    }
+
+    async fn get_prices_batch(&self, player_slugs: &[String]) -> PricesBatch {
+        let price_repo = resolve_trait!(PriceRepo);
+
+        let batch = price_repo.get_prices_batch(player_slugs).await;
+
+        // Record history (failures do not prevent returning prices)
+        let history_service = resolve_trait!(HistoryService);
+        for (player_slug, player_prices) in &batch.prices {
+            if let Err(err) = history_service
+                .record_prices(player_slug, player_prices)
+                .await
+            {
+                log::warn!("Failed to record price history: {}", err);
+            }
+        }
+
+        batch
     }
 }
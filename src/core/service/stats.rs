@@ -6,6 +6,7 @@ use crate::{
     core::{
         model::stats::Stats,
         repository::{error::RepoError, stats::StatsRepo},
+        service::history::HistoryService,
     },
     resolve_trait,
 };
@@ -41,6 +42,14 @@ impl StatsService for StatsServiceImpl {
     async fn get_stats(&self, player_slugs: &[String]) -> Result<Vec<Stats>, StatsError> {
         let stats_repo = resolve_trait!(StatsRepo);
 
-        Ok(stats_repo.get_stats(player_slugs).await?)
+        let stats = stats_repo.get_stats(player_slugs).await?;
+
+        // Record history (failures do not prevent returning stats)
+        let history_service = resolve_trait!(HistoryService);
+        if let Err(err) = history_service.record_stats(&stats).await {
+            log::warn!("Failed to record stats history: {}", err);
+        }
+
+        Ok(stats)
     }
 }
@@ -1,19 +1,38 @@
 use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
     fmt::Display,
     fs,
     path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
 };
 
 use async_trait::async_trait;
-use regex::Regex;
-use rquickjs::{Context, Function, Runtime};
+use serde_json::{from_str, from_value, to_value, Map, Value};
+use tokio::sync::Mutex;
 
-use crate::core::{
-    model::{
-        decision::{Decision, ScriptDecision},
-        player::Player,
+use crate::{
+    core::{
+        engine::{
+            default_engines, engine_for, LogRateLimiter, ScriptInput, ScriptLogger, StrategyEngine,
+        },
+        metrics::metrics,
+        model::{
+            context::StrategyContext,
+            decision::Decision,
+            ignore::{IgnoreRules, IGNORE_FILE},
+            params::ParamValues,
+            player::Player,
+            requirement::{default_requirements, missing_reason, Requirements},
+            strategy::StrategyInfo,
+            trace::{EvaluationTrace, TraceOutcome},
+        },
+        repository::{error::RepoError, storage::StorageRepo},
     },
-    repository::error::RepoError,
+    resolve_trait,
 };
 
 #[derive(Debug)]
@@ -39,92 +58,768 @@ impl From<RepoError> for StrategyError {
     }
 }
 
-impl From<rquickjs::Error> for StrategyError {
-    fn from(e: rquickjs::Error) -> Self {
-        Self::Script(e.to_string())
-    }
-}
-
 #[async_trait]
 pub trait StrategyService {
-    async fn run_all(&self, player: &Player) -> Result<Vec<Decision>, StrategyError>;
+    async fn run_all(
+        &self,
+        player: &Player,
+        context: &StrategyContext,
+    ) -> Result<Vec<Decision>, StrategyError>;
+
+    /// Lists strategy scripts (each followed by its parameter sets), with their enabled status.
+    async fn list(&self) -> Result<Vec<StrategyInfo>, StrategyError>;
+
+    /// Enables or disables a strategy, or a single parameter set (`<name>:<set>`).
+    /// Disabled strategies are not run.
+    async fn set_enabled(&self, strategy_name: &str, enabled: bool) -> Result<(), StrategyError>;
+
+    /// Traces of the last evaluation of each strategy on a player.
+    fn traces(&self, player_slug: &str) -> Vec<EvaluationTrace>;
+
+    /// Player data required by each enabled strategy.
+    async fn requirements(&self) -> Result<Vec<Requirements>, StrategyError>;
+
+    /// Parameter values of a strategy and its parameter sets, by strategy name
+    /// (known once the strategy is run).
+    fn params(&self, strategy_name: &str) -> Vec<(String, ParamValues)>;
+
+    /// Overrides a parameter of a strategy (or of one of its parameter sets).
+    async fn set_param(
+        &self,
+        strategy_name: &str,
+        param: &str,
+        value: Value,
+    ) -> Result<(), StrategyError>;
 }
 
+/// Storage collection holding strategies state
+const STATE_COLLECTION: &str = "strategies_state";
+
+/// Storage collection holding disabled strategy names
+const DISABLED_COLLECTION: &str = "strategies_disabled";
+
+/// Storage collection holding parameters overridden from the UI, by strategy name
+const PARAMS_COLLECTION: &str = "strategies_params";
+
+/// Type definitions of the strategy API, for TypeScript strategies
+pub const TYPE_DEFINITIONS: &str = include_str!("../js/socli.d.ts");
+pub const TYPE_DEFINITIONS_FILE: &str = "socli.d.ts";
+
+/// Maximum log messages per strategy, per `LOG_RATE_WINDOW`
+const LOG_RATE_MAX_MESSAGES: u32 = 50;
+const LOG_RATE_WINDOW: Duration = Duration::from_secs(60);
+
 pub struct StrategyServiceImpl {
     pub strategy_dir: String,
+    /// Strategies state by strategy name (lazily loaded)
+    states: Mutex<Option<Map<String, Value>>>,
+    /// Strategies state changed since last saved
+    states_changed: AtomicBool,
+    /// Disabled strategy names (lazily loaded)
+    disabled: Mutex<Option<BTreeSet<String>>>,
+    /// Parameters overridden from the UI, by strategy name (lazily loaded)
+    param_overrides: Mutex<Option<BTreeMap<String, ParamValues>>>,
+    /// Parameter values of the last evaluation, by strategy name
+    params: std::sync::Mutex<BTreeMap<String, ParamValues>>,
+    /// Persist strategies state and enabled status to storage
+    persist_state: bool,
+    /// Script log rate limiter
+    log_limiter: Arc<LogRateLimiter>,
+    /// Script engines (selected by file extension)
+    engines: Vec<Box<dyn StrategyEngine>>,
+    /// Last evaluation traces, by player slug and strategy name
+    traces: std::sync::Mutex<HashMap<String, BTreeMap<String, EvaluationTrace>>>,
 }
 
 impl StrategyServiceImpl {
     pub fn new(strategy_dir: &str) -> Self {
         Self {
             strategy_dir: strategy_dir.to_string(),
+            states: Mutex::new(None),
+            states_changed: AtomicBool::new(false),
+            disabled: Mutex::new(None),
+            param_overrides: Mutex::new(None),
+            params: std::sync::Mutex::new(BTreeMap::new()),
+            persist_state: true,
+            log_limiter: Arc::new(LogRateLimiter::new(LOG_RATE_MAX_MESSAGES, LOG_RATE_WINDOW)),
+            engines: default_engines(),
+            traces: std::sync::Mutex::new(HashMap::new()),
         }
     }
 
+    /// Keeps strategies state and enabled status in memory only.
+    pub fn ephemeral(mut self) -> Self {
+        self.persist_state = false;
+        self
+    }
+
     async fn run(
         &self,
         player: &Player,
+        context: &StrategyContext,
+        path: &Path,
         strategy_name: &str,
         code: &str,
+        overrides: &ParamValues,
+        saved_params: &ParamValues,
     ) -> Result<Option<Decision>, StrategyError> {
-        // If missing player prices and stats: skip eval
-        if player.prices.is_empty() || player.stats.is_none() {
+        let engine = engine_for(&self.engines, path)
+            .ok_or_else(|| StrategyError::Config(format!("no engine for `{}`", path.display())))?;
+
+        // If missing player data required by the strategy: skip eval
+        let requirements = match engine.requirements(strategy_name, code) {
+            Ok(requirements) => requirements.unwrap_or_else(default_requirements),
+            Err(e) => {
+                self.failed(player, strategy_name, Duration::ZERO, &e);
+                return Err(e);
+            }
+        };
+        let missing = player.missing_data(&requirements);
+        if !missing.is_empty() {
+            let reason = skipped(&missing_reason(&missing));
+            self.trace(player, strategy_name, Duration::ZERO, None, reason);
             return Ok(None);
         }
 
-        let rt = Runtime::new().unwrap();
-        let ctx = Context::full(&rt).unwrap();
-        ctx.with(|ctx| {
-            let module = ctx.compile("strategy".to_string(), code)?;
-            let decide: Function = module.get("decide")?;
-            let res: Option<ScriptDecision> = decide.call((player.clone(), &player.slug))?;
-            if let Some(decision) = res {
-                Ok(Some(decision.to_decision(&player, strategy_name)))
-            } else {
-                Ok(None)
+        // Strategies state is not locked during evaluation (strategies are run concurrently)
+        let state = self.state(strategy_name).await?;
+
+        let start = Instant::now();
+        let result = engine.run(
+            &ScriptInput {
+                strategy_name,
+                path,
+                code,
+                player,
+                context,
+                overrides,
+                saved_params,
+                state: &state,
+            },
+            ScriptLogger::new(strategy_name, &player.slug, self.log_limiter.clone()),
+        );
+        let duration = start.elapsed();
+        metrics().strategy_run(strategy_name, duration);
+        let output = match result {
+            Ok(output) => output,
+            Err(e) => {
+                self.failed(player, strategy_name, duration, &e);
+                return Err(e);
+            }
+        };
+        let decision = output
+            .decision
+            .map(|d| d.to_decision(player, strategy_name));
+        let outcome = match &decision {
+            Some(d) => {
+                metrics().decision(strategy_name, &d.action);
+                TraceOutcome::Decision {
+                    action: d.action.clone(),
+                    comment: d.comment.clone(),
+                }
             }
-        })
+            None => TraceOutcome::NoDecision,
+        };
+        self.trace(player, strategy_name, duration, output.value, outcome);
+        // Saved values not applied anymore (parameter removed from the script, or of another type)
+        let stale = saved_params
+            .iter()
+            .filter(|(name, value)| output.params.get(*name) != Some(value))
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        if !stale.is_empty() {
+            self.drop_param_overrides(strategy_name, &stale).await?;
+        }
+        if let Ok(mut params) = self.params.lock() {
+            params.insert(strategy_name.to_string(), output.params);
+        }
+
+        // Update state if changed (saved once strategies are run)
+        if let Some(new_state) = output.state {
+            if new_state != state {
+                let mut states = self.states.lock().await;
+                let current = states
+                    .get_or_insert_with(Map::new)
+                    .entry(strategy_name.to_string())
+                    .or_insert_with(|| Value::Object(Map::new()));
+                merge_state(current, &state, new_state);
+                self.states_changed.store(true, Ordering::SeqCst);
+            }
+        }
+
+        Ok(decision)
     }
 
-    pub(crate) fn extract_strategy_name(&self, path: &Path) -> String {
-        let path_name = path.file_name().unwrap().to_str().unwrap().to_string();
-        let re = Regex::new(r"(.*)\.[^.]+$").unwrap();
-        let caps = re.captures(&path_name).unwrap();
-        let result = caps.get(1).map_or("", |m| m.as_str()).to_string();
+    /// State of a strategy (empty object if none).
+    async fn state(&self, strategy_name: &str) -> Result<Value, StrategyError> {
+        let mut states = self.states.lock().await;
+        if states.is_none() {
+            *states = Some(self.load_states().await?);
+        }
+        Ok(states
+            .as_ref()
+            .unwrap()
+            .get(strategy_name)
+            .cloned()
+            .unwrap_or_else(|| Value::Object(Map::new())))
+    }
+
+    /// Saves strategies state, if changed.
+    async fn save_states(&self) -> Result<(), StrategyError> {
+        if !self.persist_state || !self.states_changed.swap(false, Ordering::SeqCst) {
+            return Ok(());
+        }
 
-        result
+        let states = match self.states.lock().await.as_ref() {
+            Some(states) => Value::Object(states.clone()),
+            None => return Ok(()),
+        };
+        let storage_repo = resolve_trait!(StorageRepo);
+        if let Err(e) = storage_repo.set_collection(STATE_COLLECTION, &states).await {
+            self.states_changed.store(true, Ordering::SeqCst);
+            return Err(e.into());
+        }
+        Ok(())
     }
-}
 
-#[async_trait]
-impl StrategyService for StrategyServiceImpl {
-    async fn run_all(&self, player: &Player) -> Result<Vec<Decision>, StrategyError> {
-        log::debug!("Running all strategies on {}", player.slug);
+    /// Records the evaluation trace of a strategy on a player.
+    fn trace(
+        &self,
+        player: &Player,
+        strategy_name: &str,
+        duration: Duration,
+        value: Option<Value>,
+        outcome: TraceOutcome,
+    ) {
+        let trace = EvaluationTrace {
+            strategy: strategy_name.to_string(),
+            player_slug: player.slug.clone(),
+            date: chrono::Utc::now().to_rfc3339(),
+            duration_ms: duration.as_secs_f64() * 1000.0,
+            value,
+            outcome,
+        };
+        if let Ok(mut traces) = self.traces.lock() {
+            traces
+                .entry(player.slug.clone())
+                .or_default()
+                .insert(strategy_name.to_string(), trace);
+        }
+    }
+
+    /// Records a failed evaluation.
+    fn failed(&self, player: &Player, strategy_name: &str, duration: Duration, e: &StrategyError) {
+        metrics().error("strategy");
+        let outcome = TraceOutcome::Error {
+            message: e.to_string(),
+        };
+        self.trace(player, strategy_name, duration, None, outcome);
+    }
+
+    async fn load_states(&self) -> Result<Map<String, Value>, StrategyError> {
+        if !self.persist_state {
+            return Ok(Map::new());
+        }
+
+        let storage_repo = resolve_trait!(StorageRepo);
+        match storage_repo.get_collection(STATE_COLLECTION).await? {
+            Some(Value::Object(states)) => Ok(states),
+            _ => Ok(Map::new()),
+        }
+    }
+
+    async fn load_disabled(&self) -> Result<BTreeSet<String>, StrategyError> {
+        if !self.persist_state {
+            return Ok(BTreeSet::new());
+        }
+
+        let storage_repo = resolve_trait!(StorageRepo);
+        match storage_repo.get_collection(DISABLED_COLLECTION).await? {
+            Some(names) => from_value(names).map_err(|e| StrategyError::Data(e.to_string())),
+            None => Ok(BTreeSet::new()),
+        }
+    }
+
+    /// Names of disabled strategies.
+    async fn disabled(&self) -> Result<BTreeSet<String>, StrategyError> {
+        let mut disabled = self.disabled.lock().await;
+        if disabled.is_none() {
+            *disabled = Some(self.load_disabled().await?);
+        }
+        Ok(disabled.clone().unwrap_or_default())
+    }
+
+    async fn load_param_overrides(&self) -> Result<BTreeMap<String, ParamValues>, StrategyError> {
+        if !self.persist_state {
+            return Ok(BTreeMap::new());
+        }
+
+        let storage_repo = resolve_trait!(StorageRepo);
+        match storage_repo.get_collection(PARAMS_COLLECTION).await? {
+            Some(params) => from_value(params).map_err(|e| StrategyError::Data(e.to_string())),
+            None => Ok(BTreeMap::new()),
+        }
+    }
+
+    /// Parameters overridden from the UI, by strategy name.
+    async fn param_overrides(&self) -> Result<BTreeMap<String, ParamValues>, StrategyError> {
+        let mut overrides = self.param_overrides.lock().await;
+        if overrides.is_none() {
+            *overrides = Some(self.load_param_overrides().await?);
+        }
+        Ok(overrides.clone().unwrap_or_default())
+    }
+
+    /// Drops parameters overridden from the UI, not declared by the strategy anymore.
+    async fn drop_param_overrides(
+        &self,
+        strategy_name: &str,
+        names: &[String],
+    ) -> Result<(), StrategyError> {
+        log::warn!(
+            "Dropping saved parameters of {} not declared anymore: {}",
+            strategy_name,
+            names.join(", ")
+        );
+        let mut overrides = self.param_overrides.lock().await;
+        if let Some(overrides) = overrides.as_mut() {
+            if let Some(values) = overrides.get_mut(strategy_name) {
+                values.retain(|name, _| !names.contains(name));
+                if values.is_empty() {
+                    overrides.remove(strategy_name);
+                }
+            }
+            self.save_param_overrides(overrides).await?;
+        }
+        Ok(())
+    }
+
+    async fn save_param_overrides(
+        &self,
+        overrides: &BTreeMap<String, ParamValues>,
+    ) -> Result<(), StrategyError> {
+        if !self.persist_state {
+            return Ok(());
+        }
+
+        let storage_repo = resolve_trait!(StorageRepo);
+        let overrides = to_value(overrides).map_err(|e| StrategyError::Data(e.to_string()))?;
+        storage_repo
+            .set_collection(PARAMS_COLLECTION, &overrides)
+            .await?;
+        Ok(())
+    }
+
+    /// Loads the parameter sets of a script from its sidecar file (`<name>.params.json`).
+    /// Each parameter set is run as a distinct strategy, named `<name>:<set>`.
+    /// Without sidecar file (or parameter sets), the script runs once, with default parameters.
+    fn load_param_sets(
+        &self,
+        path: &Path,
+        strategy_name: &str,
+    ) -> Result<Vec<(String, ParamValues)>, StrategyError> {
+        let default_set = || vec![(strategy_name.to_string(), ParamValues::new())];
+        let sidecar_path = sidecar_path(path, "params.json");
+        if !sidecar_path.exists() {
+            return Ok(default_set());
+        }
+
+        let json = fs::read_to_string(&sidecar_path).map_err(|e| {
+            StrategyError::Config(format!(
+                "failed to read `{}`: {}",
+                sidecar_path.display(),
+                e
+            ))
+        })?;
+        let variants: BTreeMap<String, ParamValues> = from_str(&json).map_err(|e| {
+            StrategyError::Config(format!(
+                "failed to parse `{}`: {}",
+                sidecar_path.display(),
+                e
+            ))
+        })?;
+
+        if variants.is_empty() {
+            return Ok(default_set());
+        }
+
+        Ok(variants
+            .into_iter()
+            .map(|(variant, values)| (format!("{}:{}", strategy_name, variant), values))
+            .collect())
+    }
 
+    /// Runs a single script, with parameters overrides.
+    pub async fn run_script(
+        &self,
+        path: &Path,
+        player: &Player,
+        context: &StrategyContext,
+        overrides: &ParamValues,
+    ) -> Result<Option<Decision>, StrategyError> {
+        let strategy_name = self.extract_strategy_name(path);
+        let code = fs::read_to_string(path).map_err(|e| {
+            StrategyError::Config(format!("failed to read `{}`: {}", path.display(), e))
+        })?;
+
+        let decision = self
+            .run(
+                player,
+                context,
+                path,
+                &strategy_name,
+                &code,
+                overrides,
+                &ParamValues::new(),
+            )
+            .await;
+        self.save_states().await?;
+        decision
+    }
+
+    /// Lists script files in the strategy directory and its sub folders.
+    /// Hidden files and files matching `.socliignore` patterns are skipped.
+    pub fn script_paths(&self) -> Result<Vec<PathBuf>, StrategyError> {
         let scripts_path = PathBuf::from(&self.strategy_dir);
 
-        // Check if file exists
-        if !scripts_path.exists() {
+        // Check if folder exists
+        if !scripts_path.is_dir() {
             return Err(StrategyError::Config(format!(
                 "failed to access directory `{}`",
                 &self.strategy_dir
             )));
         }
 
+        let ignore_rules = match fs::read_to_string(scripts_path.join(IGNORE_FILE)) {
+            Ok(content) => IgnoreRules::parse(&content),
+            Err(_) => IgnoreRules::default(),
+        };
+
+        let mut paths = vec![];
+        self.collect_scripts(&scripts_path, &ignore_rules, &mut paths)?;
+        paths.sort();
+
+        Ok(paths)
+    }
+
+    fn collect_scripts(
+        &self,
+        dir: &Path,
+        ignore_rules: &IgnoreRules,
+        paths: &mut Vec<PathBuf>,
+    ) -> Result<(), StrategyError> {
+        let entries = fs::read_dir(dir).map_err(|e| {
+            StrategyError::Config(format!(
+                "failed to access directory `{}`: {}",
+                dir.display(),
+                e
+            ))
+        })?;
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+
+            // Skip hidden files and folders (such as editor swap files)
+            let is_hidden = path
+                .file_name()
+                .map_or(true, |name| name.to_string_lossy().starts_with('.'));
+            // Symbolic links to folders are not followed
+            let is_dir = entry.file_type().map_or(false, |t| t.is_dir());
+            if is_hidden || ignore_rules.is_ignored(&self.relative_path(&path), is_dir) {
+                continue;
+            }
+
+            if is_dir {
+                self.collect_scripts(&path, ignore_rules, paths)?;
+            } else if self.is_script(&path) {
+                // Non script files (such as parameter files or READMEs) are filtered out
+                paths.push(path);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn is_script(&self, path: &Path) -> bool {
+        let is_declaration = path
+            .file_name()
+            .map_or(false, |name| name.to_string_lossy().ends_with(".d.ts"));
+        !path.is_dir() && !is_declaration && engine_for(&self.engines, path).is_some()
+    }
+
+    /// Path relative to the strategy directory, with `/` separators.
+    fn relative_path(&self, path: &Path) -> String {
+        path.strip_prefix(&self.strategy_dir)
+            .unwrap_or(path)
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Strategy name: script path relative to the strategy directory, without extension.
+    /// Sub folders are namespaces (such as `momentum/v2`).
+    pub(crate) fn extract_strategy_name(&self, path: &Path) -> String {
+        let path = match path.strip_prefix(&self.strategy_dir) {
+            Ok(relative) => relative,
+            // Outside the strategy directory: file name only
+            Err(_) => path.file_name().map_or(path, Path::new),
+        };
+        self.relative_path(&path.with_extension(""))
+    }
+}
+
+/// Applies the changes of a strategy run (from `old` to `new` state) to the current state:
+/// changes of concurrent runs on other keys are kept.
+pub(crate) fn merge_state(current: &mut Value, old: &Value, new: Value) {
+    match (current, old, new) {
+        (Value::Object(current), Value::Object(old), Value::Object(new)) => {
+            for key in old.keys().filter(|k| !new.contains_key(*k)) {
+                current.remove(key);
+            }
+            for (key, value) in new {
+                if old.get(&key) != Some(&value) {
+                    current.insert(key, value);
+                }
+            }
+        }
+        (current, _, new) => *current = new,
+    }
+}
+
+fn skipped(reason: &str) -> TraceOutcome {
+    TraceOutcome::Skipped {
+        reason: reason.to_string(),
+    }
+}
+
+/// Path of a file next to a script, sharing its name (such as `<name>.params.json`).
+pub(crate) fn sidecar_path(path: &Path, suffix: &str) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map_or_else(String::new, |s| s.to_string_lossy().to_string());
+    path.with_file_name(format!("{}.{}", stem, suffix))
+}
+
+#[async_trait]
+impl StrategyService for StrategyServiceImpl {
+    async fn run_all(
+        &self,
+        player: &Player,
+        context: &StrategyContext,
+    ) -> Result<Vec<Decision>, StrategyError> {
+        log::debug!("Running all strategies on {}", player.slug);
+
+        let disabled = self.disabled().await?;
+        let param_overrides = self.param_overrides().await?;
         let mut decisions = vec![];
-        let paths = fs::read_dir(scripts_path).unwrap();
-        for path in paths {
-            // Filter out sub directories
-            if !path.as_ref().unwrap().path().is_dir() {
-                let strategy_name = self.extract_strategy_name(&path.as_ref().unwrap().path());
-                if let Ok(code) = fs::read_to_string(path.unwrap().path()) {
-                    if let Some(decision) = self.run(player, &strategy_name, &code).await? {
-                        decisions.push(decision);
+        for path in self.script_paths()? {
+            let strategy_name = self.extract_strategy_name(&path);
+            if disabled.contains(&strategy_name) {
+                let reason = skipped("disabled strategy");
+                self.trace(player, &strategy_name, Duration::ZERO, None, reason);
+                continue;
+            }
+            if let Ok(code) = fs::read_to_string(&path) {
+                // A failing strategy does not prevent other strategies from running
+                let variants = match self.load_param_sets(&path, &strategy_name) {
+                    Ok(variants) => variants,
+                    Err(e) => {
+                        log::error!("Failed to load {} parameters: {}", strategy_name, e);
+                        self.failed(player, &strategy_name, Duration::ZERO, &e);
+                        continue;
+                    }
+                };
+                for (name, overrides) in variants {
+                    // Parameter sets can be disabled on their own
+                    if disabled.contains(&name) {
+                        let reason = skipped("disabled strategy");
+                        self.trace(player, &name, Duration::ZERO, None, reason);
+                        continue;
+                    }
+                    let saved_params = param_overrides.get(&name).cloned().unwrap_or_default();
+                    match self
+                        .run(
+                            player,
+                            context,
+                            &path,
+                            &name,
+                            &code,
+                            &overrides,
+                            &saved_params,
+                        )
+                        .await
+                    {
+                        Ok(Some(decision)) => decisions.push(decision),
+                        Ok(None) => (),
+                        Err(e) => log::error!("Failed to run {} on {}: {}", name, player.slug, e),
                     }
                 }
             }
         }
+        self.save_states().await?;
 
         Ok(decisions)
     }
+
+    async fn list(&self) -> Result<Vec<StrategyInfo>, StrategyError> {
+        let disabled = self.disabled().await?;
+        let mut strategies = vec![];
+        for path in self.script_paths()? {
+            let name = self.extract_strategy_name(&path);
+            let info = StrategyInfo {
+                enabled: !disabled.contains(&name),
+                path: self.relative_path(&path),
+                engine: engine_for(&self.engines, &path)
+                    .map_or("", |e| e.name())
+                    .to_string(),
+                name: name.clone(),
+            };
+
+            // Followed by its parameter sets (`<name>:<set>`)
+            let sets = self
+                .load_param_sets(&path, &name)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|(set, _)| *set != name)
+                .map(|(set, _)| StrategyInfo {
+                    enabled: !disabled.contains(&set),
+                    name: set,
+                    ..info.clone()
+                })
+                .collect::<Vec<_>>();
+            strategies.push(info);
+            strategies.extend(sets);
+        }
+
+        Ok(strategies)
+    }
+
+    async fn set_enabled(&self, strategy_name: &str, enabled: bool) -> Result<(), StrategyError> {
+        // A strategy, or one of its parameter sets
+        let exists = self.script_paths()?.iter().any(|path| {
+            let name = self.extract_strategy_name(path);
+            name == strategy_name
+                || (strategy_name.starts_with(&format!("{}:", name))
+                    && self.load_param_sets(path, &name).map_or(false, |sets| {
+                        sets.iter().any(|(set, _)| set == strategy_name)
+                    }))
+        });
+        if !exists {
+            return Err(StrategyError::Config(format!(
+                "unknown strategy `{}`",
+                strategy_name
+            )));
+        }
+
+        let mut disabled = self.disabled.lock().await;
+        if disabled.is_none() {
+            *disabled = Some(self.load_disabled().await?);
+        }
+        let names = disabled.as_mut().unwrap();
+        if enabled {
+            names.remove(strategy_name);
+        } else {
+            names.insert(strategy_name.to_string());
+        }
+
+        if self.persist_state {
+            let storage_repo = resolve_trait!(StorageRepo);
+            let names = to_value(&*names).map_err(|e| StrategyError::Data(e.to_string()))?;
+            storage_repo
+                .set_collection(DISABLED_COLLECTION, &names)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    fn traces(&self, player_slug: &str) -> Vec<EvaluationTrace> {
+        match self.traces.lock() {
+            Ok(traces) => traces
+                .get(player_slug)
+                .map(|t| t.values().cloned().collect())
+                .unwrap_or_default(),
+            Err(_) => vec![],
+        }
+    }
+
+    async fn requirements(&self) -> Result<Vec<Requirements>, StrategyError> {
+        let disabled = self.disabled().await?;
+        let mut requirements = vec![];
+        for path in self.script_paths()? {
+            let strategy_name = self.extract_strategy_name(&path);
+            if disabled.contains(&strategy_name) {
+                continue;
+            }
+            if let (Some(engine), Ok(code)) =
+                (engine_for(&self.engines, &path), fs::read_to_string(&path))
+            {
+                requirements.push(
+                    engine
+                        .requirements(&strategy_name, &code)?
+                        .unwrap_or_else(default_requirements),
+                );
+            }
+        }
+
+        Ok(requirements)
+    }
+
+    fn params(&self, strategy_name: &str) -> Vec<(String, ParamValues)> {
+        let set_prefix = format!("{}:", strategy_name);
+        match self.params.lock() {
+            Ok(params) => params
+                .iter()
+                .filter(|(name, _)| *name == strategy_name || name.starts_with(&set_prefix))
+                .map(|(name, values)| (name.clone(), values.clone()))
+                .collect(),
+            Err(_) => vec![],
+        }
+    }
+
+    async fn set_param(
+        &self,
+        strategy_name: &str,
+        param: &str,
+        value: Value,
+    ) -> Result<(), StrategyError> {
+        // Only parameters of evaluated strategies are known, with their type
+        {
+            let mut params = self
+                .params
+                .lock()
+                .map_err(|e| StrategyError::Data(e.to_string()))?;
+            let current = params
+                .get_mut(strategy_name)
+                .and_then(|values| values.get_mut(param))
+                .ok_or_else(|| {
+                    StrategyError::Config(format!(
+                        "unknown parameter `{}` of strategy `{}`",
+                        param, strategy_name
+                    ))
+                })?;
+            let same_type = (current.is_number() && value.is_number())
+                || (current.is_string() && value.is_string())
+                || (current.is_boolean() && value.is_boolean());
+            if !same_type {
+                return Err(StrategyError::Config(format!(
+                    "invalid value `{}` for parameter `{}` of strategy `{}`",
+                    value, param, strategy_name
+                )));
+            }
+            *current = value.clone();
+        }
+
+        let mut overrides = self.param_overrides.lock().await;
+        if overrides.is_none() {
+            *overrides = Some(self.load_param_overrides().await?);
+        }
+        let overrides = overrides.as_mut().unwrap();
+        overrides
+            .entry(strategy_name.to_string())
+            .or_default()
+            .insert(param.to_string(), value);
+        self.save_param_overrides(overrides).await
+    }
 }
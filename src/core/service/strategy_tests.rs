@@ -1,6 +1,49 @@
-use std::path::Path;
+use std::{fs, path::Path, time::Duration};
 
-use crate::core::service::strategy::StrategyServiceImpl;
+use serde_json::json;
+use tempfile::TempDir;
+
+use crate::core::{
+    engine::{LogPermit, LogRateLimiter},
+    model::{
+        context::StrategyContext,
+        params::ParamValues,
+        player::Player,
+        player_builder::PlayerBuilder,
+        requirement::{DataRequirement, Requirements},
+        trace::TraceOutcome,
+    },
+    service::strategy::{
+        merge_state, StrategyService, StrategyServiceImpl, TYPE_DEFINITIONS, TYPE_DEFINITIONS_FILE,
+    },
+};
+
+fn create_player() -> Player {
+    PlayerBuilder::new("slug")
+        .team("team")
+        .price("2023-07-22T16:15:13Z", "40", "50")
+        .score(40)
+        .build()
+}
+
+/// Creates a strategy folder in the temp directory, containing a single script.
+/// The folder is removed when the returned guard is dropped.
+fn create_strategy_dir(name: &str, code: &str) -> TempDir {
+    let dir = TempDir::new().unwrap();
+    create_strategy_file(&dir, &format!("{}.js", name), code);
+    dir
+}
+
+/// Creates a file in a strategy folder (`file_name` may include sub folders).
+fn create_strategy_file(dir: &TempDir, file_name: &str, code: &str) {
+    let path = dir.path().join(file_name);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, code).unwrap();
+}
+
+fn create_strategy_service(dir: &TempDir) -> StrategyServiceImpl {
+    StrategyServiceImpl::new(dir.path().to_str().unwrap()).ephemeral()
+}
 
 #[tokio::test]
 async fn parse_player_slug() {
@@ -9,4 +52,399 @@ async fn parse_player_slug() {
 
     let strategy_service = StrategyServiceImpl::new("fake");
     assert_eq!(strategy_service.extract_strategy_name(&path), "test");
+}
+
+#[test]
+fn strategy_names() {
+    let strategy_service = StrategyServiceImpl::new("strats");
+    let name = |path: &str| strategy_service.extract_strategy_name(Path::new(path));
+
+    assert_eq!(name("strats/momentum/v2.js"), "momentum/v2");
+    assert_eq!(name("strats/trending.v1.ts"), "trending.v1");
+    // Files without extension
+    assert_eq!(name("strats/README"), "README");
+    assert_eq!(name("other/test.lua"), "test");
+}
+
+#[tokio::test]
+async fn recursive_discovery() {
+    let dir = TempDir::new().unwrap();
+    let code = r#"export function decide() { return { action: "Buy", comment: "" }; }"#;
+    create_strategy_file(&dir, "root.js", code);
+    create_strategy_file(&dir, "momentum/v2.js", code);
+    create_strategy_file(&dir, "momentum/drafts/v3.js", code);
+    create_strategy_file(&dir, "draft.wip.js", code);
+    create_strategy_file(&dir, ".root.js.swp", code);
+    create_strategy_file(&dir, "README.md", "# Strategies");
+    create_strategy_file(&dir, ".socliignore", "# Ignored\n*.wip.js\ndrafts/\n");
+
+    let strategy_service = create_strategy_service(&dir);
+    let names = strategy_service
+        .list()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|s| s.name)
+        .collect::<Vec<String>>();
+    assert_eq!(names, vec!["momentum/v2", "root"]);
+}
+
+#[tokio::test]
+async fn disabled_strategy() {
+    let dir = create_strategy_dir(
+        "disabled",
+        r#"export function decide() { return { action: "Buy", comment: "" }; }"#,
+    );
+
+    let strategy_service = create_strategy_service(&dir);
+    let player = create_player();
+    let context = StrategyContext::new(&[player.clone()]);
+
+    strategy_service.set_enabled("disabled", false).await.unwrap();
+    assert!(!strategy_service.list().await.unwrap()[0].enabled);
+    assert!(strategy_service
+        .run_all(&player, &context)
+        .await
+        .unwrap()
+        .is_empty());
+
+    strategy_service.set_enabled("disabled", true).await.unwrap();
+    assert_eq!(
+        strategy_service.run_all(&player, &context).await.unwrap().len(),
+        1
+    );
+
+    assert!(strategy_service.set_enabled("unknown", false).await.is_err());
+}
+
+#[tokio::test]
+async fn disabled_param_set() {
+    let dir = TempDir::new().unwrap();
+    create_strategy_file(
+        &dir,
+        "sets.lua",
+        r#"
+        params = { min = { type = "number", default = 1 } }
+        function decide(player, slug, context, params)
+            return { action = "Buy", comment = tostring(params.min) }
+        end
+        "#,
+    );
+    create_strategy_file(
+        &dir,
+        "sets.params.json",
+        r#"{ "low": { "min": 1 }, "high": { "min": 2 } }"#,
+    );
+
+    let strategy_service = create_strategy_service(&dir);
+    let player = create_player();
+    let context = StrategyContext::new(&[player.clone()]);
+
+    strategy_service.set_enabled("sets:low", false).await.unwrap();
+    let strategies = strategy_service.list().await.unwrap();
+    assert_eq!(
+        strategies
+            .iter()
+            .map(|s| (s.name.as_str(), s.enabled))
+            .collect::<Vec<_>>(),
+        vec![("sets", true), ("sets:high", true), ("sets:low", false)]
+    );
+    let decisions = strategy_service.run_all(&player, &context).await.unwrap();
+    assert_eq!(decisions.len(), 1);
+    assert_eq!(decisions[0].strategy, "sets:high");
+
+    assert!(strategy_service.set_enabled("sets:medium", false).await.is_err());
+}
+
+#[tokio::test]
+async fn state_kept_between_runs() {
+    let dir = create_strategy_dir(
+        "state",
+        r#"
+        export function decide(player, slug) {
+            const count = state.get(slug, 0) + 1;
+            state.set(slug, count);
+            return { action: "Buy", comment: `${count}` };
+        }
+        "#,
+    );
+
+    let strategy_service = create_strategy_service(&dir);
+    let player = create_player();
+    let context = StrategyContext::new(&[player.clone()]);
+
+    let decisions = strategy_service.run_all(&player, &context).await.unwrap();
+    assert_eq!(decisions[0].comment, "1");
+    let decisions = strategy_service.run_all(&player, &context).await.unwrap();
+    assert_eq!(decisions[0].comment, "2");
+}
+
+#[test]
+fn concurrent_state_changes_merged() {
+    let mut current = json!({ "a": 1, "b": 2, "c": 3 });
+
+    // Run started before `b` was set to 2 by another run
+    merge_state(
+        &mut current,
+        &json!({ "a": 1, "b": 1, "c": 3 }),
+        json!({ "a": 2, "b": 1 }),
+    );
+
+    assert_eq!(current, json!({ "a": 2, "b": 2 }));
+}
+
+#[tokio::test]
+async fn helper_library() {
+    let dir = create_strategy_dir(
+        "helpers",
+        r#"
+        import { price_avg, moving_average } from "socli";
+
+        export function decide(player) {
+            const avg = moving_average([1, 2, 3], 2);
+            return { action: "Buy", comment: `${price_avg(player, 5)} ${avg}` };
+        }
+        "#,
+    );
+
+    let strategy_service = create_strategy_service(&dir);
+    let player = create_player();
+    let context = StrategyContext::new(&[player.clone()]);
+
+    let decisions = strategy_service.run_all(&player, &context).await.unwrap();
+    assert_eq!(decisions[0].comment, "40 1.5,2.5");
+}
+
+#[tokio::test]
+async fn typescript_strategy() {
+    let dir = TempDir::new().unwrap();
+    create_strategy_file(
+        &dir,
+        "typescript.ts",
+        r#"
+        import { price_avg } from "socli";
+
+        export function decide(player: Player, slug: string): Decision | undefined {
+            const avg: number = price_avg(player, 5) as number;
+            return { action: "Buy", comment: `${slug} ${avg}` };
+        }
+        "#,
+    );
+    // Type definitions are not run as a strategy
+    create_strategy_file(&dir, TYPE_DEFINITIONS_FILE, TYPE_DEFINITIONS);
+
+    let strategy_service = create_strategy_service(&dir);
+    let player = create_player();
+    let context = StrategyContext::new(&[player.clone()]);
+
+    let decisions = strategy_service.run_all(&player, &context).await.unwrap();
+    assert_eq!(decisions.len(), 1);
+    assert_eq!(decisions[0].strategy, "typescript");
+    assert_eq!(decisions[0].comment, "slug 40");
+}
+
+#[tokio::test]
+async fn failing_strategy_does_not_stop_others() {
+    let dir = TempDir::new().unwrap();
+    create_strategy_file(&dir, "a_invalid.rhai", "fn decide(player) {");
+    create_strategy_file(
+        &dir,
+        "b_invalid_params.rhai",
+        r#"
+        let params = #{ min: #{ type: "number", "default": 1 } };
+        fn decide(player, slug, context, params) { #{ action: "Sell", comment: "" } }
+        "#,
+    );
+    create_strategy_file(&dir, "b_invalid_params.params.json", "{ \"min\": \"high\" }");
+    create_strategy_file(
+        &dir,
+        "c_valid.rhai",
+        r#"fn decide(player, slug, context, params) { #{ action: "Buy", comment: slug } }"#,
+    );
+
+    let strategy_service = create_strategy_service(&dir);
+    let player = create_player();
+    let context = StrategyContext::new(&[player.clone()]);
+
+    let decisions = strategy_service.run_all(&player, &context).await.unwrap();
+    assert_eq!(decisions.len(), 1);
+    assert_eq!(decisions[0].strategy, "c_valid");
+    let traces = strategy_service.traces("slug");
+    assert_eq!(traces.len(), 3);
+    assert!(traces[..2]
+        .iter()
+        .all(|t| matches!(t.outcome, TraceOutcome::Error { .. })));
+}
+
+#[tokio::test]
+async fn param_overrides() {
+    let dir = TempDir::new().unwrap();
+    const MIN_SCRIPT: &str = r#"
+        params = { min = { type = "number", default = 1 } }
+        function decide(player, slug, context, params)
+            return { action = "Buy", comment = tostring(params.min) }
+        end
+        "#;
+    create_strategy_file(&dir, "min.lua", MIN_SCRIPT);
+    // Empty sidecar: default parameters
+    create_strategy_file(&dir, "none.lua", "function decide() end");
+    create_strategy_file(&dir, "none.params.json", "{}");
+
+    let strategy_service = create_strategy_service(&dir);
+    let player = create_player();
+    let context = StrategyContext::new(&[player.clone()]);
+
+    // Parameters are known once run
+    assert!(strategy_service.set_param("min", "min", json!(2)).await.is_err());
+    let decisions = strategy_service.run_all(&player, &context).await.unwrap();
+    assert_eq!(decisions[0].comment, "1");
+    assert_eq!(
+        strategy_service.params("none"),
+        vec![("none".to_string(), ParamValues::new())]
+    );
+
+    assert!(strategy_service.set_param("min", "min", json!("high")).await.is_err());
+    strategy_service.set_param("min", "min", json!(2)).await.unwrap();
+    let decisions = strategy_service.run_all(&player, &context).await.unwrap();
+    assert_eq!(decisions[0].comment, "2");
+
+    // Saved value of a parameter removed from the script: dropped
+    create_strategy_file(
+        &dir,
+        "min.lua",
+        r#"
+        params = { max = { type = "number", default = 3 } }
+        function decide(player, slug, context, params)
+            return { action = "Buy", comment = tostring(params.max) }
+        end
+        "#,
+    );
+    let decisions = strategy_service.run_all(&player, &context).await.unwrap();
+    assert_eq!(decisions[0].comment, "3");
+    assert!(strategy_service.set_param("min", "min", json!(2)).await.is_err());
+    create_strategy_file(&dir, "min.lua", MIN_SCRIPT);
+    let decisions = strategy_service.run_all(&player, &context).await.unwrap();
+    assert_eq!(decisions[0].comment, "1");
+}
+
+#[tokio::test]
+async fn evaluation_traces() {
+    let dir = TempDir::new().unwrap();
+    create_strategy_file(
+        &dir,
+        "nothing.js",
+        r#"export function decide(player) { if (player.number > 50) { throw new Error("boom"); } }"#,
+    );
+
+    let strategy_service = create_strategy_service(&dir);
+    let player = create_player();
+    let context = StrategyContext::new(&[player.clone()]);
+
+    // No decision
+    strategy_service.run_all(&player, &context).await.unwrap();
+    let traces = strategy_service.traces("slug");
+    assert_eq!(traces.len(), 1);
+    assert_eq!(traces[0].strategy, "nothing");
+    assert_eq!(traces[0].outcome, TraceOutcome::NoDecision);
+    assert!(traces[0].value.is_none());
+
+    // Error
+    let mut failing_player = player.clone();
+    failing_player.number = 99;
+    assert!(strategy_service
+        .run_all(&failing_player, &context)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(matches!(
+        &strategy_service.traces("slug")[0].outcome,
+        TraceOutcome::Error { message } if message.contains("boom")
+    ));
+
+    // Skipped
+    let mut skipped_player = player.clone();
+    skipped_player.prices.clear();
+    strategy_service
+        .run_all(&skipped_player, &context)
+        .await
+        .unwrap();
+    assert_eq!(
+        strategy_service.traces("slug")[0].outcome,
+        TraceOutcome::Skipped {
+            reason: "missing prices".to_string()
+        }
+    );
+}
+
+#[tokio::test]
+async fn required_data() {
+    let dir = TempDir::new().unwrap();
+    create_strategy_file(
+        &dir,
+        "bio.js",
+        "// @requires none\nexport function decide(player) { return { action: \"Buy\", comment: player.country }; }",
+    );
+    create_strategy_file(
+        &dir,
+        "injury.js",
+        "// @requires injury\nexport function decide(player) { return { action: \"Sell\", comment: \"\" }; }",
+    );
+
+    let strategy_service = create_strategy_service(&dir);
+    let mut player = create_player();
+    player.prices.clear();
+    player.stats = None;
+    let context = StrategyContext::new(&[player.clone()]);
+
+    // Strategies are gated on their own requirements
+    let decisions = strategy_service.run_all(&player, &context).await.unwrap();
+    assert_eq!(decisions.len(), 1);
+    assert_eq!(decisions[0].strategy, "bio");
+    assert!(strategy_service.traces("slug").iter().any(|t| t.strategy == "injury"
+        && t.outcome
+            == TraceOutcome::Skipped {
+                reason: "missing injury".to_string()
+            }));
+
+    player.injury_loaded = true;
+    let decisions = strategy_service.run_all(&player, &context).await.unwrap();
+    assert_eq!(decisions.len(), 2);
+
+    assert_eq!(
+        strategy_service.requirements().await.unwrap(),
+        vec![
+            Requirements::new(),
+            Requirements::from([DataRequirement::Injury])
+        ]
+    );
+}
+
+#[test]
+fn type_definitions_cover_player() {
+    let player = serde_json::to_value(create_player()).unwrap();
+    let start = TYPE_DEFINITIONS.find("interface Player {").unwrap();
+    let end = start + TYPE_DEFINITIONS[start..].find('}').unwrap();
+    for field in player.as_object().unwrap().keys() {
+        assert!(
+            TYPE_DEFINITIONS[start..end].contains(&format!("  {}:", field)),
+            "missing player field `{}`",
+            field
+        );
+    }
+}
+
+#[test]
+fn log_rate_limit() {
+    let limiter = LogRateLimiter::new(2, Duration::from_secs(60));
+    assert_eq!(limiter.acquire("a"), LogPermit::Allowed);
+    assert_eq!(limiter.acquire("a"), LogPermit::Allowed);
+    assert_eq!(limiter.acquire("a"), LogPermit::LimitReached);
+    assert_eq!(limiter.acquire("a"), LogPermit::Dropped);
+    // Limits are per strategy
+    assert_eq!(limiter.acquire("b"), LogPermit::Allowed);
+
+    // Limits are reset on new window
+    let limiter = LogRateLimiter::new(1, Duration::ZERO);
+    assert_eq!(limiter.acquire("a"), LogPermit::Allowed);
+    assert_eq!(limiter.acquire("a"), LogPermit::Allowed);
 }
\ No newline at end of file
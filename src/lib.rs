@@ -1,2 +1,5 @@
 pub mod app;
+pub mod cli;
 pub mod core;
+pub mod output;
+pub mod server;
@@ -1,34 +1,148 @@
 use std::{
     io::{self},
+    net::SocketAddr,
+    path::{Path, PathBuf},
     sync::Arc,
     time::Duration,
 };
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use crossterm::{execute, terminal::LeaveAlternateScreen};
+use dirs::home_dir;
 use log::LevelFilter;
+use ratatui::{backend::CrosstermBackend, Terminal};
 use socli::{
     app::{
+        daemon,
         input::{events::Events, InputEvent},
         io::{handler::IoAsyncHandler, IoEvent},
         ui::{check_window_size, draw},
         App, AppReturn,
     },
-    core::{service::player::PlayerService, setup_container},
-    resolve_trait,
+    cli::{self, run_command},
+    core::{
+        model::config::{Config, ConfigError, CONFIG_FILE},
+        service::{notification::NotificationServiceImpl, player::PlayerService},
+        setup_container,
+    },
+    output::OutputFormat,
+    resolve, resolve_trait, server,
 };
-use ratatui::{backend::CrosstermBackend, Terminal};
 
 /// SoCli - A Sorare NBA 🏀 CLI
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
-    /// Strategy scripts folder path
+    /// Strategy scripts folder path [default: ~/.socli/strategies]
     #[arg(short, long)]
-    strategies: String,
+    strategies: Option<String>,
     /// Reset stored data
     #[clap(long, short, action)]
     reset: bool,
+    /// Directory of tables exported from the UI
+    #[arg(long, default_value = ".")]
+    export_dir: PathBuf,
+    /// Serve the HTTP API on this address (such as `127.0.0.1:8080`), with the UI or in daemon mode
+    #[arg(long)]
+    serve: Option<SocketAddr>,
+    /// Serve Prometheus metrics on this address (such as `127.0.0.1:9090`), on `/metrics`
+    #[arg(long)]
+    metrics: Option<SocketAddr>,
+    /// Settings file [default: ~/.socli/config.toml]
+    #[arg(long)]
+    config: Option<PathBuf>,
+    /// Override a setting (such as `refresh.interval_secs=5`), can be repeated
+    #[arg(long = "set", value_name = "KEY=VALUE")]
+    settings: Vec<String>,
+    /// Output format of commands
+    #[arg(long, short, value_enum, default_value_t = OutputFormat::Table)]
+    output: OutputFormat,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run without UI: refresh players and run strategies until stopped (SIGTERM or Ctrl-C)
+    Daemon {
+        /// Log debug messages
+        #[clap(long, action)]
+        verbose: bool,
+    },
+    #[command(flatten)]
+    Cli(cli::Command),
+}
+
+/// Creates the app, with its IO events handled in a specific thread (and its HTTP API and metrics served if set)
+fn start_app(
+    export_dir: PathBuf,
+    serve: Option<SocketAddr>,
+    metrics: Option<SocketAddr>,
+) -> Arc<tokio::sync::Mutex<App>> {
+    // Create a channel for IoEvent
+    let (sync_io_tx, mut sync_io_rx) = tokio::sync::mpsc::channel::<IoEvent>(100);
+
+    // Create app
+    let app = Arc::new(tokio::sync::Mutex::new(App::new(sync_io_tx.clone())));
+    let app_io = Arc::clone(&app);
+
+    // Handle IO in a specifc thread
+    tokio::spawn(async move {
+        let mut handler = IoAsyncHandler::new(app_io, export_dir);
+        while let Some(io_event) = sync_io_rx.recv().await {
+            handler.handle_io_event(io_event).await;
+        }
+    });
+
+    // Serve HTTP API
+    if let Some(addr) = serve {
+        let app_server = Arc::clone(&app);
+        tokio::spawn(async move {
+            if let Err(e) = server::serve(addr, app_server).await {
+                log::error!("HTTP API failed on {}: {}", addr, e);
+            }
+        });
+    }
+
+    // Serve metrics
+    if let Some(addr) = metrics {
+        let app_metrics = Arc::clone(&app);
+        tokio::spawn(async move {
+            if let Err(e) = server::metrics::serve_metrics(addr, app_metrics).await {
+                log::error!("Metrics failed on {}: {}", addr, e);
+            }
+        });
+    }
+
+    app
+}
+
+/// Strategy scripts folder, in the `~/.socli` folder unless set.
+fn strategies_dir(path: Option<String>) -> String {
+    path.unwrap_or_else(|| {
+        home_dir()
+            .expect("failed to retrieve user home directory")
+            .join(".socli")
+            .join("strategies")
+            .to_string_lossy()
+            .to_string()
+    })
+}
+
+/// Loads settings from the settings file, then applies environment variables and `--set` options.
+fn load_config(path: Option<&Path>, settings: &[String]) -> Result<Config, ConfigError> {
+    let path = match path {
+        Some(path) => path.to_path_buf(),
+        None => home_dir()
+            .expect("failed to retrieve user home directory")
+            .join(".socli")
+            .join(CONFIG_FILE),
+    };
+    let mut config = Config::read(&path)?;
+    config.apply_env(std::env::vars())?;
+    config.apply_overrides(settings)?;
+    config.validate()?;
+    Ok(config)
 }
 
 pub async fn start_ui(app: &Arc<tokio::sync::Mutex<App>>) -> io::Result<()> {
@@ -41,12 +155,13 @@ pub async fn start_ui(app: &Arc<tokio::sync::Mutex<App>>) -> io::Result<()> {
     terminal.hide_cursor()?;
 
     // User event handler
-    let tick_rate = Duration::from_millis(200);
+    let tick_rate = Duration::from_millis(resolve!(Config).ui.tick_rate_ms);
     let mut events = Events::new(tick_rate);
 
     // Trigger state change from Init to Initialized
     {
         let mut app = app.lock().await;
+        app.attach_ui();
         // Here we assume the the first load is a long task
         app.dispatch(IoEvent::Initialize).await;
     }
@@ -94,12 +209,32 @@ async fn main() -> io::Result<()> {
     // Parse args
     let args = Args::parse();
 
-    // Configure log
-    tui_logger::init_logger(LevelFilter::Debug).unwrap();
-    tui_logger::set_default_level(log::LevelFilter::Debug);
+    // Configure log (to stderr without UI, only warnings and errors for commands)
+    match args.command {
+        Some(Command::Daemon { verbose }) => daemon::init_logger(if verbose {
+            LevelFilter::Debug
+        } else {
+            LevelFilter::Info
+        })
+        .unwrap(),
+        Some(Command::Cli(_)) => daemon::init_logger(LevelFilter::Warn).unwrap(),
+        None => {
+            tui_logger::init_logger(LevelFilter::Debug).unwrap();
+            tui_logger::set_default_level(log::LevelFilter::Debug);
+        }
+    }
+
+    // Load settings
+    let config = load_config(args.config.as_deref(), &args.settings)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e.to_string()))?;
+
+    // Load notification settings
+    let notification_service = NotificationServiceImpl::new()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e.to_string()))?;
 
     // Init core container
-    setup_container(&args.strategies)
+    let strategies = strategies_dir(args.strategies);
+    setup_container(&strategies, config, notification_service)
         .await
         .expect("failed to intialize container");
 
@@ -111,22 +246,18 @@ async fn main() -> io::Result<()> {
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
     }
 
-    // Create a channel for IoEvent
-    let (sync_io_tx, mut sync_io_rx) = tokio::sync::mpsc::channel::<IoEvent>(100);
-
-    // Create app
-    let app = Arc::new(tokio::sync::Mutex::new(App::new(sync_io_tx.clone())));
-    let app_ui = Arc::clone(&app);
-
-    // Handle IO in a specifc thread
-    tokio::spawn(async move {
-        let mut handler = IoAsyncHandler::new(app);
-        while let Some(io_event) = sync_io_rx.recv().await {
-            handler.handle_io_event(io_event).await;
+    // Run command (without UI)
+    match args.command {
+        Some(Command::Daemon { .. }) => {
+            let app = start_app(args.export_dir, args.serve, args.metrics);
+            daemon::run(&app).await?;
         }
-    });
-
-    start_ui(&app_ui).await?;
+        Some(Command::Cli(command)) => run_command(command, &strategies, args.output).await?,
+        None => {
+            let app = start_app(args.export_dir, args.serve, args.metrics);
+            start_ui(&app).await?;
+        }
+    }
 
     Ok(())
 }
@@ -0,0 +1,216 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use chrono::Local;
+
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+pub mod tabular;
+
+#[cfg(test)]
+pub mod output_tests;
+
+pub use tabular::Tabular;
+
+/// Version of the output schema (field names of records, JSON envelope).
+/// Bumped on breaking changes only: new fields can be added within a version.
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Aligned columns, for humans
+    #[default]
+    Table,
+    /// JSON document: `{ "schema_version", "kind", "items" }`
+    Json,
+    /// CSV with a header row
+    Csv,
+    /// One JSON record per line
+    Ndjson,
+    /// Markdown table
+    Markdown,
+}
+
+impl OutputFormat {
+    /// File extension of exports
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Table => "txt",
+            Self::Json => "json",
+            Self::Csv => "csv",
+            Self::Ndjson => "ndjson",
+            Self::Markdown => "md",
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Envelope<'a, T> {
+    schema_version: u32,
+    kind: &'a str,
+    /// Document fields (such as a report period)
+    #[serde(flatten)]
+    fields: &'a Map<String, Value>,
+    items: &'a [T],
+}
+
+/// Writes records in the given format.
+pub fn write<T: Tabular, W: Write>(
+    out: &mut W,
+    format: OutputFormat,
+    items: &[T],
+) -> io::Result<()> {
+    write_with_fields(out, format, items, &Map::new())
+}
+
+/// Writes records in the given format, with document fields (such as a report period)
+/// added to the JSON envelope: other formats only output records.
+pub fn write_with_fields<T: Tabular, W: Write>(
+    out: &mut W,
+    format: OutputFormat,
+    items: &[T],
+    fields: &Map<String, Value>,
+) -> io::Result<()> {
+    match format {
+        OutputFormat::Table => write_table(out, &T::headers(), &rows(items)),
+        OutputFormat::Csv => write_csv(out, &T::headers(), &rows(items)),
+        OutputFormat::Markdown => write_markdown(out, &T::headers(), &rows(items)),
+        OutputFormat::Json => write_json(out, T::KIND, items, fields),
+        OutputFormat::Ndjson => write_ndjson(out, items),
+    }
+}
+
+/// Writes records in a JSON envelope (records that are not tabular can be written as JSON only).
+pub fn write_json<T: Serialize, W: Write>(
+    out: &mut W,
+    kind: &str,
+    items: &[T],
+    fields: &Map<String, Value>,
+) -> io::Result<()> {
+    let envelope = Envelope {
+        schema_version: SCHEMA_VERSION,
+        kind,
+        fields,
+        items,
+    };
+    serde_json::to_writer_pretty(&mut *out, &envelope)?;
+    writeln!(out)
+}
+
+/// Writes one JSON record per line.
+pub fn write_ndjson<T: Serialize, W: Write>(out: &mut W, items: &[T]) -> io::Result<()> {
+    for item in items {
+        serde_json::to_writer(&mut *out, item)?;
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+/// Writes records to stdout.
+pub fn print<T: Tabular>(format: OutputFormat, items: &[T]) -> io::Result<()> {
+    print_with_fields(format, items, &Map::new())
+}
+
+/// Writes records to stdout, with document fields in the JSON envelope.
+pub fn print_with_fields<T: Tabular>(
+    format: OutputFormat,
+    items: &[T],
+    fields: &Map<String, Value>,
+) -> io::Result<()> {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    write_with_fields(&mut out, format, items, fields)
+}
+
+/// Writes records to a new file in `dir`, named after the record kind and the current time.
+/// Returns the file path.
+pub fn export<T: Tabular>(dir: &Path, format: OutputFormat, items: &[T]) -> io::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+    let path = dir.join(format!(
+        "socli-{}-{}.{}",
+        T::KIND,
+        Local::now().format("%Y%m%d-%H%M%S"),
+        format.extension()
+    ));
+    let mut out = BufWriter::new(File::create(&path)?);
+    write(&mut out, format, items)?;
+    out.flush()?;
+    Ok(path)
+}
+
+fn rows<T: Tabular>(items: &[T]) -> Vec<Vec<String>> {
+    items.iter().flat_map(|i| i.rows()).collect()
+}
+
+fn write_table<W: Write>(out: &mut W, headers: &[&str], rows: &[Vec<String>]) -> io::Result<()> {
+    let mut widths = headers
+        .iter()
+        .map(|h| h.chars().count())
+        .collect::<Vec<_>>();
+    for row in rows {
+        for (width, value) in widths.iter_mut().zip(row) {
+            *width = (*width).max(value.chars().count());
+        }
+    }
+
+    let headers = headers.iter().map(|h| h.to_string()).collect::<Vec<_>>();
+    for row in std::iter::once(&headers).chain(rows) {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(value, width)| format!("{:<width$}", value, width = width))
+            .collect::<Vec<_>>()
+            .join("  ");
+        writeln!(out, "{}", line.trim_end())?;
+    }
+    Ok(())
+}
+
+fn write_csv<W: Write>(out: &mut W, headers: &[&str], rows: &[Vec<String>]) -> io::Result<()> {
+    writeln!(out, "{}", headers.join(","))?;
+    for row in rows {
+        let line = row
+            .iter()
+            .map(|v| escape_csv(v))
+            .collect::<Vec<_>>()
+            .join(",");
+        writeln!(out, "{}", line)?;
+    }
+    Ok(())
+}
+
+fn write_markdown<W: Write>(out: &mut W, headers: &[&str], rows: &[Vec<String>]) -> io::Result<()> {
+    writeln!(out, "| {} |", headers.join(" | "))?;
+    writeln!(out, "|{}", "---|".repeat(headers.len()))?;
+    for row in rows {
+        let line = row
+            .iter()
+            .map(|v| escape_markdown(v))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        writeln!(out, "| {} |", line)?;
+    }
+    Ok(())
+}
+
+/// Escapes a Markdown table cell (pipes and line breaks).
+pub(crate) fn escape_markdown(value: &str) -> String {
+    value
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+/// Quotes a CSV field if needed.
+pub(crate) fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
@@ -0,0 +1,159 @@
+use serde_json::{json, Map, Value};
+
+use crate::core::model::{
+    decision::Decision,
+    price::Price,
+    stats::{Game, Stats},
+};
+
+use super::{
+    escape_csv, escape_markdown, export, write, write_with_fields, OutputFormat, Tabular,
+    SCHEMA_VERSION,
+};
+
+fn decision(comment: &str) -> Decision {
+    Decision {
+        action: "Buy".to_string(),
+        player_slug: "lebron-james".to_string(),
+        player_name: "LeBron James".to_string(),
+        strategy: "momentum".to_string(),
+        comment: comment.to_string(),
+    }
+}
+
+fn output<T: Tabular>(format: OutputFormat, items: &[T]) -> String {
+    let mut out = vec![];
+    write(&mut out, format, items).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn escape_csv_quotes_special_characters() {
+    assert_eq!(escape_csv("plain"), "plain");
+    assert_eq!(escape_csv("a,b"), "\"a,b\"");
+    assert_eq!(escape_csv("say \"hi\""), "\"say \"\"hi\"\"\"");
+    assert_eq!(escape_csv("two\nlines"), "\"two\nlines\"");
+}
+
+#[test]
+fn write_csv() {
+    let csv = output(OutputFormat::Csv, &[decision("price is up, buy")]);
+
+    assert_eq!(
+        csv,
+        "action,player_slug,player_name,strategy,comment\n\
+         Buy,lebron-james,LeBron James,momentum,\"price is up, buy\"\n"
+    );
+}
+
+#[test]
+fn write_json_envelope() {
+    let json: Value = serde_json::from_str(&output(OutputFormat::Json, &[decision("ok")])).unwrap();
+
+    assert_eq!(json["schema_version"], SCHEMA_VERSION);
+    assert_eq!(json["kind"], "decision");
+    assert_eq!(json["items"][0]["player_slug"], "lebron-james");
+}
+
+#[test]
+fn write_json_document_fields() {
+    let mut fields = Map::new();
+    fields.insert("days".to_string(), json!(3));
+    let mut out = vec![];
+    write_with_fields(&mut out, OutputFormat::Json, &[decision("ok")], &fields).unwrap();
+    let json: Value = serde_json::from_slice(&out).unwrap();
+
+    assert_eq!(json["kind"], "decision");
+    assert_eq!(json["days"], 3);
+    assert_eq!(json["items"][0]["comment"], "ok");
+
+    // Records only in other formats
+    let mut out = vec![];
+    write_with_fields(&mut out, OutputFormat::Ndjson, &[decision("ok")], &fields).unwrap();
+    assert_eq!(
+        out,
+        output(OutputFormat::Ndjson, &[decision("ok")]).into_bytes()
+    );
+}
+
+#[test]
+fn write_ndjson() {
+    let ndjson = output(OutputFormat::Ndjson, &[decision("a"), decision("b")]);
+    let lines = ndjson.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines.len(), 2);
+    let first: Value = serde_json::from_str(lines[0]).unwrap();
+    assert_eq!(first["comment"], "a");
+}
+
+#[test]
+fn write_table_aligns_columns() {
+    let prices = vec![Price {
+        player_slug: "lebron-james".to_string(),
+        date: "2024-01-01T00:00:00Z".to_string(),
+        eur: "12.5".to_string(),
+        usd: "13.6".to_string(),
+    }];
+    let table = output(OutputFormat::Table, &prices);
+    let lines = table.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines[0], "player_slug   date                  eur   usd");
+    assert_eq!(lines[1], "lebron-james  2024-01-01T00:00:00Z  12.5  13.6");
+}
+
+#[test]
+fn write_markdown() {
+    let markdown = output(OutputFormat::Markdown, &[decision("a | b\nc")]);
+
+    assert_eq!(
+        markdown,
+        "| action | player_slug | player_name | strategy | comment |\n\
+         |---|---|---|---|---|\n\
+         | Buy | lebron-james | LeBron James | momentum | a \\| b<br>c |\n"
+    );
+    assert_eq!(escape_markdown("plain"), "plain");
+}
+
+#[test]
+fn export_to_file() {
+    let dir = std::env::temp_dir().join("socli-output-tests");
+    let path = export(&dir, OutputFormat::Csv, &[decision("ok")]).unwrap();
+
+    assert!(path
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .starts_with("socli-decision-"));
+    assert_eq!(path.extension().unwrap(), "csv");
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(content.starts_with("action,player_slug"));
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn stats_rows_by_game() {
+    let stats = Stats {
+        player_slug: "lebron-james".to_string(),
+        score: 40,
+        games: vec![
+            Game {
+                date: "2024-01-01".to_string(),
+                did_play: true,
+                score: 45,
+                minutes_played: 35,
+            },
+            Game {
+                date: "2024-01-03".to_string(),
+                did_play: false,
+                score: 0,
+                minutes_played: 0,
+            },
+        ],
+    };
+
+    let rows = stats.rows();
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[1][3], "false");
+    assert_eq!(rows[0].len(), Stats::headers().len());
+}
@@ -0,0 +1,240 @@
+use serde::Serialize;
+
+use crate::core::model::{
+    backtest::{StrategyReport, Trade},
+    currency::Currency,
+    decision::Decision,
+    fixture::FixtureResult,
+    player::Player,
+    price::Price,
+    stats::Stats,
+    strategy::StrategyInfo,
+};
+
+/// A record that can be output as flat rows (table and CSV).
+/// Columns are named after the serialized fields, except for computed ones
+/// (e.g. `last_price_eur`, `score` and `injury` for players).
+pub trait Tabular: Serialize {
+    /// Record kind, in JSON envelopes
+    const KIND: &'static str;
+
+    fn headers() -> Vec<&'static str>;
+
+    /// Rows of the record (nested lists can be output as several rows)
+    fn rows(&self) -> Vec<Vec<String>>;
+}
+
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map_or(String::new(), |v| v.to_string())
+}
+
+impl Tabular for Player {
+    const KIND: &'static str = "player";
+
+    fn headers() -> Vec<&'static str> {
+        vec![
+            "slug",
+            "display_name",
+            "birth_date",
+            "team",
+            "positions",
+            "country",
+            "number",
+            // Computed columns
+            "last_price_eur",
+            "score",
+            "injury",
+        ]
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        vec![vec![
+            self.slug.clone(),
+            self.display_name.clone(),
+            self.birth_date.clone(),
+            optional(self.team.as_ref()),
+            self.positions.join(";"),
+            self.country.clone(),
+            self.number.to_string(),
+            optional(
+                self.price_avg(Currency::Euro, 1)
+                    .map(|p| format!("{:.2}", p)),
+            ),
+            optional(self.stats.as_ref().map(|s| s.score)),
+            optional(self.injury.as_ref().map(|i| &i.description)),
+        ]]
+    }
+}
+
+impl Tabular for Price {
+    const KIND: &'static str = "price";
+
+    fn headers() -> Vec<&'static str> {
+        vec!["player_slug", "date", "eur", "usd"]
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        vec![vec![
+            self.player_slug.clone(),
+            self.date.clone(),
+            self.eur.clone(),
+            self.usd.clone(),
+        ]]
+    }
+}
+
+impl Tabular for Stats {
+    const KIND: &'static str = "stats";
+
+    fn headers() -> Vec<&'static str> {
+        vec![
+            "player_slug",
+            "score",
+            "game_date",
+            "game_did_play",
+            "game_score",
+            "game_minutes_played",
+        ]
+    }
+
+    /// One row per game
+    fn rows(&self) -> Vec<Vec<String>> {
+        if self.games.is_empty() {
+            return vec![vec![
+                self.player_slug.clone(),
+                self.score.to_string(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+            ]];
+        }
+
+        self.games
+            .iter()
+            .map(|g| {
+                vec![
+                    self.player_slug.clone(),
+                    self.score.to_string(),
+                    g.date.clone(),
+                    g.did_play.to_string(),
+                    g.score.to_string(),
+                    g.minutes_played.to_string(),
+                ]
+            })
+            .collect()
+    }
+}
+
+impl Tabular for Decision {
+    const KIND: &'static str = "decision";
+
+    fn headers() -> Vec<&'static str> {
+        vec![
+            "action",
+            "player_slug",
+            "player_name",
+            "strategy",
+            "comment",
+        ]
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        vec![vec![
+            self.action.clone(),
+            self.player_slug.clone(),
+            self.player_name.clone(),
+            self.strategy.clone(),
+            self.comment.clone(),
+        ]]
+    }
+}
+
+impl Tabular for StrategyInfo {
+    const KIND: &'static str = "strategy";
+
+    fn headers() -> Vec<&'static str> {
+        vec!["name", "path", "engine", "enabled"]
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        vec![vec![
+            self.name.clone(),
+            self.path.clone(),
+            self.engine.clone(),
+            self.enabled.to_string(),
+        ]]
+    }
+}
+
+impl Tabular for FixtureResult {
+    const KIND: &'static str = "fixture_result";
+
+    fn headers() -> Vec<&'static str> {
+        vec!["strategy", "case", "failure"]
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        vec![vec![
+            self.strategy.clone(),
+            self.case.clone(),
+            optional(self.failure.as_ref()),
+        ]]
+    }
+}
+
+impl Tabular for Trade {
+    const KIND: &'static str = "backtest_trade";
+
+    fn headers() -> Vec<&'static str> {
+        vec![
+            "strategy",
+            "player_slug",
+            "buy_date",
+            "buy_price",
+            "sell_date",
+            "sell_price",
+        ]
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        vec![vec![
+            self.strategy.clone(),
+            self.player_slug.clone(),
+            self.buy_date.clone(),
+            format!("{:.2}", self.buy_price),
+            optional(self.sell_date.as_ref()),
+            optional(self.sell_price.map(|p| format!("{:.2}", p))),
+        ]]
+    }
+}
+
+impl Tabular for StrategyReport {
+    const KIND: &'static str = "backtest_strategy";
+
+    fn headers() -> Vec<&'static str> {
+        vec![
+            "strategy",
+            "trades",
+            "open_trades",
+            "winning_trades",
+            "hit_rate",
+            "realized_pnl",
+            "pnl",
+            "max_drawdown",
+        ]
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        vec![vec![
+            self.strategy.clone(),
+            self.trades.to_string(),
+            self.open_trades.to_string(),
+            self.winning_trades.to_string(),
+            optional(self.hit_rate.map(|r| format!("{:.4}", r))),
+            format!("{:.2}", self.realized_pnl),
+            format!("{:.2}", self.pnl),
+            format!("{:.2}", self.max_drawdown),
+        ]]
+    }
+}
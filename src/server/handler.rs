@@ -0,0 +1,166 @@
+use std::convert::Infallible;
+
+use axum::{
+    extract::{Path, State},
+    response::sse::{Event, KeepAlive, Sse},
+    Json,
+};
+use serde::Serialize;
+use tokio_stream::{wrappers::BroadcastStream, Stream, StreamExt};
+
+use crate::{
+    core::{
+        metrics::metrics,
+        model::{
+            decision::Decision, decision_history::DecisionHistory, evaluation::EvaluationProgress,
+            injury::Injury, player::Player, price::Price, requirement::DataRequirement,
+            stats::Stats,
+        },
+        service::{
+            decision::DecisionService, injury::InjuryService, price::PriceService,
+            stats::StatsService,
+        },
+        DecisionSender,
+    },
+    resolve, resolve_trait,
+};
+
+use super::{ApiError, ServerState};
+
+/// Application status
+#[derive(Serialize)]
+pub struct Status {
+    /// Data is being loaded
+    pub loading: bool,
+    pub player_count: usize,
+    pub players_with_prices: usize,
+    pub players_with_stats: usize,
+    pub decision_count: usize,
+    /// Last "evaluate all" progress
+    pub evaluation: Option<EvaluationProgress>,
+    /// Background tasks running
+    pub running_tasks: i64,
+    /// Background task failures since startup
+    pub task_failures: u64,
+    /// Last background refresh of player details (RFC 3339)
+    pub last_refresh: Option<String>,
+}
+
+pub async fn status(State(state): State<ServerState>) -> Json<Status> {
+    let app = state.app.lock().await;
+    let players = app.state().get_players();
+    Json(Status {
+        loading: app.is_loading(),
+        player_count: players.len(),
+        players_with_prices: players
+            .iter()
+            .filter(|p| p.has_data(DataRequirement::Prices))
+            .count(),
+        players_with_stats: players
+            .iter()
+            .filter(|p| p.has_data(DataRequirement::Stats))
+            .count(),
+        decision_count: app.state().get_decisions().len(),
+        evaluation: app.state().get_evaluation().cloned(),
+        running_tasks: metrics().running_tasks(),
+        task_failures: metrics().task_failures(),
+        last_refresh: app.last_refresh().map(|d| d.to_rfc3339()),
+    })
+}
+
+pub async fn players(State(state): State<ServerState>) -> Json<Vec<Player>> {
+    let app = state.app.lock().await;
+    Json(app.state().get_players().to_vec())
+}
+
+pub async fn player(
+    State(state): State<ServerState>,
+    Path(slug): Path<String>,
+) -> Result<Json<Player>, ApiError> {
+    find_player(&state, &slug).await.map(Json)
+}
+
+/// Player prices, loaded if not loaded by the application yet.
+pub async fn player_prices(
+    State(state): State<ServerState>,
+    Path(slug): Path<String>,
+) -> Result<Json<Vec<Price>>, ApiError> {
+    let player = find_player(&state, &slug).await?;
+    if player.has_data(DataRequirement::Prices) {
+        return Ok(Json(player.prices));
+    }
+
+    resolve_trait!(PriceService)
+        .get_prices(&slug)
+        .await
+        .map(Json)
+        .map_err(|e| ApiError::Service(e.to_string()))
+}
+
+/// Player stats, loaded if not loaded by the application yet.
+pub async fn player_stats(
+    State(state): State<ServerState>,
+    Path(slug): Path<String>,
+) -> Result<Json<Option<Stats>>, ApiError> {
+    let player = find_player(&state, &slug).await?;
+    if player.has_data(DataRequirement::Stats) {
+        return Ok(Json(player.stats));
+    }
+
+    let stats = resolve_trait!(StatsService)
+        .get_stats(&[slug])
+        .await
+        .map_err(|e| ApiError::Service(e.to_string()))?;
+    Ok(Json(stats.into_iter().next()))
+}
+
+/// Player injury (`null` if healthy), loaded if not loaded by the application yet.
+pub async fn player_injury(
+    State(state): State<ServerState>,
+    Path(slug): Path<String>,
+) -> Result<Json<Option<Injury>>, ApiError> {
+    let player = find_player(&state, &slug).await?;
+    if player.has_data(DataRequirement::Injury) {
+        return Ok(Json(player.injury));
+    }
+
+    let injuries = resolve_trait!(InjuryService)
+        .get_injuries(&[slug])
+        .await
+        .map_err(|e| ApiError::Service(e.to_string()))?;
+    Ok(Json(injuries.into_iter().next()))
+}
+
+/// Decisions of the last strategies runs
+pub async fn decisions(State(state): State<ServerState>) -> Json<Vec<Decision>> {
+    let app = state.app.lock().await;
+    Json(app.state().get_decisions().to_vec())
+}
+
+pub async fn decision_history() -> Result<Json<DecisionHistory>, ApiError> {
+    resolve_trait!(DecisionService)
+        .get_history()
+        .await
+        .map(Json)
+        .map_err(|e| ApiError::Service(e.to_string()))
+}
+
+/// Server-sent events stream of new decisions (`decision` events).
+pub async fn events() -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let stream = BroadcastStream::new(resolve!(DecisionSender).subscribe()).filter_map(|result| {
+        // Lagging receivers miss decisions
+        result
+            .ok()
+            .and_then(|d| Event::default().event("decision").json_data(d).ok())
+            .map(Ok)
+    });
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
+async fn find_player(state: &ServerState, slug: &str) -> Result<Player, ApiError> {
+    let app = state.app.lock().await;
+    app.state()
+        .get_player(slug)
+        .cloned()
+        .ok_or_else(|| ApiError::NotFound(format!("player {}", slug)))
+}
@@ -0,0 +1,141 @@
+use std::sync::{Arc, Once};
+
+use axum::{
+    body::{Body, HttpBody},
+    http::{header::CONTENT_TYPE, Request, StatusCode},
+    Router,
+};
+use serde_json::Value;
+use tokio::sync::{broadcast, mpsc};
+use tower::ServiceExt;
+
+use crate::{
+    app::{io::IoEvent, App},
+    core::{
+        model::{config::Config, decision::Decision, player_builder::PlayerBuilder},
+        DecisionSender,
+    },
+    resolve,
+};
+
+use super::router;
+
+/// Registers what handlers resolve (once: the container is global).
+fn init_container() {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        dilib::global::init_container(|container| {
+            container.add_singleton(Config::default()).unwrap();
+            let (decision_sender, _) = broadcast::channel::<Decision>(100);
+            container.add_singleton(decision_sender).unwrap();
+        })
+        .unwrap();
+    });
+}
+
+/// API on an app with a single player (with loaded prices).
+/// The IO events receiver must be kept until the end of the test.
+async fn create_router() -> (Router, mpsc::Receiver<IoEvent>) {
+    init_container();
+    let (io_tx, io_rx) = mpsc::channel(100);
+    let mut app = App::new(io_tx);
+    app.initialize(vec![PlayerBuilder::new("slug")
+        .price("2023-07-22T16:15:13Z", "40", "50")
+        .build()])
+        .await;
+    (router(Arc::new(tokio::sync::Mutex::new(app))), io_rx)
+}
+
+async fn get(router: Router, uri: &str) -> (StatusCode, Value) {
+    let response = router
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn status() {
+    let (router, _io_rx) = create_router().await;
+
+    let (status, json) = get(router, "/api/status").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["player_count"], 1);
+    assert_eq!(json["players_with_prices"], 1);
+    assert_eq!(json["players_with_stats"], 0);
+    assert_eq!(json["decision_count"], 0);
+    assert!(json["evaluation"].is_null());
+    assert!(json["running_tasks"].is_i64());
+    assert!(json["task_failures"].is_u64());
+    assert!(json["last_refresh"].is_null());
+}
+
+#[tokio::test]
+async fn players() {
+    let (router, _io_rx) = create_router().await;
+
+    let (status, json) = get(router.clone(), "/api/players").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json.as_array().unwrap().len(), 1);
+    assert_eq!(json[0]["slug"], "slug");
+    assert!(json[0].get("injury_loaded").is_none());
+
+    let (status, json) = get(router.clone(), "/api/players/slug").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["display_name"], "name");
+
+    // Loaded prices are not requested again
+    let (status, json) = get(router, "/api/players/slug/prices").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json[0]["eur"], "40");
+}
+
+#[tokio::test]
+async fn unknown_player() {
+    let (router, _io_rx) = create_router().await;
+
+    let (status, json) = get(router.clone(), "/api/players/unknown").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(json["error"], "not found: player unknown");
+
+    let (status, _) = get(router.clone(), "/api/players/unknown/stats").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let response = router
+        .oneshot(Request::get("/api/unknown").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn decision_events() {
+    let (router, _io_rx) = create_router().await;
+
+    let response = router
+        .oneshot(Request::get("/api/events").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[CONTENT_TYPE], "text/event-stream");
+
+    // Subscribed when the stream is requested
+    resolve!(DecisionSender)
+        .send(Decision {
+            action: "Buy".to_string(),
+            player_slug: "slug".to_string(),
+            player_name: "name".to_string(),
+            strategy: "momentum/v2".to_string(),
+            comment: "score 40".to_string(),
+        })
+        .unwrap();
+
+    let mut body = response.into_body();
+    let event = body.data().await.unwrap().unwrap();
+    let event = String::from_utf8(event.to_vec()).unwrap();
+    assert!(event.starts_with("event:decision\n"));
+    assert!(event.contains(r#""strategy":"momentum/v2""#));
+}
@@ -0,0 +1,42 @@
+use std::{net::SocketAddr, sync::Arc};
+
+use axum::{extract::State, http::header, response::IntoResponse, routing::get, Router};
+
+use crate::{
+    app::App,
+    core::{metrics::metrics, model::requirement::DataRequirement},
+};
+
+use super::{ApiError, ServerState};
+
+/// Serves Prometheus metrics on `/metrics`, until the application exits.
+pub async fn serve_metrics(
+    addr: SocketAddr,
+    app: Arc<tokio::sync::Mutex<App>>,
+) -> Result<(), String> {
+    let router = Router::new()
+        .route("/metrics", get(scrape))
+        .with_state(ServerState { app });
+    let server = axum::Server::try_bind(&addr).map_err(|e| e.to_string())?;
+    log::info!("📈 Metrics available on http://{}/metrics", addr);
+    server
+        .serve(router.into_make_service())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Loaded players gauges are updated on scrape.
+async fn scrape(State(state): State<ServerState>) -> Result<impl IntoResponse, ApiError> {
+    {
+        let app = state.app.lock().await;
+        let players = app.state().get_players();
+        let count = |requirement| players.iter().filter(|p| p.has_data(requirement)).count();
+        metrics().set_players("all", players.len());
+        metrics().set_players("prices", count(DataRequirement::Prices));
+        metrics().set_players("stats", count(DataRequirement::Stats));
+        metrics().set_players("injury", count(DataRequirement::Injury));
+    }
+
+    let body = metrics().encode().map_err(ApiError::Service)?;
+    Ok(([(header::CONTENT_TYPE, "text/plain; version=0.0.4")], body))
+}
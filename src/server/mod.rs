@@ -0,0 +1,72 @@
+use std::{fmt::Display, net::SocketAddr, sync::Arc};
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde_json::json;
+
+use crate::app::App;
+
+pub mod handler;
+pub mod metrics;
+
+#[cfg(test)]
+pub mod handler_tests;
+
+/// Shared by request handlers
+#[derive(Clone)]
+pub struct ServerState {
+    app: Arc<tokio::sync::Mutex<App>>,
+}
+
+#[derive(Debug)]
+pub enum ApiError {
+    NotFound(String),
+    Service(String),
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound(msg) => write!(f, "not found: {}", msg),
+            Self::Service(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Service(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        (status, Json(json!({ "error": self.to_string() }))).into_response()
+    }
+}
+
+pub fn router(app: Arc<tokio::sync::Mutex<App>>) -> Router {
+    Router::new()
+        .route("/api/status", get(handler::status))
+        .route("/api/players", get(handler::players))
+        .route("/api/players/:slug", get(handler::player))
+        .route("/api/players/:slug/prices", get(handler::player_prices))
+        .route("/api/players/:slug/stats", get(handler::player_stats))
+        .route("/api/players/:slug/injury", get(handler::player_injury))
+        .route("/api/decisions", get(handler::decisions))
+        .route("/api/decisions/history", get(handler::decision_history))
+        .route("/api/events", get(handler::events))
+        .with_state(ServerState { app })
+}
+
+/// Serves the HTTP API, until the application exits.
+pub async fn serve(addr: SocketAddr, app: Arc<tokio::sync::Mutex<App>>) -> Result<(), String> {
+    let server = axum::Server::try_bind(&addr).map_err(|e| e.to_string())?;
+    log::info!("🌐 HTTP API listening on http://{}", addr);
+    server
+        .serve(router(app).into_make_service())
+        .await
+        .map_err(|e| e.to_string())
+}
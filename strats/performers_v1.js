@@ -1,8 +1,12 @@
-export function decide(player) {
+export const params = {
+  min_score: { type: "number", default: 35 },
+};
+
+export function decide(player, slug, context, params) {
 
     const lastPrice = parseFloat(player.prices[0].eur);
     
-    if (player.stats.score > 35) {
+    if (player.stats.score > params.min_score) {
       return {
         action: "Buy",
         comment: `score: ${player.stats.score}, price: ${lastPrice}€`,
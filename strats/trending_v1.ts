@@ -0,0 +1,31 @@
+import { game_scores, last_price, linear_trend, price_avg } from "socli";
+
+interface Params {
+  min_slope: number;
+  window: number;
+}
+
+export const params: ParamSpecs = {
+  min_slope: { type: "number", default: 1 },
+  window: { type: "number", default: 5 },
+};
+
+export function decide(
+  player: Player,
+  slug: string,
+  context: StrategyContext,
+  params: Params
+): Decision | undefined {
+  // Oldest first, so that a positive slope means scores are going up
+  const scores: number[] = game_scores(player).slice(0, params.window).reverse();
+  const trend = linear_trend(scores);
+  const price = last_price(player);
+  const average = price_avg(player, params.window) as number;
+
+  if (trend && price !== undefined && trend.slope >= params.min_slope && price <= average) {
+    return {
+      action: "Buy",
+      comment: `scores trending up (${trend.slope.toFixed(2)}), price: ${price}€`,
+    };
+  }
+}
@@ -1,11 +1,16 @@
-export function decide(player) {
+// @requires none
+export const params = {
+  max_age: { type: "number", default: 25 },
+};
+
+export function decide(player, slug, context, params) {
   const today = new Date();
   const birthDate = new Date(player.birth_date);
   const age = today.getFullYear() - birthDate.getFullYear();
-  if (age < 25) {
+  if (age < params.max_age) {
     return {
       action: "Buy",
-      comment: `age: ${age}, score: ${player.stats.score}`,
+      comment: `age: ${age}, score: ${player.stats ? player.stats.score : "n/a"}`,
     };
   }
 }